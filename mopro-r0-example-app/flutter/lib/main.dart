@@ -152,7 +152,8 @@ class _MyAppState extends State<MyApp> with TickerProviderStateMixin {
                                     if (message.isEmpty) {
                                       throw Exception("Message cannot be empty");
                                     }
-                                    risc0ProofResult = await _moproFlutterPlugin.generateRisc0Proof(message);
+                                    risc0ProofResult = await _moproFlutterPlugin.generateRisc0Proof(
+                                        message, Risc0RequestedReceiptKind.composite);
                                   } on Exception catch (e) {
                                     print("Error: $e");
                                     risc0ProofResult = null;
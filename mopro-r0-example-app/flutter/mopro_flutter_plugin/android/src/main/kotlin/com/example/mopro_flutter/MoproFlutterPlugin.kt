@@ -320,10 +320,20 @@ class MoproFlutterPlugin : FlutterPlugin, MethodCallHandler {
                 null
             )
 
+            val receiptKindIndex = call.argument<Int>("receiptKind") ?: return result.error(
+                "ARGUMENT_ERROR",
+                "Missing receiptKind",
+                null
+            )
+
+            val receiptKind = Risc0RequestedReceiptKind.values()[receiptKindIndex]
+
             try {
-                val res = risc0Prove(message)
+                val res = risc0Prove(message, receiptKind)
                 val resultMap = mapOf(
-                    "receipt" to res.receipt
+                    "receipt" to res.receipt,
+                    "receiptKind" to res.receiptKind.name.lowercase(),
+                    "sealSize" to res.sealSize.toLong()
                 )
                 result.success(resultMap)
             } catch (e: Exception) {
@@ -341,7 +351,13 @@ class MoproFlutterPlugin : FlutterPlugin, MethodCallHandler {
                 val res = risc0Verify(receiptBytes)
                 val resultMap = mapOf(
                     "isValid" to res.isValid,
-                    "verifiedMessage" to res.verifiedMessage
+                    "verifiedMessage" to res.verifiedMessage,
+                    "signer" to mapOf(
+                        "curve" to res.signer.curve.name.lowercase(),
+                        "compressed" to res.signer.compressed,
+                        "uncompressed" to res.signer.uncompressed,
+                        "fingerprint" to res.signer.fingerprint
+                    )
                 )
                 result.success(resultMap)
             } catch (e: Exception) {
@@ -17,18 +17,19 @@ package uniffi.mopro
 // compile the Rust component. The easiest way to ensure this is to bundle the Kotlin
 // helpers directly inline like we're doing here.
 
-import com.sun.jna.Callback
 import com.sun.jna.Library
+import com.sun.jna.IntegerType
 import com.sun.jna.Native
 import com.sun.jna.Pointer
 import com.sun.jna.Structure
+import com.sun.jna.Callback
 import com.sun.jna.ptr.*
 import java.nio.ByteBuffer
 import java.nio.ByteOrder
 import java.nio.CharBuffer
 import java.nio.charset.CodingErrorAction
-import java.util.concurrent.ConcurrentHashMap
 import java.util.concurrent.atomic.AtomicLong
+import java.util.concurrent.ConcurrentHashMap
 
 // This is a helper for safely working with byte buffers returned from the Rust code.
 // A rust-owned buffer is represented by its capacity, its current length, and a
@@ -42,41 +43,29 @@ open class RustBuffer : Structure() {
     // Note: `capacity` and `len` are actually `ULong` values, but JVM only supports signed values.
     // When dealing with these fields, make sure to call `toULong()`.
     @JvmField var capacity: Long = 0
-
     @JvmField var len: Long = 0
-
     @JvmField var data: Pointer? = null
 
-    class ByValue :
-        RustBuffer(),
-        Structure.ByValue
+    class ByValue: RustBuffer(), Structure.ByValue
+    class ByReference: RustBuffer(), Structure.ByReference
 
-    class ByReference :
-        RustBuffer(),
-        Structure.ByReference
-
-    internal fun setValue(other: RustBuffer) {
+   internal fun setValue(other: RustBuffer) {
         capacity = other.capacity
         len = other.len
         data = other.data
     }
 
     companion object {
-        internal fun alloc(size: ULong = 0UL) =
-            uniffiRustCall { status ->
-                // Note: need to convert the size to a `Long` value to make this work with JVM.
-                UniffiLib.INSTANCE.ffi_mopro_r0_example_app_rustbuffer_alloc(size.toLong(), status)
-            }.also {
-                if (it.data == null) {
-                    throw RuntimeException("RustBuffer.alloc() returned null data pointer (size=$size)")
-                }
-            }
+        internal fun alloc(size: ULong = 0UL) = uniffiRustCall() { status ->
+            // Note: need to convert the size to a `Long` value to make this work with JVM.
+            UniffiLib.INSTANCE.ffi_mopro_r0_example_app_rustbuffer_alloc(size.toLong(), status)
+        }.also {
+            if(it.data == null) {
+               throw RuntimeException("RustBuffer.alloc() returned null data pointer (size=${size})")
+           }
+        }
 
-        internal fun create(
-            capacity: ULong,
-            len: ULong,
-            data: Pointer?,
-        ): RustBuffer.ByValue {
+        internal fun create(capacity: ULong, len: ULong, data: Pointer?): RustBuffer.ByValue {
             var buf = RustBuffer.ByValue()
             buf.capacity = capacity.toLong()
             buf.len = len.toLong()
@@ -84,10 +73,9 @@ open class RustBuffer : Structure() {
             return buf
         }
 
-        internal fun free(buf: RustBuffer.ByValue) =
-            uniffiRustCall { status ->
-                UniffiLib.INSTANCE.ffi_mopro_r0_example_app_rustbuffer_free(buf, status)
-            }
+        internal fun free(buf: RustBuffer.ByValue) = uniffiRustCall() { status ->
+            UniffiLib.INSTANCE.ffi_mopro_r0_example_app_rustbuffer_free(buf, status)
+        }
     }
 
     @Suppress("TooGenericExceptionThrown")
@@ -140,14 +128,10 @@ class RustBufferByReference : ByReference(16) {
 @Structure.FieldOrder("len", "data")
 internal open class ForeignBytes : Structure() {
     @JvmField var len: Int = 0
-
     @JvmField var data: Pointer? = null
 
-    class ByValue :
-        ForeignBytes(),
-        Structure.ByValue
+    class ByValue : ForeignBytes(), Structure.ByValue
 }
-
 /**
  * The FfiConverter interface handles converter types to and from the FFI
  *
@@ -177,10 +161,7 @@ public interface FfiConverter<KotlinType, FfiType> {
     fun allocationSize(value: KotlinType): ULong
 
     // Write a Kotlin type to a `ByteBuffer`
-    fun write(
-        value: KotlinType,
-        buf: ByteBuffer,
-    )
+    fun write(value: KotlinType, buf: ByteBuffer)
 
     // Lower a value into a `RustBuffer`
     //
@@ -191,10 +172,9 @@ public interface FfiConverter<KotlinType, FfiType> {
     fun lowerIntoRustBuffer(value: KotlinType): RustBuffer.ByValue {
         val rbuf = RustBuffer.alloc(allocationSize(value))
         try {
-            val bbuf =
-                rbuf.data!!.getByteBuffer(0, rbuf.capacity).also {
-                    it.order(ByteOrder.BIG_ENDIAN)
-                }
+            val bbuf = rbuf.data!!.getByteBuffer(0, rbuf.capacity).also {
+                it.order(ByteOrder.BIG_ENDIAN)
+            }
             write(value, bbuf)
             rbuf.writeField("len", bbuf.position().toLong())
             return rbuf
@@ -211,11 +191,11 @@ public interface FfiConverter<KotlinType, FfiType> {
     fun liftFromRustBuffer(rbuf: RustBuffer.ByValue): KotlinType {
         val byteBuf = rbuf.asByteBuffer()!!
         try {
-            val item = read(byteBuf)
-            if (byteBuf.hasRemaining()) {
-                throw RuntimeException("junk remaining in buffer after lifting, something is very wrong!!")
-            }
-            return item
+           val item = read(byteBuf)
+           if (byteBuf.hasRemaining()) {
+               throw RuntimeException("junk remaining in buffer after lifting, something is very wrong!!")
+           }
+           return item
         } finally {
             RustBuffer.free(rbuf)
         }
@@ -227,9 +207,8 @@ public interface FfiConverter<KotlinType, FfiType> {
  *
  * @suppress
  */
-public interface FfiConverterRustBuffer<KotlinType> : FfiConverter<KotlinType, RustBuffer.ByValue> {
+public interface FfiConverterRustBuffer<KotlinType>: FfiConverter<KotlinType, RustBuffer.ByValue> {
     override fun lift(value: RustBuffer.ByValue) = liftFromRustBuffer(value)
-
     override fun lower(value: KotlinType) = lowerIntoRustBuffer(value)
 }
 // A handful of classes and functions to support the generated data structures.
@@ -242,24 +221,24 @@ internal const val UNIFFI_CALL_UNEXPECTED_ERROR = 2.toByte()
 @Structure.FieldOrder("code", "error_buf")
 internal open class UniffiRustCallStatus : Structure() {
     @JvmField var code: Byte = 0
-
     @JvmField var error_buf: RustBuffer.ByValue = RustBuffer.ByValue()
 
-    class ByValue :
-        UniffiRustCallStatus(),
-        Structure.ByValue
+    class ByValue: UniffiRustCallStatus(), Structure.ByValue
 
-    fun isSuccess(): Boolean = code == UNIFFI_CALL_SUCCESS
+    fun isSuccess(): Boolean {
+        return code == UNIFFI_CALL_SUCCESS
+    }
 
-    fun isError(): Boolean = code == UNIFFI_CALL_ERROR
+    fun isError(): Boolean {
+        return code == UNIFFI_CALL_ERROR
+    }
 
-    fun isPanic(): Boolean = code == UNIFFI_CALL_UNEXPECTED_ERROR
+    fun isPanic(): Boolean {
+        return code == UNIFFI_CALL_UNEXPECTED_ERROR
+    }
 
     companion object {
-        fun create(
-            code: Byte,
-            errorBuf: RustBuffer.ByValue,
-        ): UniffiRustCallStatus.ByValue {
+        fun create(code: Byte, errorBuf: RustBuffer.ByValue): UniffiRustCallStatus.ByValue {
             val callStatus = UniffiRustCallStatus.ByValue()
             callStatus.code = code
             callStatus.error_buf = errorBuf
@@ -268,9 +247,7 @@ internal open class UniffiRustCallStatus : Structure() {
     }
 }
 
-class InternalException(
-    message: String,
-) : kotlin.Exception(message)
+class InternalException(message: String) : kotlin.Exception(message)
 
 /**
  * Each top-level error class has a companion object that can lift the error from the call status's rust buffer
@@ -278,7 +255,7 @@ class InternalException(
  * @suppress
  */
 interface UniffiRustCallStatusErrorHandler<E> {
-    fun lift(error_buf: RustBuffer.ByValue): E
+    fun lift(error_buf: RustBuffer.ByValue): E;
 }
 
 // Helpers for calling Rust
@@ -286,10 +263,7 @@ interface UniffiRustCallStatusErrorHandler<E> {
 // synchronize itself
 
 // Call a rust function that returns a Result<>.  Pass in the Error class companion that corresponds to the Err
-private inline fun <U, E : kotlin.Exception> uniffiRustCallWithError(
-    errorHandler: UniffiRustCallStatusErrorHandler<E>,
-    callback: (UniffiRustCallStatus) -> U,
-): U {
+private inline fun <U, E: kotlin.Exception> uniffiRustCallWithError(errorHandler: UniffiRustCallStatusErrorHandler<E>, callback: (UniffiRustCallStatus) -> U): U {
     var status = UniffiRustCallStatus()
     val return_value = callback(status)
     uniffiCheckCallStatus(errorHandler, status)
@@ -297,10 +271,7 @@ private inline fun <U, E : kotlin.Exception> uniffiRustCallWithError(
 }
 
 // Check UniffiRustCallStatus and throw an error if the call wasn't successful
-private fun <E : kotlin.Exception> uniffiCheckCallStatus(
-    errorHandler: UniffiRustCallStatusErrorHandler<E>,
-    status: UniffiRustCallStatus,
-) {
+private fun<E: kotlin.Exception> uniffiCheckCallStatus(errorHandler: UniffiRustCallStatusErrorHandler<E>, status: UniffiRustCallStatus) {
     if (status.isSuccess()) {
         return
     } else if (status.isError()) {
@@ -324,7 +295,7 @@ private fun <E : kotlin.Exception> uniffiCheckCallStatus(
  *
  * @suppress
  */
-object UniffiNullRustCallStatusErrorHandler : UniffiRustCallStatusErrorHandler<InternalException> {
+object UniffiNullRustCallStatusErrorHandler: UniffiRustCallStatusErrorHandler<InternalException> {
     override fun lift(error_buf: RustBuffer.ByValue): InternalException {
         RustBuffer.free(error_buf)
         return InternalException("Unexpected CALL_ERROR")
@@ -332,31 +303,32 @@ object UniffiNullRustCallStatusErrorHandler : UniffiRustCallStatusErrorHandler<I
 }
 
 // Call a rust function that returns a plain value
-private inline fun <U> uniffiRustCall(callback: (UniffiRustCallStatus) -> U): U =
-    uniffiRustCallWithError(UniffiNullRustCallStatusErrorHandler, callback)
+private inline fun <U> uniffiRustCall(callback: (UniffiRustCallStatus) -> U): U {
+    return uniffiRustCallWithError(UniffiNullRustCallStatusErrorHandler, callback)
+}
 
-internal inline fun <T> uniffiTraitInterfaceCall(
+internal inline fun<T> uniffiTraitInterfaceCall(
     callStatus: UniffiRustCallStatus,
     makeCall: () -> T,
     writeReturn: (T) -> Unit,
 ) {
     try {
         writeReturn(makeCall())
-    } catch (e: kotlin.Exception) {
+    } catch(e: kotlin.Exception) {
         callStatus.code = UNIFFI_CALL_UNEXPECTED_ERROR
         callStatus.error_buf = FfiConverterString.lower(e.toString())
     }
 }
 
-internal inline fun <T, reified E : Throwable> uniffiTraitInterfaceCallWithError(
+internal inline fun<T, reified E: Throwable> uniffiTraitInterfaceCallWithError(
     callStatus: UniffiRustCallStatus,
     makeCall: () -> T,
     writeReturn: (T) -> Unit,
-    lowerError: (E) -> RustBuffer.ByValue,
+    lowerError: (E) -> RustBuffer.ByValue
 ) {
     try {
         writeReturn(makeCall())
-    } catch (e: kotlin.Exception) {
+    } catch(e: kotlin.Exception) {
         if (e is E) {
             callStatus.code = UNIFFI_CALL_ERROR
             callStatus.error_buf = lowerError(e)
@@ -366,15 +338,12 @@ internal inline fun <T, reified E : Throwable> uniffiTraitInterfaceCallWithError
         }
     }
 }
-
 // Map handles to objects
 //
 // This is used pass an opaque 64-bit handle representing a foreign object to the Rust code.
-internal class UniffiHandleMap<T : Any> {
+internal class UniffiHandleMap<T: Any> {
     private val map = ConcurrentHashMap<Long, T>()
-    private val counter =
-        java.util.concurrent.atomic
-            .AtomicLong(0)
+    private val counter = java.util.concurrent.atomic.AtomicLong(0)
 
     val size: Int
         get() = map.size
@@ -387,10 +356,14 @@ internal class UniffiHandleMap<T : Any> {
     }
 
     // Get an object from the handle map
-    fun get(handle: Long): T = map.get(handle) ?: throw InternalException("UniffiHandleMap.get: Invalid handle")
+    fun get(handle: Long): T {
+        return map.get(handle) ?: throw InternalException("UniffiHandleMap.get: Invalid handle")
+    }
 
     // Remove an entry from the handlemap and get the Kotlin object back
-    fun remove(handle: Long): T = map.remove(handle) ?: throw InternalException("UniffiHandleMap: Invalid handle")
+    fun remove(handle: Long): T {
+        return map.remove(handle) ?: throw InternalException("UniffiHandleMap: Invalid handle")
+    }
 }
 
 // Contains loading, initialization code,
@@ -404,25 +377,22 @@ private fun findLibraryName(componentName: String): String {
     return "mopro_r0_example_app"
 }
 
-private inline fun <reified Lib : Library> loadIndirect(componentName: String): Lib =
-    Native.load<Lib>(findLibraryName(componentName), Lib::class.java)
+private inline fun <reified Lib : Library> loadIndirect(
+    componentName: String
+): Lib {
+    return Native.load<Lib>(findLibraryName(componentName), Lib::class.java)
+}
 
 // Define FFI callback types
 internal interface UniffiRustFutureContinuationCallback : com.sun.jna.Callback {
-    fun callback(
-        `data`: Long,
-        `pollResult`: Byte,
-    )
+    fun callback(`data`: Long,`pollResult`: Byte,)
 }
-
 internal interface UniffiForeignFutureFree : com.sun.jna.Callback {
-    fun callback(`handle`: Long)
+    fun callback(`handle`: Long,)
 }
-
 internal interface UniffiCallbackInterfaceFree : com.sun.jna.Callback {
-    fun callback(`handle`: Long)
+    fun callback(`handle`: Long,)
 }
-
 @Structure.FieldOrder("handle", "free")
 internal open class UniffiForeignFuture(
     @JvmField internal var `handle`: Long = 0.toLong(),
@@ -431,15 +401,14 @@ internal open class UniffiForeignFuture(
     class UniffiByValue(
         `handle`: Long = 0.toLong(),
         `free`: UniffiForeignFutureFree? = null,
-    ) : UniffiForeignFuture(`handle`, `free`),
-        Structure.ByValue
+    ): UniffiForeignFuture(`handle`,`free`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFuture) {
+   internal fun uniffiSetValue(other: UniffiForeignFuture) {
         `handle` = other.`handle`
         `free` = other.`free`
     }
-}
 
+}
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructU8(
     @JvmField internal var `returnValue`: Byte = 0.toByte(),
@@ -448,22 +417,17 @@ internal open class UniffiForeignFutureStructU8(
     class UniffiByValue(
         `returnValue`: Byte = 0.toByte(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructU8(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructU8(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructU8) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructU8) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU8 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructU8.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructU8.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructI8(
     @JvmField internal var `returnValue`: Byte = 0.toByte(),
@@ -472,22 +436,17 @@ internal open class UniffiForeignFutureStructI8(
     class UniffiByValue(
         `returnValue`: Byte = 0.toByte(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructI8(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructI8(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructI8) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructI8) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI8 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructI8.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructI8.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructU16(
     @JvmField internal var `returnValue`: Short = 0.toShort(),
@@ -496,22 +455,17 @@ internal open class UniffiForeignFutureStructU16(
     class UniffiByValue(
         `returnValue`: Short = 0.toShort(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructU16(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructU16(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructU16) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructU16) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU16 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructU16.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructU16.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructI16(
     @JvmField internal var `returnValue`: Short = 0.toShort(),
@@ -520,22 +474,17 @@ internal open class UniffiForeignFutureStructI16(
     class UniffiByValue(
         `returnValue`: Short = 0.toShort(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructI16(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructI16(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructI16) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructI16) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI16 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructI16.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructI16.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructU32(
     @JvmField internal var `returnValue`: Int = 0,
@@ -544,22 +493,17 @@ internal open class UniffiForeignFutureStructU32(
     class UniffiByValue(
         `returnValue`: Int = 0,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructU32(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructU32(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructU32) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructU32) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU32 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructU32.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructU32.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructI32(
     @JvmField internal var `returnValue`: Int = 0,
@@ -568,22 +512,17 @@ internal open class UniffiForeignFutureStructI32(
     class UniffiByValue(
         `returnValue`: Int = 0,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructI32(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructI32(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructI32) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructI32) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI32 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructI32.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructI32.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructU64(
     @JvmField internal var `returnValue`: Long = 0.toLong(),
@@ -592,22 +531,17 @@ internal open class UniffiForeignFutureStructU64(
     class UniffiByValue(
         `returnValue`: Long = 0.toLong(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructU64(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructU64(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructU64) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructU64) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU64 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructU64.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructU64.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructI64(
     @JvmField internal var `returnValue`: Long = 0.toLong(),
@@ -616,22 +550,17 @@ internal open class UniffiForeignFutureStructI64(
     class UniffiByValue(
         `returnValue`: Long = 0.toLong(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructI64(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructI64(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructI64) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructI64) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI64 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructI64.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructI64.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructF32(
     @JvmField internal var `returnValue`: Float = 0.0f,
@@ -640,22 +569,17 @@ internal open class UniffiForeignFutureStructF32(
     class UniffiByValue(
         `returnValue`: Float = 0.0f,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructF32(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructF32(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructF32) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructF32) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteF32 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructF32.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructF32.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructF64(
     @JvmField internal var `returnValue`: Double = 0.0,
@@ -664,22 +588,17 @@ internal open class UniffiForeignFutureStructF64(
     class UniffiByValue(
         `returnValue`: Double = 0.0,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructF64(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructF64(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructF64) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructF64) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteF64 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructF64.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructF64.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructPointer(
     @JvmField internal var `returnValue`: Pointer = Pointer.NULL,
@@ -688,22 +607,17 @@ internal open class UniffiForeignFutureStructPointer(
     class UniffiByValue(
         `returnValue`: Pointer = Pointer.NULL,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructPointer(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructPointer(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructPointer) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructPointer) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompletePointer : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructPointer.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructPointer.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructRustBuffer(
     @JvmField internal var `returnValue`: RustBuffer.ByValue = RustBuffer.ByValue(),
@@ -712,1073 +626,3027 @@ internal open class UniffiForeignFutureStructRustBuffer(
     class UniffiByValue(
         `returnValue`: RustBuffer.ByValue = RustBuffer.ByValue(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructRustBuffer(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructRustBuffer(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructRustBuffer) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructRustBuffer) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteRustBuffer : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructRustBuffer.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructRustBuffer.UniffiByValue,)
 }
-
 @Structure.FieldOrder("callStatus")
 internal open class UniffiForeignFutureStructVoid(
     @JvmField internal var `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
 ) : Structure() {
     class UniffiByValue(
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructVoid(`callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructVoid(`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructVoid) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructVoid) {
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteVoid : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructVoid.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructVoid.UniffiByValue,)
 }
 
-// For large crates we prevent `MethodTooLargeException` (see #2340)
-// N.B. the name of the extension is very misleading, since it is
-// rather `InterfaceTooLargeException`, caused by too many methods
-// in the interface for large crates.
-//
-// By splitting the otherwise huge interface into two parts
-// * UniffiLib
-// * IntegrityCheckingUniffiLib (this)
-// we allow for ~2x as many methods in the UniffiLib interface.
-//
-// The `ffi_uniffi_contract_version` method and all checksum methods are put
-// into `IntegrityCheckingUniffiLib` and these methods are called only once,
-// when the library is loaded.
-internal interface IntegrityCheckingUniffiLib : Library {
-    // Integrity check functions only
-    fun uniffi_mopro_r0_example_app_checksum_func_generate_circom_proof(): Short
 
-    fun uniffi_mopro_r0_example_app_checksum_func_generate_halo2_proof(): Short
 
-    fun uniffi_mopro_r0_example_app_checksum_func_generate_noir_proof(): Short
 
-    fun uniffi_mopro_r0_example_app_checksum_func_get_noir_verification_key(): Short
 
-    fun uniffi_mopro_r0_example_app_checksum_func_risc0_prove(): Short
 
-    fun uniffi_mopro_r0_example_app_checksum_func_risc0_verify(): Short
 
-    fun uniffi_mopro_r0_example_app_checksum_func_verify_circom_proof(): Short
 
-    fun uniffi_mopro_r0_example_app_checksum_func_verify_halo2_proof(): Short
 
-    fun uniffi_mopro_r0_example_app_checksum_func_verify_noir_proof(): Short
 
-    fun ffi_mopro_r0_example_app_uniffi_contract_version(): Int
-}
 
-// A JNA Library to expose the extern-C FFI definitions.
-// This is an implementation detail which will be called internally by the public API.
-internal interface UniffiLib : Library {
-    companion object {
-        internal val INSTANCE: UniffiLib by lazy {
-            val componentName = "mopro_r0_example_app"
-            // For large crates we prevent `MethodTooLargeException` (see #2340)
-            // N.B. the name of the extension is very misleading, since it is
-            // rather `InterfaceTooLargeException`, caused by too many methods
-            // in the interface for large crates.
-            //
-            // By splitting the otherwise huge interface into two parts
-            // * UniffiLib (this)
-            // * IntegrityCheckingUniffiLib
-            // And all checksum methods are put into `IntegrityCheckingUniffiLib`
-            // we allow for ~2x as many methods in the UniffiLib interface.
-            //
-            // Thus we first load the library with `loadIndirect` as `IntegrityCheckingUniffiLib`
-            // so that we can (optionally!) call `uniffiCheckApiChecksums`...
-            loadIndirect<IntegrityCheckingUniffiLib>(componentName)
-                .also { lib: IntegrityCheckingUniffiLib ->
-                    uniffiCheckContractApiVersion(lib)
-                    uniffiCheckApiChecksums(lib)
-                }
-            // ... and then we load the library as `UniffiLib`
-            // N.B. we cannot use `loadIndirect` once and then try to cast it to `UniffiLib`
-            // => results in `java.lang.ClassCastException: com.sun.proxy.$Proxy cannot be cast to ...`
-            // error. So we must call `loadIndirect` twice. For crates large enough
-            // to trigger this issue, the performance impact is negligible, running on
-            // a macOS M1 machine the `loadIndirect` call takes ~50ms.
-            val lib = loadIndirect<UniffiLib>(componentName)
-            // No need to check the contract version and checksums, since
-            // we already did that with `IntegrityCheckingUniffiLib` above.
-            // Loading of library with integrity check done.
-            lib
-        }
-    }
 
-    // FFI functions
-    fun uniffi_mopro_r0_example_app_fn_func_generate_circom_proof(
-        `zkeyPath`: RustBuffer.ByValue,
-        `circuitInputs`: RustBuffer.ByValue,
-        `proofLib`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
-
-    fun uniffi_mopro_r0_example_app_fn_func_generate_halo2_proof(
-        `srsPath`: RustBuffer.ByValue,
-        `pkPath`: RustBuffer.ByValue,
-        `circuitInputs`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
-
-    fun uniffi_mopro_r0_example_app_fn_func_generate_noir_proof(
-        `circuitPath`: RustBuffer.ByValue,
-        `srsPath`: RustBuffer.ByValue,
-        `inputs`: RustBuffer.ByValue,
-        `onChain`: Byte,
-        `vk`: RustBuffer.ByValue,
-        `lowMemoryMode`: Byte,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
-
-    fun uniffi_mopro_r0_example_app_fn_func_get_noir_verification_key(
-        `circuitPath`: RustBuffer.ByValue,
-        `srsPath`: RustBuffer.ByValue,
-        `onChain`: Byte,
-        `lowMemoryMode`: Byte,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
-
-    fun uniffi_mopro_r0_example_app_fn_func_risc0_prove(
-        `message`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
-
-    fun uniffi_mopro_r0_example_app_fn_func_risc0_verify(
-        `receiptBytes`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
-
-    fun uniffi_mopro_r0_example_app_fn_func_verify_circom_proof(
-        `zkeyPath`: RustBuffer.ByValue,
-        `proofResult`: RustBuffer.ByValue,
-        `proofLib`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
-
-    fun uniffi_mopro_r0_example_app_fn_func_verify_halo2_proof(
-        `srsPath`: RustBuffer.ByValue,
-        `vkPath`: RustBuffer.ByValue,
-        `proof`: RustBuffer.ByValue,
-        `publicInput`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
-
-    fun uniffi_mopro_r0_example_app_fn_func_verify_noir_proof(
-        `circuitPath`: RustBuffer.ByValue,
-        `proof`: RustBuffer.ByValue,
-        `onChain`: Byte,
-        `vk`: RustBuffer.ByValue,
-        `lowMemoryMode`: Byte,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
-
-    fun ffi_mopro_r0_example_app_rustbuffer_alloc(
-        `size`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
-
-    fun ffi_mopro_r0_example_app_rustbuffer_from_bytes(
-        `bytes`: ForeignBytes.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
-
-    fun ffi_mopro_r0_example_app_rustbuffer_free(
-        `buf`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Unit
-
-    fun ffi_mopro_r0_example_app_rustbuffer_reserve(
-        `buf`: RustBuffer.ByValue,
-        `additional`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
-
-    fun ffi_mopro_r0_example_app_rust_future_poll_u8(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
-
-    fun ffi_mopro_r0_example_app_rust_future_cancel_u8(`handle`: Long): Unit
-
-    fun ffi_mopro_r0_example_app_rust_future_free_u8(`handle`: Long): Unit
-
-    fun ffi_mopro_r0_example_app_rust_future_complete_u8(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
-
-    fun ffi_mopro_r0_example_app_rust_future_poll_i8(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
-
-    fun ffi_mopro_r0_example_app_rust_future_cancel_i8(`handle`: Long): Unit
-
-    fun ffi_mopro_r0_example_app_rust_future_free_i8(`handle`: Long): Unit
-
-    fun ffi_mopro_r0_example_app_rust_future_complete_i8(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
-
-    fun ffi_mopro_r0_example_app_rust_future_poll_u16(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
-
-    fun ffi_mopro_r0_example_app_rust_future_cancel_u16(`handle`: Long): Unit
-
-    fun ffi_mopro_r0_example_app_rust_future_free_u16(`handle`: Long): Unit
-
-    fun ffi_mopro_r0_example_app_rust_future_complete_u16(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Short
-
-    fun ffi_mopro_r0_example_app_rust_future_poll_i16(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
-
-    fun ffi_mopro_r0_example_app_rust_future_cancel_i16(`handle`: Long): Unit
-
-    fun ffi_mopro_r0_example_app_rust_future_free_i16(`handle`: Long): Unit
-
-    fun ffi_mopro_r0_example_app_rust_future_complete_i16(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Short
 
-    fun ffi_mopro_r0_example_app_rust_future_poll_u32(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_cancel_u32(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_free_u32(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_complete_u32(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Int
 
-    fun ffi_mopro_r0_example_app_rust_future_poll_i32(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_cancel_i32(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_free_i32(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_complete_i32(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Int
 
-    fun ffi_mopro_r0_example_app_rust_future_poll_u64(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_cancel_u64(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_free_u64(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_complete_u64(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Long
 
-    fun ffi_mopro_r0_example_app_rust_future_poll_i64(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_cancel_i64(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_free_i64(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_complete_i64(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Long
 
-    fun ffi_mopro_r0_example_app_rust_future_poll_f32(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_cancel_f32(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_free_f32(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_complete_f32(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Float
 
-    fun ffi_mopro_r0_example_app_rust_future_poll_f64(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_cancel_f64(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_free_f64(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_complete_f64(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Double
 
-    fun ffi_mopro_r0_example_app_rust_future_poll_pointer(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_cancel_pointer(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_free_pointer(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_complete_pointer(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Pointer
 
-    fun ffi_mopro_r0_example_app_rust_future_poll_rust_buffer(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_cancel_rust_buffer(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_free_rust_buffer(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_complete_rust_buffer(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun ffi_mopro_r0_example_app_rust_future_poll_void(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_cancel_void(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_free_void(`handle`: Long): Unit
 
-    fun ffi_mopro_r0_example_app_rust_future_complete_void(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Unit
-}
 
-private fun uniffiCheckContractApiVersion(lib: IntegrityCheckingUniffiLib) {
-    // Get the bindings contract version from our ComponentInterface
-    val bindings_contract_version = 29
-    // Get the scaffolding contract version by calling the into the dylib
-    val scaffolding_contract_version = lib.ffi_mopro_r0_example_app_uniffi_contract_version()
-    if (bindings_contract_version != scaffolding_contract_version) {
-        throw RuntimeException("UniFFI contract version mismatch: try cleaning and rebuilding your project")
-    }
-}
 
-@Suppress("UNUSED_PARAMETER")
-private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
-    if (lib.uniffi_mopro_r0_example_app_checksum_func_generate_circom_proof() != 15503.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mopro_r0_example_app_checksum_func_generate_halo2_proof() != 24703.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mopro_r0_example_app_checksum_func_generate_noir_proof() != 46965.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mopro_r0_example_app_checksum_func_get_noir_verification_key() != 28810.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_prove() != 42798.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_verify() != 34311.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mopro_r0_example_app_checksum_func_verify_circom_proof() != 51913.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mopro_r0_example_app_checksum_func_verify_halo2_proof() != 7235.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mopro_r0_example_app_checksum_func_verify_noir_proof() != 55859.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-}
 
-/**
- * @suppress
- */
-public fun uniffiEnsureInitialized() {
-    UniffiLib.INSTANCE
-}
 
-// Async support
 
-// Public interface members begin here.
 
-// Interface implemented by anything that can contain an object reference.
-//
-// Such types expose a `destroy()` method that must be called to cleanly
-// dispose of the contained objects. Failure to call this method may result
-// in memory leaks.
-//
-// The easiest way to ensure this method is called is to use the `.use`
-// helper method to execute a block and destroy the object at the end.
-interface Disposable {
-    fun destroy()
 
-    companion object {
-        fun destroy(vararg args: Any?) {
-            args
-                .filterIsInstance<Disposable>()
-                .forEach(Disposable::destroy)
-        }
-    }
-}
 
-/**
- * @suppress
- */
-inline fun <T : Disposable?, R> T.use(block: (T) -> R) =
-    try {
-        block(this)
-    } finally {
-        try {
-            // N.B. our implementation is on the nullable type `Disposable?`.
-            this?.destroy()
-        } catch (e: Throwable) {
-            // swallow
-        }
-    }
 
-/**
- * Used to instantiate an interface without an actual pointer, for fakes in tests, mostly.
- *
- * @suppress
- * */
-object NoPointer
 
-/**
- * @suppress
- */
-public object FfiConverterBoolean : FfiConverter<Boolean, Byte> {
-    override fun lift(value: Byte): Boolean = value.toInt() != 0
 
-    override fun read(buf: ByteBuffer): Boolean = lift(buf.get())
 
-    override fun lower(value: Boolean): Byte = if (value) 1.toByte() else 0.toByte()
 
-    override fun allocationSize(value: Boolean) = 1UL
 
-    override fun write(
-        value: Boolean,
-        buf: ByteBuffer,
-    ) {
-        buf.put(lower(value))
-    }
-}
 
-/**
- * @suppress
- */
-public object FfiConverterString : FfiConverter<String, RustBuffer.ByValue> {
-    // Note: we don't inherit from FfiConverterRustBuffer, because we use a
-    // special encoding when lowering/lifting.  We can use `RustBuffer.len` to
-    // store our length and avoid writing it out to the buffer.
-    override fun lift(value: RustBuffer.ByValue): String {
-        try {
-            val byteArr = ByteArray(value.len.toInt())
-            value.asByteBuffer()!!.get(byteArr)
-            return byteArr.toString(Charsets.UTF_8)
-        } finally {
-            RustBuffer.free(value)
-        }
-    }
 
-    override fun read(buf: ByteBuffer): String {
-        val len = buf.getInt()
-        val byteArr = ByteArray(len)
-        buf.get(byteArr)
-        return byteArr.toString(Charsets.UTF_8)
-    }
 
-    fun toUtf8(value: String): ByteBuffer {
-        // Make sure we don't have invalid UTF-16, check for lone surrogates.
-        return Charsets.UTF_8.newEncoder().run {
-            onMalformedInput(CodingErrorAction.REPORT)
-            encode(CharBuffer.wrap(value))
-        }
-    }
 
-    override fun lower(value: String): RustBuffer.ByValue {
-        val byteBuf = toUtf8(value)
-        // Ideally we'd pass these bytes to `ffi_bytebuffer_from_bytes`, but doing so would require us
-        // to copy them into a JNA `Memory`. So we might as well directly copy them into a `RustBuffer`.
-        val rbuf = RustBuffer.alloc(byteBuf.limit().toULong())
-        rbuf.asByteBuffer()!!.put(byteBuf)
-        return rbuf
-    }
 
-    // We aren't sure exactly how many bytes our string will be once it's UTF-8
-    // encoded.  Allocate 3 bytes per UTF-16 code unit which will always be
-    // enough.
-    override fun allocationSize(value: String): ULong {
-        val sizeForLength = 4UL
-        val sizeForString = value.length.toULong() * 3UL
-        return sizeForLength + sizeForString
-    }
 
-    override fun write(
-        value: String,
-        buf: ByteBuffer,
-    ) {
-        val byteBuf = toUtf8(value)
-        buf.putInt(byteBuf.limit())
-        buf.put(byteBuf)
-    }
-}
 
-/**
- * @suppress
- */
-public object FfiConverterByteArray : FfiConverterRustBuffer<ByteArray> {
-    override fun read(buf: ByteBuffer): ByteArray {
-        val len = buf.getInt()
-        val byteArr = ByteArray(len)
-        buf.get(byteArr)
-        return byteArr
-    }
 
-    override fun allocationSize(value: ByteArray): ULong = 4UL + value.size.toULong()
 
-    override fun write(
-        value: ByteArray,
-        buf: ByteBuffer,
-    ) {
-        buf.putInt(value.size)
-        buf.put(value)
-    }
-}
 
-data class CircomProof(
-    var `a`: G1,
-    var `b`: G2,
-    var `c`: G1,
-    var `protocol`: kotlin.String,
-    var `curve`: kotlin.String,
-) {
-    companion object
-}
 
-/**
- * @suppress
- */
-public object FfiConverterTypeCircomProof : FfiConverterRustBuffer<CircomProof> {
-    override fun read(buf: ByteBuffer): CircomProof =
-        CircomProof(
-            FfiConverterTypeG1.read(buf),
-            FfiConverterTypeG2.read(buf),
-            FfiConverterTypeG1.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-        )
 
-    override fun allocationSize(value: CircomProof) =
-        (
-            FfiConverterTypeG1.allocationSize(value.`a`) +
-                FfiConverterTypeG2.allocationSize(value.`b`) +
-                FfiConverterTypeG1.allocationSize(value.`c`) +
-                FfiConverterString.allocationSize(value.`protocol`) +
-                FfiConverterString.allocationSize(value.`curve`)
-        )
 
-    override fun write(
-        value: CircomProof,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterTypeG1.write(value.`a`, buf)
-        FfiConverterTypeG2.write(value.`b`, buf)
-        FfiConverterTypeG1.write(value.`c`, buf)
-        FfiConverterString.write(value.`protocol`, buf)
-        FfiConverterString.write(value.`curve`, buf)
-    }
-}
 
-data class CircomProofResult(
-    var `proof`: CircomProof,
-    var `inputs`: List<kotlin.String>,
-) {
-    companion object
-}
 
-/**
- * @suppress
- */
-public object FfiConverterTypeCircomProofResult : FfiConverterRustBuffer<CircomProofResult> {
-    override fun read(buf: ByteBuffer): CircomProofResult =
-        CircomProofResult(
-            FfiConverterTypeCircomProof.read(buf),
-            FfiConverterSequenceString.read(buf),
-        )
 
-    override fun allocationSize(value: CircomProofResult) =
-        (
-            FfiConverterTypeCircomProof.allocationSize(value.`proof`) +
-                FfiConverterSequenceString.allocationSize(value.`inputs`)
-        )
 
-    override fun write(
-        value: CircomProofResult,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterTypeCircomProof.write(value.`proof`, buf)
-        FfiConverterSequenceString.write(value.`inputs`, buf)
-    }
-}
 
-data class G1(
-    var `x`: kotlin.String,
-    var `y`: kotlin.String,
-    var `z`: kotlin.String,
-) {
-    companion object
-}
 
-/**
- * @suppress
- */
-public object FfiConverterTypeG1 : FfiConverterRustBuffer<G1> {
-    override fun read(buf: ByteBuffer): G1 =
-        G1(
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-        )
 
-    override fun allocationSize(value: G1) =
-        (
-            FfiConverterString.allocationSize(value.`x`) +
-                FfiConverterString.allocationSize(value.`y`) +
-                FfiConverterString.allocationSize(value.`z`)
-        )
 
-    override fun write(
-        value: G1,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`x`, buf)
-        FfiConverterString.write(value.`y`, buf)
-        FfiConverterString.write(value.`z`, buf)
-    }
-}
 
-data class G2(
-    var `x`: List<kotlin.String>,
-    var `y`: List<kotlin.String>,
-    var `z`: List<kotlin.String>,
-) {
-    companion object
-}
 
-/**
- * @suppress
- */
-public object FfiConverterTypeG2 : FfiConverterRustBuffer<G2> {
-    override fun read(buf: ByteBuffer): G2 =
-        G2(
-            FfiConverterSequenceString.read(buf),
-            FfiConverterSequenceString.read(buf),
-            FfiConverterSequenceString.read(buf),
-        )
 
-    override fun allocationSize(value: G2) =
-        (
-            FfiConverterSequenceString.allocationSize(value.`x`) +
-                FfiConverterSequenceString.allocationSize(value.`y`) +
-                FfiConverterSequenceString.allocationSize(value.`z`)
-        )
 
-    override fun write(
-        value: G2,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterSequenceString.write(value.`x`, buf)
-        FfiConverterSequenceString.write(value.`y`, buf)
-        FfiConverterSequenceString.write(value.`z`, buf)
-    }
-}
 
-data class Halo2ProofResult(
-    var `proof`: kotlin.ByteArray,
-    var `inputs`: kotlin.ByteArray,
-) {
-    companion object
-}
 
-/**
- * @suppress
- */
-public object FfiConverterTypeHalo2ProofResult : FfiConverterRustBuffer<Halo2ProofResult> {
-    override fun read(buf: ByteBuffer): Halo2ProofResult =
-        Halo2ProofResult(
-            FfiConverterByteArray.read(buf),
-            FfiConverterByteArray.read(buf),
-        )
 
-    override fun allocationSize(value: Halo2ProofResult) =
-        (
-            FfiConverterByteArray.allocationSize(value.`proof`) +
-                FfiConverterByteArray.allocationSize(value.`inputs`)
-        )
 
-    override fun write(
-        value: Halo2ProofResult,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterByteArray.write(value.`proof`, buf)
-        FfiConverterByteArray.write(value.`inputs`, buf)
-    }
-}
 
-data class Risc0ProofOutput(
-    var `receipt`: kotlin.ByteArray,
-) {
-    companion object
-}
 
-/**
- * @suppress
- */
-public object FfiConverterTypeRisc0ProofOutput : FfiConverterRustBuffer<Risc0ProofOutput> {
-    override fun read(buf: ByteBuffer): Risc0ProofOutput =
-        Risc0ProofOutput(
-            FfiConverterByteArray.read(buf),
-        )
 
-    override fun allocationSize(value: Risc0ProofOutput) =
-        (
-            FfiConverterByteArray.allocationSize(value.`receipt`)
-        )
 
-    override fun write(
-        value: Risc0ProofOutput,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterByteArray.write(value.`receipt`, buf)
-    }
-}
 
-data class Risc0VerifyOutput(
-    var `isValid`: kotlin.Boolean,
-    var `verifiedMessage`: kotlin.String,
-) {
-    companion object
-}
 
-/**
- * @suppress
- */
-public object FfiConverterTypeRisc0VerifyOutput : FfiConverterRustBuffer<Risc0VerifyOutput> {
-    override fun read(buf: ByteBuffer): Risc0VerifyOutput =
-        Risc0VerifyOutput(
-            FfiConverterBoolean.read(buf),
-            FfiConverterString.read(buf),
-        )
 
-    override fun allocationSize(value: Risc0VerifyOutput) =
-        (
-            FfiConverterBoolean.allocationSize(value.`isValid`) +
-                FfiConverterString.allocationSize(value.`verifiedMessage`)
-        )
 
-    override fun write(
-        value: Risc0VerifyOutput,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterBoolean.write(value.`isValid`, buf)
-        FfiConverterString.write(value.`verifiedMessage`, buf)
-    }
-}
 
-sealed class MoproException : kotlin.Exception() {
-    class CircomException(
-        val v1: kotlin.String,
-    ) : MoproException() {
-        override val message
-            get() = "v1=${ v1 }"
-    }
 
-    class Halo2Exception(
-        val v1: kotlin.String,
-    ) : MoproException() {
-        override val message
-            get() = "v1=${ v1 }"
-    }
 
-    class NoirException(
-        val v1: kotlin.String,
-    ) : MoproException() {
-        override val message
-            get() = "v1=${ v1 }"
-    }
 
-    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<MoproException> {
-        override fun lift(error_buf: RustBuffer.ByValue): MoproException = FfiConverterTypeMoproError.lift(error_buf)
-    }
-}
 
-/**
- * @suppress
- */
-public object FfiConverterTypeMoproError : FfiConverterRustBuffer<MoproException> {
-    override fun read(buf: ByteBuffer): MoproException =
-        when (buf.getInt()) {
-            1 ->
-                MoproException.CircomException(
-                    FfiConverterString.read(buf),
-                )
-            2 ->
-                MoproException.Halo2Exception(
-                    FfiConverterString.read(buf),
-                )
-            3 ->
-                MoproException.NoirException(
-                    FfiConverterString.read(buf),
-                )
-            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
-        }
 
-    override fun allocationSize(value: MoproException): ULong =
-        when (value) {
-            is MoproException.CircomException -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL +
-                    FfiConverterString.allocationSize(value.v1)
-            )
-            is MoproException.Halo2Exception -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL +
-                    FfiConverterString.allocationSize(value.v1)
-            )
-            is MoproException.NoirException -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL +
-                    FfiConverterString.allocationSize(value.v1)
-            )
-        }
 
-    override fun write(
-        value: MoproException,
-        buf: ByteBuffer,
-    ) {
-        when (value) {
-            is MoproException.CircomException -> {
-                buf.putInt(1)
-                FfiConverterString.write(value.v1, buf)
-                Unit
-            }
-            is MoproException.Halo2Exception -> {
-                buf.putInt(2)
-                FfiConverterString.write(value.v1, buf)
-                Unit
-            }
-            is MoproException.NoirException -> {
-                buf.putInt(3)
-                FfiConverterString.write(value.v1, buf)
-                Unit
-            }
-        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
-    }
-}
 
-enum class ProofLib {
-    ARKWORKS,
-    RAPIDSNARK,
-    ;
 
-    companion object
-}
 
-/**
- * @suppress
- */
-public object FfiConverterTypeProofLib : FfiConverterRustBuffer<ProofLib> {
-    override fun read(buf: ByteBuffer) =
-        try {
-            ProofLib.values()[buf.getInt() - 1]
-        } catch (e: IndexOutOfBoundsException) {
-            throw RuntimeException("invalid enum value, something is very wrong!!", e)
-        }
 
-    override fun allocationSize(value: ProofLib) = 4UL
 
-    override fun write(
-        value: ProofLib,
-        buf: ByteBuffer,
-    ) {
-        buf.putInt(value.ordinal + 1)
-    }
-}
 
-sealed class Risc0Exception : kotlin.Exception() {
-    class ProveException(
-        val v1: kotlin.String,
-    ) : Risc0Exception() {
-        override val message
-            get() = "v1=${ v1 }"
-    }
 
-    class SerializeException(
-        val v1: kotlin.String,
-    ) : Risc0Exception() {
-        override val message
-            get() = "v1=${ v1 }"
-    }
 
-    class VerifyException(
-        val v1: kotlin.String,
-    ) : Risc0Exception() {
-        override val message
-            get() = "v1=${ v1 }"
-    }
 
-    class DecodeException(
-        val v1: kotlin.String,
-    ) : Risc0Exception() {
-        override val message
-            get() = "v1=${ v1 }"
-    }
 
-    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<Risc0Exception> {
-        override fun lift(error_buf: RustBuffer.ByValue): Risc0Exception = FfiConverterTypeRisc0Error.lift(error_buf)
-    }
-}
 
-/**
- * @suppress
- */
-public object FfiConverterTypeRisc0Error : FfiConverterRustBuffer<Risc0Exception> {
-    override fun read(buf: ByteBuffer): Risc0Exception =
-        when (buf.getInt()) {
-            1 ->
-                Risc0Exception.ProveException(
-                    FfiConverterString.read(buf),
-                )
-            2 ->
-                Risc0Exception.SerializeException(
-                    FfiConverterString.read(buf),
-                )
-            3 ->
-                Risc0Exception.VerifyException(
-                    FfiConverterString.read(buf),
-                )
-            4 ->
-                Risc0Exception.DecodeException(
-                    FfiConverterString.read(buf),
-                )
-            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
-        }
 
-    override fun allocationSize(value: Risc0Exception): ULong =
-        when (value) {
-            is Risc0Exception.ProveException -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL +
-                    FfiConverterString.allocationSize(value.v1)
-            )
-            is Risc0Exception.SerializeException -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL +
-                    FfiConverterString.allocationSize(value.v1)
-            )
-            is Risc0Exception.VerifyException -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL +
-                    FfiConverterString.allocationSize(value.v1)
-            )
-            is Risc0Exception.DecodeException -> (
-                // Add the size for the Int that specifies the variant plus the size needed for all fields
-                4UL +
-                    FfiConverterString.allocationSize(value.v1)
-            )
-        }
 
-    override fun write(
-        value: Risc0Exception,
-        buf: ByteBuffer,
-    ) {
-        when (value) {
-            is Risc0Exception.ProveException -> {
-                buf.putInt(1)
-                FfiConverterString.write(value.v1, buf)
-                Unit
-            }
-            is Risc0Exception.SerializeException -> {
-                buf.putInt(2)
-                FfiConverterString.write(value.v1, buf)
-                Unit
-            }
-            is Risc0Exception.VerifyException -> {
-                buf.putInt(3)
-                FfiConverterString.write(value.v1, buf)
-                Unit
-            }
-            is Risc0Exception.DecodeException -> {
-                buf.putInt(4)
-                FfiConverterString.write(value.v1, buf)
-                Unit
-            }
-        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
-    }
-}
 
-/**
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+// For large crates we prevent `MethodTooLargeException` (see #2340)
+// N.B. the name of the extension is very misleading, since it is 
+// rather `InterfaceTooLargeException`, caused by too many methods 
+// in the interface for large crates.
+//
+// By splitting the otherwise huge interface into two parts
+// * UniffiLib 
+// * IntegrityCheckingUniffiLib (this)
+// we allow for ~2x as many methods in the UniffiLib interface.
+// 
+// The `ffi_uniffi_contract_version` method and all checksum methods are put 
+// into `IntegrityCheckingUniffiLib` and these methods are called only once,
+// when the library is loaded.
+internal interface IntegrityCheckingUniffiLib : Library {
+    // Integrity check functions only
+    fun uniffi_mopro_r0_example_app_checksum_func_ed25519_prove(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_ed25519_prove_signature(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_ed25519_verify(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_eth_encode_typed_address(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_eth_encode_typed_bool(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_eth_encode_typed_bytes(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_eth_encode_typed_string(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_eth_encode_typed_uint(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_eth_hash_typed_struct(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_eth_prove_personal_sign(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_eth_prove_typed_data(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_eth_typed_data_domain_separator(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_eth_verify_personal_sign(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_eth_verify_typed_data(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_generate_circom_proof(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_generate_halo2_proof(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_generate_noir_proof(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_get_noir_verification_key(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_p384_prove(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_p384_prove_signature(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_p384_verify(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_aggregate(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_aggregate_root(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_batch_root(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_chain_message(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_commit_public_key(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_compress_receipt(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_context_message(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_generate_challenge(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_generate_salt(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_key_set_root(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_open_key_commitment(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_prove(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_prove_batch(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_prove_bytes(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_prove_chain_link(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_prove_hidden_message(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_prove_in_context(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_prove_key_commitment(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_prove_membership(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_prove_signature(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_prove_signature_with_mode(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_prove_threshold(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_prove_unrevoked(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_prove_with_challenge(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_prove_with_validity(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_revocation_root(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_verify(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_verify_aggregate(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_verify_batch(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_verify_bytes(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_verify_chain(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_verify_hidden_message(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_verify_in_context(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_verify_key_commitment(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_verify_membership(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_verify_prehashed(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_verify_threshold(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_verify_unrevoked(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_verify_with_challenge(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_verify_with_policy(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_risc0_verify_with_validity(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_rsa_modulus_hash(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_rsa_prove(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_rsa_prove_signature(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_rsa_verify(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_schnorr_prove(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_schnorr_prove_signature(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_schnorr_verify(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_secp256k1_prove(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_secp256k1_prove_signature(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_secp256k1_verify(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_verify_circom_proof(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_verify_halo2_proof(
+): Short
+fun uniffi_mopro_r0_example_app_checksum_func_verify_noir_proof(
+): Short
+fun ffi_mopro_r0_example_app_uniffi_contract_version(
+): Int
+
+}
+
+// A JNA Library to expose the extern-C FFI definitions.
+// This is an implementation detail which will be called internally by the public API.
+internal interface UniffiLib : Library {
+    companion object {
+        internal val INSTANCE: UniffiLib by lazy {
+            val componentName = "mopro_r0_example_app"
+            // For large crates we prevent `MethodTooLargeException` (see #2340)
+            // N.B. the name of the extension is very misleading, since it is 
+            // rather `InterfaceTooLargeException`, caused by too many methods 
+            // in the interface for large crates.
+            //
+            // By splitting the otherwise huge interface into two parts
+            // * UniffiLib (this)
+            // * IntegrityCheckingUniffiLib
+            // And all checksum methods are put into `IntegrityCheckingUniffiLib`
+            // we allow for ~2x as many methods in the UniffiLib interface.
+            // 
+            // Thus we first load the library with `loadIndirect` as `IntegrityCheckingUniffiLib`
+            // so that we can (optionally!) call `uniffiCheckApiChecksums`...
+            loadIndirect<IntegrityCheckingUniffiLib>(componentName)
+                .also { lib: IntegrityCheckingUniffiLib ->
+                    uniffiCheckContractApiVersion(lib)
+                    uniffiCheckApiChecksums(lib)
+                }
+            // ... and then we load the library as `UniffiLib`
+            // N.B. we cannot use `loadIndirect` once and then try to cast it to `UniffiLib`
+            // => results in `java.lang.ClassCastException: com.sun.proxy.$Proxy cannot be cast to ...`
+            // error. So we must call `loadIndirect` twice. For crates large enough
+            // to trigger this issue, the performance impact is negligible, running on
+            // a macOS M1 machine the `loadIndirect` call takes ~50ms.
+            val lib = loadIndirect<UniffiLib>(componentName)
+            // No need to check the contract version and checksums, since 
+            // we already did that with `IntegrityCheckingUniffiLib` above.
+            // Loading of library with integrity check done.
+            lib
+        }
+        
+    }
+
+    // FFI functions
+    fun uniffi_mopro_r0_example_app_fn_func_ed25519_prove(`message`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_ed25519_prove_signature(`publicKey`: RustBuffer.ByValue,`message`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_ed25519_verify(`receiptBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_eth_encode_typed_address(`address`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_eth_encode_typed_bool(`value`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_eth_encode_typed_bytes(`value`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_eth_encode_typed_string(`value`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_eth_encode_typed_uint(`value`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_eth_hash_typed_struct(`structType`: RustBuffer.ByValue,`encodedFields`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_eth_prove_personal_sign(`message`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_eth_prove_typed_data(`domain`: RustBuffer.ByValue,`structType`: RustBuffer.ByValue,`encodedFields`: RustBuffer.ByValue,`disclosedFields`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_eth_typed_data_domain_separator(`domain`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_eth_verify_personal_sign(`receiptBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_eth_verify_typed_data(`receiptBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_generate_circom_proof(`zkeyPath`: RustBuffer.ByValue,`circuitInputs`: RustBuffer.ByValue,`proofLib`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_generate_halo2_proof(`srsPath`: RustBuffer.ByValue,`pkPath`: RustBuffer.ByValue,`circuitInputs`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_generate_noir_proof(`circuitPath`: RustBuffer.ByValue,`srsPath`: RustBuffer.ByValue,`inputs`: RustBuffer.ByValue,`onChain`: Byte,`vk`: RustBuffer.ByValue,`lowMemoryMode`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_get_noir_verification_key(`circuitPath`: RustBuffer.ByValue,`srsPath`: RustBuffer.ByValue,`onChain`: Byte,`lowMemoryMode`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_p384_prove(`message`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_p384_prove_signature(`publicKey`: RustBuffer.ByValue,`message`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_p384_verify(`receiptBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_aggregate(`receipts`: RustBuffer.ByValue,`commitJournals`: Byte,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_aggregate_root(`receipts`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_batch_root(`publicKeys`: RustBuffer.ByValue,`messages`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_chain_message(`previousHead`: RustBuffer.ByValue,`payload`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_commit_public_key(`publicKey`: RustBuffer.ByValue,`salt`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_compress_receipt(`receiptBytes`: RustBuffer.ByValue,`targetKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_context_message(`context`: RustBuffer.ByValue,`payload`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_generate_challenge(uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_generate_salt(uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_key_set_root(`publicKeys`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_open_key_commitment(`keyCommitment`: RustBuffer.ByValue,`publicKey`: RustBuffer.ByValue,`salt`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+fun uniffi_mopro_r0_example_app_fn_func_risc0_prove(`message`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_prove_batch(`entries`: RustBuffer.ByValue,`commitEntries`: Byte,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_prove_bytes(`message`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_prove_chain_link(`previousReceipt`: RustBuffer.ByValue,`publicKey`: RustBuffer.ByValue,`message`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_prove_hidden_message(`publicKey`: RustBuffer.ByValue,`message`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,`blinding`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_prove_in_context(`context`: RustBuffer.ByValue,`publicKey`: RustBuffer.ByValue,`message`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,`requireContextPrefix`: Byte,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_prove_key_commitment(`publicKey`: RustBuffer.ByValue,`message`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,`salt`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_prove_membership(`publicKeys`: RustBuffer.ByValue,`publicKey`: RustBuffer.ByValue,`message`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_prove_signature(`publicKey`: RustBuffer.ByValue,`message`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_prove_signature_with_mode(`publicKey`: RustBuffer.ByValue,`message`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,`mode`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_prove_threshold(`publicKeys`: RustBuffer.ByValue,`threshold`: Int,`message`: RustBuffer.ByValue,`signatures`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_prove_unrevoked(`revokedPublicKeys`: RustBuffer.ByValue,`publicKey`: RustBuffer.ByValue,`message`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_prove_with_challenge(`publicKey`: RustBuffer.ByValue,`message`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,`challenge`: RustBuffer.ByValue,`requireChallengeInMessage`: Byte,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_prove_with_validity(`publicKey`: RustBuffer.ByValue,`message`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,`notBefore`: Long,`notAfter`: Long,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_revocation_root(`revokedPublicKeys`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_verify(`receiptBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_verify_aggregate(`receiptBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_verify_batch(`receiptBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_verify_bytes(`receiptBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_verify_chain(`receiptBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_verify_hidden_message(`receiptBytes`: RustBuffer.ByValue,`candidateMessage`: RustBuffer.ByValue,`blinding`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_verify_in_context(`receiptBytes`: RustBuffer.ByValue,`expectedContext`: RustBuffer.ByValue,`requireContextPrefix`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_verify_key_commitment(`receiptBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_verify_membership(`receiptBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_verify_prehashed(`receiptBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_verify_threshold(`receiptBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_verify_unrevoked(`receiptBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_verify_with_challenge(`receiptBytes`: RustBuffer.ByValue,`expectedChallenge`: RustBuffer.ByValue,`requireChallengeInMessage`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_verify_with_policy(`receiptBytes`: RustBuffer.ByValue,`allowedPublicKeys`: RustBuffer.ByValue,`expectedMessage`: RustBuffer.ByValue,`expectedMessageSha256`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_risc0_verify_with_validity(`receiptBytes`: RustBuffer.ByValue,`now`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_rsa_modulus_hash(`modulus`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_rsa_prove(`message`: RustBuffer.ByValue,`scheme`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_rsa_prove_signature(`modulus`: RustBuffer.ByValue,`exponent`: RustBuffer.ByValue,`scheme`: RustBuffer.ByValue,`message`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_rsa_verify(`receiptBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_schnorr_prove(`message`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_schnorr_prove_signature(`publicKey`: RustBuffer.ByValue,`message`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_schnorr_verify(`receiptBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_secp256k1_prove(`message`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_secp256k1_prove_signature(`publicKey`: RustBuffer.ByValue,`message`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,`receiptKind`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_secp256k1_verify(`receiptBytes`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_mopro_r0_example_app_fn_func_verify_circom_proof(`zkeyPath`: RustBuffer.ByValue,`proofResult`: RustBuffer.ByValue,`proofLib`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+fun uniffi_mopro_r0_example_app_fn_func_verify_halo2_proof(`srsPath`: RustBuffer.ByValue,`vkPath`: RustBuffer.ByValue,`proof`: RustBuffer.ByValue,`publicInput`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+fun uniffi_mopro_r0_example_app_fn_func_verify_noir_proof(`circuitPath`: RustBuffer.ByValue,`proof`: RustBuffer.ByValue,`onChain`: Byte,`vk`: RustBuffer.ByValue,`lowMemoryMode`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+fun ffi_mopro_r0_example_app_rustbuffer_alloc(`size`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun ffi_mopro_r0_example_app_rustbuffer_from_bytes(`bytes`: ForeignBytes.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun ffi_mopro_r0_example_app_rustbuffer_free(`buf`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+fun ffi_mopro_r0_example_app_rustbuffer_reserve(`buf`: RustBuffer.ByValue,`additional`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun ffi_mopro_r0_example_app_rust_future_poll_u8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_cancel_u8(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_free_u8(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_complete_u8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+fun ffi_mopro_r0_example_app_rust_future_poll_i8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_cancel_i8(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_free_i8(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_complete_i8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+fun ffi_mopro_r0_example_app_rust_future_poll_u16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_cancel_u16(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_free_u16(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_complete_u16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Short
+fun ffi_mopro_r0_example_app_rust_future_poll_i16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_cancel_i16(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_free_i16(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_complete_i16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Short
+fun ffi_mopro_r0_example_app_rust_future_poll_u32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_cancel_u32(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_free_u32(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_complete_u32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Int
+fun ffi_mopro_r0_example_app_rust_future_poll_i32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_cancel_i32(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_free_i32(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_complete_i32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Int
+fun ffi_mopro_r0_example_app_rust_future_poll_u64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_cancel_u64(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_free_u64(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_complete_u64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+fun ffi_mopro_r0_example_app_rust_future_poll_i64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_cancel_i64(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_free_i64(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_complete_i64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+fun ffi_mopro_r0_example_app_rust_future_poll_f32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_cancel_f32(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_free_f32(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_complete_f32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Float
+fun ffi_mopro_r0_example_app_rust_future_poll_f64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_cancel_f64(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_free_f64(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_complete_f64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Double
+fun ffi_mopro_r0_example_app_rust_future_poll_pointer(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_cancel_pointer(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_free_pointer(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_complete_pointer(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Pointer
+fun ffi_mopro_r0_example_app_rust_future_poll_rust_buffer(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_cancel_rust_buffer(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_free_rust_buffer(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_complete_rust_buffer(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun ffi_mopro_r0_example_app_rust_future_poll_void(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_cancel_void(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_free_void(`handle`: Long,
+): Unit
+fun ffi_mopro_r0_example_app_rust_future_complete_void(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+
+}
+
+private fun uniffiCheckContractApiVersion(lib: IntegrityCheckingUniffiLib) {
+    // Get the bindings contract version from our ComponentInterface
+    val bindings_contract_version = 29
+    // Get the scaffolding contract version by calling the into the dylib
+    val scaffolding_contract_version = lib.ffi_mopro_r0_example_app_uniffi_contract_version()
+    if (bindings_contract_version != scaffolding_contract_version) {
+        throw RuntimeException("UniFFI contract version mismatch: try cleaning and rebuilding your project")
+    }
+}
+@Suppress("UNUSED_PARAMETER")
+private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_ed25519_prove() != 55521.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_ed25519_prove_signature() != 56260.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_ed25519_verify() != 53299.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_eth_encode_typed_address() != 27483.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_eth_encode_typed_bool() != 15491.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_eth_encode_typed_bytes() != 25124.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_eth_encode_typed_string() != 30850.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_eth_encode_typed_uint() != 41024.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_eth_hash_typed_struct() != 54105.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_eth_prove_personal_sign() != 23921.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_eth_prove_typed_data() != 24941.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_eth_typed_data_domain_separator() != 62991.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_eth_verify_personal_sign() != 56823.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_eth_verify_typed_data() != 15429.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_generate_circom_proof() != 15503.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_generate_halo2_proof() != 24703.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_generate_noir_proof() != 46965.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_get_noir_verification_key() != 28810.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_p384_prove() != 12542.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_p384_prove_signature() != 59051.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_p384_verify() != 6523.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_aggregate() != 25403.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_aggregate_root() != 24496.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_batch_root() != 13010.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_chain_message() != 56697.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_commit_public_key() != 23323.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_compress_receipt() != 30919.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_context_message() != 10569.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_generate_challenge() != 15184.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_generate_salt() != 44829.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_key_set_root() != 15125.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_open_key_commitment() != 10127.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_prove() != 18553.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_prove_batch() != 57256.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_prove_bytes() != 39424.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_prove_chain_link() != 10949.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_prove_hidden_message() != 49379.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_prove_in_context() != 62328.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_prove_key_commitment() != 65069.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_prove_membership() != 48818.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_prove_signature() != 12744.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_prove_signature_with_mode() != 59165.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_prove_threshold() != 30490.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_prove_unrevoked() != 48322.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_prove_with_challenge() != 26158.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_prove_with_validity() != 29544.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_revocation_root() != 41060.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_verify() != 34311.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_verify_aggregate() != 57046.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_verify_batch() != 23322.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_verify_bytes() != 56509.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_verify_chain() != 31731.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_verify_hidden_message() != 10489.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_verify_in_context() != 34123.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_verify_key_commitment() != 2568.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_verify_membership() != 51982.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_verify_prehashed() != 58181.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_verify_threshold() != 55733.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_verify_unrevoked() != 23332.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_verify_with_challenge() != 6430.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_verify_with_policy() != 44135.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_risc0_verify_with_validity() != 56185.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_rsa_modulus_hash() != 55503.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_rsa_prove() != 6328.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_rsa_prove_signature() != 51727.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_rsa_verify() != 31768.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_schnorr_prove() != 54660.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_schnorr_prove_signature() != 647.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_schnorr_verify() != 54846.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_secp256k1_prove() != 24002.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_secp256k1_prove_signature() != 63216.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_secp256k1_verify() != 26235.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_verify_circom_proof() != 51913.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_verify_halo2_proof() != 7235.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mopro_r0_example_app_checksum_func_verify_noir_proof() != 55859.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+}
+
+/**
+ * @suppress
+ */
+public fun uniffiEnsureInitialized() {
+    UniffiLib.INSTANCE
+}
+
+// Async support
+
+// Public interface members begin here.
+
+
+// Interface implemented by anything that can contain an object reference.
+//
+// Such types expose a `destroy()` method that must be called to cleanly
+// dispose of the contained objects. Failure to call this method may result
+// in memory leaks.
+//
+// The easiest way to ensure this method is called is to use the `.use`
+// helper method to execute a block and destroy the object at the end.
+interface Disposable {
+    fun destroy()
+    companion object {
+        fun destroy(vararg args: Any?) {
+            args.filterIsInstance<Disposable>()
+                .forEach(Disposable::destroy)
+        }
+    }
+}
+
+/**
+ * @suppress
+ */
+inline fun <T : Disposable?, R> T.use(block: (T) -> R) =
+    try {
+        block(this)
+    } finally {
+        try {
+            // N.B. our implementation is on the nullable type `Disposable?`.
+            this?.destroy()
+        } catch (e: Throwable) {
+            // swallow
+        }
+    }
+
+/** 
+ * Used to instantiate an interface without an actual pointer, for fakes in tests, mostly.
+ *
+ * @suppress
+ * */
+object NoPointer
+
+/**
+ * @suppress
+ */
+public object FfiConverterUInt: FfiConverter<UInt, Int> {
+    override fun lift(value: Int): UInt {
+        return value.toUInt()
+    }
+
+    override fun read(buf: ByteBuffer): UInt {
+        return lift(buf.getInt())
+    }
+
+    override fun lower(value: UInt): Int {
+        return value.toInt()
+    }
+
+    override fun allocationSize(value: UInt) = 4UL
+
+    override fun write(value: UInt, buf: ByteBuffer) {
+        buf.putInt(value.toInt())
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterULong: FfiConverter<ULong, Long> {
+    override fun lift(value: Long): ULong {
+        return value.toULong()
+    }
+
+    override fun read(buf: ByteBuffer): ULong {
+        return lift(buf.getLong())
+    }
+
+    override fun lower(value: ULong): Long {
+        return value.toLong()
+    }
+
+    override fun allocationSize(value: ULong) = 8UL
+
+    override fun write(value: ULong, buf: ByteBuffer) {
+        buf.putLong(value.toLong())
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterBoolean: FfiConverter<Boolean, Byte> {
+    override fun lift(value: Byte): Boolean {
+        return value.toInt() != 0
+    }
+
+    override fun read(buf: ByteBuffer): Boolean {
+        return lift(buf.get())
+    }
+
+    override fun lower(value: Boolean): Byte {
+        return if (value) 1.toByte() else 0.toByte()
+    }
+
+    override fun allocationSize(value: Boolean) = 1UL
+
+    override fun write(value: Boolean, buf: ByteBuffer) {
+        buf.put(lower(value))
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterString: FfiConverter<String, RustBuffer.ByValue> {
+    // Note: we don't inherit from FfiConverterRustBuffer, because we use a
+    // special encoding when lowering/lifting.  We can use `RustBuffer.len` to
+    // store our length and avoid writing it out to the buffer.
+    override fun lift(value: RustBuffer.ByValue): String {
+        try {
+            val byteArr = ByteArray(value.len.toInt())
+            value.asByteBuffer()!!.get(byteArr)
+            return byteArr.toString(Charsets.UTF_8)
+        } finally {
+            RustBuffer.free(value)
+        }
+    }
+
+    override fun read(buf: ByteBuffer): String {
+        val len = buf.getInt()
+        val byteArr = ByteArray(len)
+        buf.get(byteArr)
+        return byteArr.toString(Charsets.UTF_8)
+    }
+
+    fun toUtf8(value: String): ByteBuffer {
+        // Make sure we don't have invalid UTF-16, check for lone surrogates.
+        return Charsets.UTF_8.newEncoder().run {
+            onMalformedInput(CodingErrorAction.REPORT)
+            encode(CharBuffer.wrap(value))
+        }
+    }
+
+    override fun lower(value: String): RustBuffer.ByValue {
+        val byteBuf = toUtf8(value)
+        // Ideally we'd pass these bytes to `ffi_bytebuffer_from_bytes`, but doing so would require us
+        // to copy them into a JNA `Memory`. So we might as well directly copy them into a `RustBuffer`.
+        val rbuf = RustBuffer.alloc(byteBuf.limit().toULong())
+        rbuf.asByteBuffer()!!.put(byteBuf)
+        return rbuf
+    }
+
+    // We aren't sure exactly how many bytes our string will be once it's UTF-8
+    // encoded.  Allocate 3 bytes per UTF-16 code unit which will always be
+    // enough.
+    override fun allocationSize(value: String): ULong {
+        val sizeForLength = 4UL
+        val sizeForString = value.length.toULong() * 3UL
+        return sizeForLength + sizeForString
+    }
+
+    override fun write(value: String, buf: ByteBuffer) {
+        val byteBuf = toUtf8(value)
+        buf.putInt(byteBuf.limit())
+        buf.put(byteBuf)
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterByteArray: FfiConverterRustBuffer<ByteArray> {
+    override fun read(buf: ByteBuffer): ByteArray {
+        val len = buf.getInt()
+        val byteArr = ByteArray(len)
+        buf.get(byteArr)
+        return byteArr
+    }
+    override fun allocationSize(value: ByteArray): ULong {
+        return 4UL + value.size.toULong()
+    }
+    override fun write(value: ByteArray, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        buf.put(value)
+    }
+}
+
+
+
+data class CircomProof (
+    var `a`: G1, 
+    var `b`: G2, 
+    var `c`: G1, 
+    var `protocol`: kotlin.String, 
+    var `curve`: kotlin.String
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeCircomProof: FfiConverterRustBuffer<CircomProof> {
+    override fun read(buf: ByteBuffer): CircomProof {
+        return CircomProof(
+            FfiConverterTypeG1.read(buf),
+            FfiConverterTypeG2.read(buf),
+            FfiConverterTypeG1.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: CircomProof) = (
+            FfiConverterTypeG1.allocationSize(value.`a`) +
+            FfiConverterTypeG2.allocationSize(value.`b`) +
+            FfiConverterTypeG1.allocationSize(value.`c`) +
+            FfiConverterString.allocationSize(value.`protocol`) +
+            FfiConverterString.allocationSize(value.`curve`)
+    )
+
+    override fun write(value: CircomProof, buf: ByteBuffer) {
+            FfiConverterTypeG1.write(value.`a`, buf)
+            FfiConverterTypeG2.write(value.`b`, buf)
+            FfiConverterTypeG1.write(value.`c`, buf)
+            FfiConverterString.write(value.`protocol`, buf)
+            FfiConverterString.write(value.`curve`, buf)
+    }
+}
+
+
+
+data class CircomProofResult (
+    var `proof`: CircomProof, 
+    var `inputs`: List<kotlin.String>
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeCircomProofResult: FfiConverterRustBuffer<CircomProofResult> {
+    override fun read(buf: ByteBuffer): CircomProofResult {
+        return CircomProofResult(
+            FfiConverterTypeCircomProof.read(buf),
+            FfiConverterSequenceString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: CircomProofResult) = (
+            FfiConverterTypeCircomProof.allocationSize(value.`proof`) +
+            FfiConverterSequenceString.allocationSize(value.`inputs`)
+    )
+
+    override fun write(value: CircomProofResult, buf: ByteBuffer) {
+            FfiConverterTypeCircomProof.write(value.`proof`, buf)
+            FfiConverterSequenceString.write(value.`inputs`, buf)
+    }
+}
+
+
+
+data class G1 (
+    var `x`: kotlin.String, 
+    var `y`: kotlin.String, 
+    var `z`: kotlin.String
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeG1: FfiConverterRustBuffer<G1> {
+    override fun read(buf: ByteBuffer): G1 {
+        return G1(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: G1) = (
+            FfiConverterString.allocationSize(value.`x`) +
+            FfiConverterString.allocationSize(value.`y`) +
+            FfiConverterString.allocationSize(value.`z`)
+    )
+
+    override fun write(value: G1, buf: ByteBuffer) {
+            FfiConverterString.write(value.`x`, buf)
+            FfiConverterString.write(value.`y`, buf)
+            FfiConverterString.write(value.`z`, buf)
+    }
+}
+
+
+
+data class G2 (
+    var `x`: List<kotlin.String>, 
+    var `y`: List<kotlin.String>, 
+    var `z`: List<kotlin.String>
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeG2: FfiConverterRustBuffer<G2> {
+    override fun read(buf: ByteBuffer): G2 {
+        return G2(
+            FfiConverterSequenceString.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterSequenceString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: G2) = (
+            FfiConverterSequenceString.allocationSize(value.`x`) +
+            FfiConverterSequenceString.allocationSize(value.`y`) +
+            FfiConverterSequenceString.allocationSize(value.`z`)
+    )
+
+    override fun write(value: G2, buf: ByteBuffer) {
+            FfiConverterSequenceString.write(value.`x`, buf)
+            FfiConverterSequenceString.write(value.`y`, buf)
+            FfiConverterSequenceString.write(value.`z`, buf)
+    }
+}
+
+
+
+data class Halo2ProofResult (
+    var `proof`: kotlin.ByteArray, 
+    var `inputs`: kotlin.ByteArray
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeHalo2ProofResult: FfiConverterRustBuffer<Halo2ProofResult> {
+    override fun read(buf: ByteBuffer): Halo2ProofResult {
+        return Halo2ProofResult(
+            FfiConverterByteArray.read(buf),
+            FfiConverterByteArray.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Halo2ProofResult) = (
+            FfiConverterByteArray.allocationSize(value.`proof`) +
+            FfiConverterByteArray.allocationSize(value.`inputs`)
+    )
+
+    override fun write(value: Halo2ProofResult, buf: ByteBuffer) {
+            FfiConverterByteArray.write(value.`proof`, buf)
+            FfiConverterByteArray.write(value.`inputs`, buf)
+    }
+}
+
+
+
+/**
+ * One signed message of a batch to prove.
+ */
+data class Risc0BatchEntry (
+    /**
+     * SEC1-encoded secp256r1 public key.
+     */
+    var `publicKey`: kotlin.ByteArray, 
+    var `message`: kotlin.ByteArray, 
+    /**
+     * `r || s` or DER-encoded ECDSA signature.
+     */
+    var `signature`: kotlin.ByteArray
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0BatchEntry: FfiConverterRustBuffer<Risc0BatchEntry> {
+    override fun read(buf: ByteBuffer): Risc0BatchEntry {
+        return Risc0BatchEntry(
+            FfiConverterByteArray.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterByteArray.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0BatchEntry) = (
+            FfiConverterByteArray.allocationSize(value.`publicKey`) +
+            FfiConverterByteArray.allocationSize(value.`message`) +
+            FfiConverterByteArray.allocationSize(value.`signature`)
+    )
+
+    override fun write(value: Risc0BatchEntry, buf: ByteBuffer) {
+            FfiConverterByteArray.write(value.`publicKey`, buf)
+            FfiConverterByteArray.write(value.`message`, buf)
+            FfiConverterByteArray.write(value.`signature`, buf)
+    }
+}
+
+
+
+/**
+ * EIP-712 domain, given like a struct: its encoded type and member
+ * encodings, so any subset of the domain fields can be used.
+ */
+data class Risc0Eip712Domain (
+    /**
+     * Encoded domain type, e.g.
+     * `EIP712Domain(string name,uint256 chainId,address verifyingContract)`.
+     */
+    var `domainType`: kotlin.String, 
+    /**
+     * 32-byte encodings of the members listed in `domain_type`; see the
+     * `eth_encode_typed_*` helpers.
+     */
+    var `encodedFields`: List<kotlin.ByteArray>
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0Eip712Domain: FfiConverterRustBuffer<Risc0Eip712Domain> {
+    override fun read(buf: ByteBuffer): Risc0Eip712Domain {
+        return Risc0Eip712Domain(
+            FfiConverterString.read(buf),
+            FfiConverterSequenceByteArray.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0Eip712Domain) = (
+            FfiConverterString.allocationSize(value.`domainType`) +
+            FfiConverterSequenceByteArray.allocationSize(value.`encodedFields`)
+    )
+
+    override fun write(value: Risc0Eip712Domain, buf: ByteBuffer) {
+            FfiConverterString.write(value.`domainType`, buf)
+            FfiConverterSequenceByteArray.write(value.`encodedFields`, buf)
+    }
+}
+
+
+
+/**
+ * A disclosed member of a typed struct, by position in the struct type.
+ */
+data class Risc0Eip712Field (
+    var `index`: kotlin.UInt, 
+    /**
+     * 32-byte EIP-712 encoding of the member value.
+     */
+    var `value`: kotlin.ByteArray
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0Eip712Field: FfiConverterRustBuffer<Risc0Eip712Field> {
+    override fun read(buf: ByteBuffer): Risc0Eip712Field {
+        return Risc0Eip712Field(
+            FfiConverterUInt.read(buf),
+            FfiConverterByteArray.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0Eip712Field) = (
+            FfiConverterUInt.allocationSize(value.`index`) +
+            FfiConverterByteArray.allocationSize(value.`value`)
+    )
+
+    override fun write(value: Risc0Eip712Field, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`index`, buf)
+            FfiConverterByteArray.write(value.`value`, buf)
+    }
+}
+
+
+
+data class Risc0ProofOutput (
+    var `receipt`: kotlin.ByteArray, 
+    /**
+     * Kind of receipt that was produced.
+     */
+    var `receiptKind`: Risc0ReceiptKind, 
+    /**
+     * Size of the seal in bytes, the bulk of the receipt.
+     */
+    var `sealSize`: kotlin.ULong
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0ProofOutput: FfiConverterRustBuffer<Risc0ProofOutput> {
+    override fun read(buf: ByteBuffer): Risc0ProofOutput {
+        return Risc0ProofOutput(
+            FfiConverterByteArray.read(buf),
+            FfiConverterTypeRisc0ReceiptKind.read(buf),
+            FfiConverterULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0ProofOutput) = (
+            FfiConverterByteArray.allocationSize(value.`receipt`) +
+            FfiConverterTypeRisc0ReceiptKind.allocationSize(value.`receiptKind`) +
+            FfiConverterULong.allocationSize(value.`sealSize`)
+    )
+
+    override fun write(value: Risc0ProofOutput, buf: ByteBuffer) {
+            FfiConverterByteArray.write(value.`receipt`, buf)
+            FfiConverterTypeRisc0ReceiptKind.write(value.`receiptKind`, buf)
+            FfiConverterULong.write(value.`sealSize`, buf)
+    }
+}
+
+
+
+/**
+ * Public key committed to the journal of a verified receipt.
+ */
+data class Risc0PublicKey (
+    var `curve`: Risc0Curve, 
+    /**
+     * SEC1 compressed encoding (33 bytes, or 49 for P-384). Ed25519 keys
+     * have a single 32-byte encoding, reported in both fields.
+     */
+    var `compressed`: kotlin.ByteArray, 
+    /**
+     * SEC1 uncompressed encoding (65 bytes, or 97 for P-384).
+     */
+    var `uncompressed`: kotlin.ByteArray, 
+    /**
+     * Lowercase hex SHA-256 of the compressed encoding.
+     */
+    var `fingerprint`: kotlin.String
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0PublicKey: FfiConverterRustBuffer<Risc0PublicKey> {
+    override fun read(buf: ByteBuffer): Risc0PublicKey {
+        return Risc0PublicKey(
+            FfiConverterTypeRisc0Curve.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0PublicKey) = (
+            FfiConverterTypeRisc0Curve.allocationSize(value.`curve`) +
+            FfiConverterByteArray.allocationSize(value.`compressed`) +
+            FfiConverterByteArray.allocationSize(value.`uncompressed`) +
+            FfiConverterString.allocationSize(value.`fingerprint`)
+    )
+
+    override fun write(value: Risc0PublicKey, buf: ByteBuffer) {
+            FfiConverterTypeRisc0Curve.write(value.`curve`, buf)
+            FfiConverterByteArray.write(value.`compressed`, buf)
+            FfiConverterByteArray.write(value.`uncompressed`, buf)
+            FfiConverterString.write(value.`fingerprint`, buf)
+    }
+}
+
+
+
+/**
+ * A signature tagged with the index of its key in the key set.
+ */
+data class Risc0ThresholdSignature (
+    /**
+     * Index of the signing key in `public_keys`.
+     */
+    var `keyIndex`: kotlin.UInt, 
+    /**
+     * Signature as `r || s` or ASN.1 DER.
+     */
+    var `signature`: kotlin.ByteArray
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0ThresholdSignature: FfiConverterRustBuffer<Risc0ThresholdSignature> {
+    override fun read(buf: ByteBuffer): Risc0ThresholdSignature {
+        return Risc0ThresholdSignature(
+            FfiConverterUInt.read(buf),
+            FfiConverterByteArray.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0ThresholdSignature) = (
+            FfiConverterUInt.allocationSize(value.`keyIndex`) +
+            FfiConverterByteArray.allocationSize(value.`signature`)
+    )
+
+    override fun write(value: Risc0ThresholdSignature, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`keyIndex`, buf)
+            FfiConverterByteArray.write(value.`signature`, buf)
+    }
+}
+
+
+
+/**
+ * A verified batch entry, present when the batch was proven with its entries.
+ */
+data class Risc0VerifiedBatchEntry (
+    var `signer`: Risc0PublicKey, 
+    var `message`: kotlin.ByteArray
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0VerifiedBatchEntry: FfiConverterRustBuffer<Risc0VerifiedBatchEntry> {
+    override fun read(buf: ByteBuffer): Risc0VerifiedBatchEntry {
+        return Risc0VerifiedBatchEntry(
+            FfiConverterTypeRisc0PublicKey.read(buf),
+            FfiConverterByteArray.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0VerifiedBatchEntry) = (
+            FfiConverterTypeRisc0PublicKey.allocationSize(value.`signer`) +
+            FfiConverterByteArray.allocationSize(value.`message`)
+    )
+
+    override fun write(value: Risc0VerifiedBatchEntry, buf: ByteBuffer) {
+            FfiConverterTypeRisc0PublicKey.write(value.`signer`, buf)
+            FfiConverterByteArray.write(value.`message`, buf)
+    }
+}
+
+
+
+/**
+ * Verify output for aggregated receipts.
+ */
+data class Risc0VerifyAggregateOutput (
+    var `isValid`: kotlin.Boolean, 
+    /**
+     * Number of inner receipts folded into the aggregate.
+     */
+    var `count`: kotlin.UInt, 
+    /**
+     * Merkle root over the inner journals, as returned by [`risc0_aggregate_root`].
+     */
+    var `root`: kotlin.ByteArray, 
+    /**
+     * The inner statements in input order, or empty if they were not committed.
+     */
+    var `entries`: List<Risc0VerifyBytesOutput>
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0VerifyAggregateOutput: FfiConverterRustBuffer<Risc0VerifyAggregateOutput> {
+    override fun read(buf: ByteBuffer): Risc0VerifyAggregateOutput {
+        return Risc0VerifyAggregateOutput(
+            FfiConverterBoolean.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterSequenceTypeRisc0VerifyBytesOutput.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0VerifyAggregateOutput) = (
+            FfiConverterBoolean.allocationSize(value.`isValid`) +
+            FfiConverterUInt.allocationSize(value.`count`) +
+            FfiConverterByteArray.allocationSize(value.`root`) +
+            FfiConverterSequenceTypeRisc0VerifyBytesOutput.allocationSize(value.`entries`)
+    )
+
+    override fun write(value: Risc0VerifyAggregateOutput, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`isValid`, buf)
+            FfiConverterUInt.write(value.`count`, buf)
+            FfiConverterByteArray.write(value.`root`, buf)
+            FfiConverterSequenceTypeRisc0VerifyBytesOutput.write(value.`entries`, buf)
+    }
+}
+
+
+
+/**
+ * Verify output for batch proofs.
+ */
+data class Risc0VerifyBatchOutput (
+    var `isValid`: kotlin.Boolean, 
+    /**
+     * Number of signatures verified in the batch.
+     */
+    var `count`: kotlin.UInt, 
+    /**
+     * Merkle root over the `(key, SHA-256 of message)` leaves, in batch order.
+     */
+    var `root`: kotlin.ByteArray, 
+    /**
+     * The entries in batch order, or empty if they were not committed.
+     */
+    var `entries`: List<Risc0VerifiedBatchEntry>
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0VerifyBatchOutput: FfiConverterRustBuffer<Risc0VerifyBatchOutput> {
+    override fun read(buf: ByteBuffer): Risc0VerifyBatchOutput {
+        return Risc0VerifyBatchOutput(
+            FfiConverterBoolean.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterSequenceTypeRisc0VerifiedBatchEntry.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0VerifyBatchOutput) = (
+            FfiConverterBoolean.allocationSize(value.`isValid`) +
+            FfiConverterUInt.allocationSize(value.`count`) +
+            FfiConverterByteArray.allocationSize(value.`root`) +
+            FfiConverterSequenceTypeRisc0VerifiedBatchEntry.allocationSize(value.`entries`)
+    )
+
+    override fun write(value: Risc0VerifyBatchOutput, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`isValid`, buf)
+            FfiConverterUInt.write(value.`count`, buf)
+            FfiConverterByteArray.write(value.`root`, buf)
+            FfiConverterSequenceTypeRisc0VerifiedBatchEntry.write(value.`entries`, buf)
+    }
+}
+
+
+
+/**
+ * Verify output for messages that are not necessarily UTF-8.
+ */
+data class Risc0VerifyBytesOutput (
+    var `isValid`: kotlin.Boolean, 
+    var `message`: kotlin.ByteArray, 
+    /**
+     * The message as text, present only when it is valid UTF-8.
+     */
+    var `messageText`: kotlin.String?, 
+    var `signer`: Risc0PublicKey
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0VerifyBytesOutput: FfiConverterRustBuffer<Risc0VerifyBytesOutput> {
+    override fun read(buf: ByteBuffer): Risc0VerifyBytesOutput {
+        return Risc0VerifyBytesOutput(
+            FfiConverterBoolean.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterTypeRisc0PublicKey.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0VerifyBytesOutput) = (
+            FfiConverterBoolean.allocationSize(value.`isValid`) +
+            FfiConverterByteArray.allocationSize(value.`message`) +
+            FfiConverterOptionalString.allocationSize(value.`messageText`) +
+            FfiConverterTypeRisc0PublicKey.allocationSize(value.`signer`)
+    )
+
+    override fun write(value: Risc0VerifyBytesOutput, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`isValid`, buf)
+            FfiConverterByteArray.write(value.`message`, buf)
+            FfiConverterOptionalString.write(value.`messageText`, buf)
+            FfiConverterTypeRisc0PublicKey.write(value.`signer`, buf)
+    }
+}
+
+
+
+/**
+ * Verify output for signature chain proofs.
+ */
+data class Risc0VerifyChainOutput (
+    var `isValid`: kotlin.Boolean, 
+    /**
+     * Key that signed every message of the chain.
+     */
+    var `signer`: Risc0PublicKey, 
+    /**
+     * Number of messages in the chain.
+     */
+    var `counter`: kotlin.ULong, 
+    /**
+     * SHA-256 of the latest message, which the next message must start with.
+     */
+    var `head`: kotlin.ByteArray
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0VerifyChainOutput: FfiConverterRustBuffer<Risc0VerifyChainOutput> {
+    override fun read(buf: ByteBuffer): Risc0VerifyChainOutput {
+        return Risc0VerifyChainOutput(
+            FfiConverterBoolean.read(buf),
+            FfiConverterTypeRisc0PublicKey.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterByteArray.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0VerifyChainOutput) = (
+            FfiConverterBoolean.allocationSize(value.`isValid`) +
+            FfiConverterTypeRisc0PublicKey.allocationSize(value.`signer`) +
+            FfiConverterULong.allocationSize(value.`counter`) +
+            FfiConverterByteArray.allocationSize(value.`head`)
+    )
+
+    override fun write(value: Risc0VerifyChainOutput, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`isValid`, buf)
+            FfiConverterTypeRisc0PublicKey.write(value.`signer`, buf)
+            FfiConverterULong.write(value.`counter`, buf)
+            FfiConverterByteArray.write(value.`head`, buf)
+    }
+}
+
+
+
+/**
+ * Verify output for receipts that commit only a hash of the message.
+ */
+data class Risc0VerifyCommitmentOutput (
+    var `isValid`: kotlin.Boolean, 
+    /**
+     * SHA-256 of the blinding value (zeros if none) followed by the message.
+     */
+    var `messageCommitment`: kotlin.ByteArray, 
+    var `signer`: Risc0PublicKey
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0VerifyCommitmentOutput: FfiConverterRustBuffer<Risc0VerifyCommitmentOutput> {
+    override fun read(buf: ByteBuffer): Risc0VerifyCommitmentOutput {
+        return Risc0VerifyCommitmentOutput(
+            FfiConverterBoolean.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterTypeRisc0PublicKey.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0VerifyCommitmentOutput) = (
+            FfiConverterBoolean.allocationSize(value.`isValid`) +
+            FfiConverterByteArray.allocationSize(value.`messageCommitment`) +
+            FfiConverterTypeRisc0PublicKey.allocationSize(value.`signer`)
+    )
+
+    override fun write(value: Risc0VerifyCommitmentOutput, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`isValid`, buf)
+            FfiConverterByteArray.write(value.`messageCommitment`, buf)
+            FfiConverterTypeRisc0PublicKey.write(value.`signer`, buf)
+    }
+}
+
+
+
+/**
+ * Verify output for receipts produced in [`crate::Risc0MessageMode::Prehashed`].
+ */
+data class Risc0VerifyDigestOutput (
+    var `isValid`: kotlin.Boolean, 
+    /**
+     * SHA-256 digest of the signed message.
+     */
+    var `messageDigest`: kotlin.ByteArray, 
+    var `signer`: Risc0PublicKey
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0VerifyDigestOutput: FfiConverterRustBuffer<Risc0VerifyDigestOutput> {
+    override fun read(buf: ByteBuffer): Risc0VerifyDigestOutput {
+        return Risc0VerifyDigestOutput(
+            FfiConverterBoolean.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterTypeRisc0PublicKey.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0VerifyDigestOutput) = (
+            FfiConverterBoolean.allocationSize(value.`isValid`) +
+            FfiConverterByteArray.allocationSize(value.`messageDigest`) +
+            FfiConverterTypeRisc0PublicKey.allocationSize(value.`signer`)
+    )
+
+    override fun write(value: Risc0VerifyDigestOutput, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`isValid`, buf)
+            FfiConverterByteArray.write(value.`messageDigest`, buf)
+            FfiConverterTypeRisc0PublicKey.write(value.`signer`, buf)
+    }
+}
+
+
+
+/**
+ * Verify output for Ethereum signature proofs.
+ */
+data class Risc0VerifyEthOutput (
+    var `isValid`: kotlin.Boolean, 
+    /**
+     * 20-byte signer address.
+     */
+    var `address`: kotlin.ByteArray, 
+    /**
+     * Signer address as EIP-55 checksummed hex with `0x` prefix.
+     */
+    var `addressHex`: kotlin.String, 
+    var `message`: kotlin.ByteArray, 
+    /**
+     * The message as text, present only when it is valid UTF-8.
+     */
+    var `messageText`: kotlin.String?
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0VerifyEthOutput: FfiConverterRustBuffer<Risc0VerifyEthOutput> {
+    override fun read(buf: ByteBuffer): Risc0VerifyEthOutput {
+        return Risc0VerifyEthOutput(
+            FfiConverterBoolean.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterOptionalString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0VerifyEthOutput) = (
+            FfiConverterBoolean.allocationSize(value.`isValid`) +
+            FfiConverterByteArray.allocationSize(value.`address`) +
+            FfiConverterString.allocationSize(value.`addressHex`) +
+            FfiConverterByteArray.allocationSize(value.`message`) +
+            FfiConverterOptionalString.allocationSize(value.`messageText`)
+    )
+
+    override fun write(value: Risc0VerifyEthOutput, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`isValid`, buf)
+            FfiConverterByteArray.write(value.`address`, buf)
+            FfiConverterString.write(value.`addressHex`, buf)
+            FfiConverterByteArray.write(value.`message`, buf)
+            FfiConverterOptionalString.write(value.`messageText`, buf)
+    }
+}
+
+
+
+/**
+ * Verify output for receipts that commit to the signer key instead of revealing it.
+ */
+data class Risc0VerifyKeyCommitmentOutput (
+    var `isValid`: kotlin.Boolean, 
+    /**
+     * SHA-256 of the salt followed by the compressed SEC1 signer key.
+     */
+    var `keyCommitment`: kotlin.ByteArray, 
+    var `message`: kotlin.ByteArray, 
+    /**
+     * The message as text, present only when it is valid UTF-8.
+     */
+    var `messageText`: kotlin.String?
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0VerifyKeyCommitmentOutput: FfiConverterRustBuffer<Risc0VerifyKeyCommitmentOutput> {
+    override fun read(buf: ByteBuffer): Risc0VerifyKeyCommitmentOutput {
+        return Risc0VerifyKeyCommitmentOutput(
+            FfiConverterBoolean.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterOptionalString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0VerifyKeyCommitmentOutput) = (
+            FfiConverterBoolean.allocationSize(value.`isValid`) +
+            FfiConverterByteArray.allocationSize(value.`keyCommitment`) +
+            FfiConverterByteArray.allocationSize(value.`message`) +
+            FfiConverterOptionalString.allocationSize(value.`messageText`)
+    )
+
+    override fun write(value: Risc0VerifyKeyCommitmentOutput, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`isValid`, buf)
+            FfiConverterByteArray.write(value.`keyCommitment`, buf)
+            FfiConverterByteArray.write(value.`message`, buf)
+            FfiConverterOptionalString.write(value.`messageText`, buf)
+    }
+}
+
+
+
+/**
+ * Verify output for anonymous membership proofs.
+ */
+data class Risc0VerifyMembershipOutput (
+    var `isValid`: kotlin.Boolean, 
+    /**
+     * Merkle root of the key set the signer belongs to.
+     */
+    var `keySetRoot`: kotlin.ByteArray, 
+    var `message`: kotlin.ByteArray, 
+    /**
+     * The message as text, present only when it is valid UTF-8.
+     */
+    var `messageText`: kotlin.String?
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0VerifyMembershipOutput: FfiConverterRustBuffer<Risc0VerifyMembershipOutput> {
+    override fun read(buf: ByteBuffer): Risc0VerifyMembershipOutput {
+        return Risc0VerifyMembershipOutput(
+            FfiConverterBoolean.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterOptionalString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0VerifyMembershipOutput) = (
+            FfiConverterBoolean.allocationSize(value.`isValid`) +
+            FfiConverterByteArray.allocationSize(value.`keySetRoot`) +
+            FfiConverterByteArray.allocationSize(value.`message`) +
+            FfiConverterOptionalString.allocationSize(value.`messageText`)
+    )
+
+    override fun write(value: Risc0VerifyMembershipOutput, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`isValid`, buf)
+            FfiConverterByteArray.write(value.`keySetRoot`, buf)
+            FfiConverterByteArray.write(value.`message`, buf)
+            FfiConverterOptionalString.write(value.`messageText`, buf)
+    }
+}
+
+
+
+data class Risc0VerifyOutput (
+    var `isValid`: kotlin.Boolean, 
+    var `verifiedMessage`: kotlin.String, 
+    var `signer`: Risc0PublicKey
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0VerifyOutput: FfiConverterRustBuffer<Risc0VerifyOutput> {
+    override fun read(buf: ByteBuffer): Risc0VerifyOutput {
+        return Risc0VerifyOutput(
+            FfiConverterBoolean.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterTypeRisc0PublicKey.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0VerifyOutput) = (
+            FfiConverterBoolean.allocationSize(value.`isValid`) +
+            FfiConverterString.allocationSize(value.`verifiedMessage`) +
+            FfiConverterTypeRisc0PublicKey.allocationSize(value.`signer`)
+    )
+
+    override fun write(value: Risc0VerifyOutput, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`isValid`, buf)
+            FfiConverterString.write(value.`verifiedMessage`, buf)
+            FfiConverterTypeRisc0PublicKey.write(value.`signer`, buf)
+    }
+}
+
+
+
+/**
+ * Verify output for RSA signature proofs.
+ */
+data class Risc0VerifyRsaOutput (
+    var `isValid`: kotlin.Boolean, 
+    /**
+     * SHA-256 of the big-endian modulus, as returned by [`rsa_modulus_hash`].
+     */
+    var `modulusHash`: kotlin.ByteArray, 
+    /**
+     * Big-endian public exponent.
+     */
+    var `exponent`: kotlin.ByteArray, 
+    var `scheme`: Risc0RsaScheme, 
+    var `message`: kotlin.ByteArray, 
+    /**
+     * The message as text, present only when it is valid UTF-8.
+     */
+    var `messageText`: kotlin.String?
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0VerifyRsaOutput: FfiConverterRustBuffer<Risc0VerifyRsaOutput> {
+    override fun read(buf: ByteBuffer): Risc0VerifyRsaOutput {
+        return Risc0VerifyRsaOutput(
+            FfiConverterBoolean.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterTypeRisc0RsaScheme.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterOptionalString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0VerifyRsaOutput) = (
+            FfiConverterBoolean.allocationSize(value.`isValid`) +
+            FfiConverterByteArray.allocationSize(value.`modulusHash`) +
+            FfiConverterByteArray.allocationSize(value.`exponent`) +
+            FfiConverterTypeRisc0RsaScheme.allocationSize(value.`scheme`) +
+            FfiConverterByteArray.allocationSize(value.`message`) +
+            FfiConverterOptionalString.allocationSize(value.`messageText`)
+    )
+
+    override fun write(value: Risc0VerifyRsaOutput, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`isValid`, buf)
+            FfiConverterByteArray.write(value.`modulusHash`, buf)
+            FfiConverterByteArray.write(value.`exponent`, buf)
+            FfiConverterTypeRisc0RsaScheme.write(value.`scheme`, buf)
+            FfiConverterByteArray.write(value.`message`, buf)
+            FfiConverterOptionalString.write(value.`messageText`, buf)
+    }
+}
+
+
+
+/**
+ * Verify output for k-of-n threshold proofs.
+ */
+data class Risc0VerifyThresholdOutput (
+    var `isValid`: kotlin.Boolean, 
+    /**
+     * Merkle root of the key set, as returned by
+     * [`crate::membership::risc0_key_set_root`].
+     */
+    var `keySetRoot`: kotlin.ByteArray, 
+    /**
+     * Minimum number of distinct keys that signed the message.
+     */
+    var `threshold`: kotlin.UInt, 
+    var `message`: kotlin.ByteArray, 
+    /**
+     * The message as text, present only when it is valid UTF-8.
+     */
+    var `messageText`: kotlin.String?
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0VerifyThresholdOutput: FfiConverterRustBuffer<Risc0VerifyThresholdOutput> {
+    override fun read(buf: ByteBuffer): Risc0VerifyThresholdOutput {
+        return Risc0VerifyThresholdOutput(
+            FfiConverterBoolean.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterOptionalString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0VerifyThresholdOutput) = (
+            FfiConverterBoolean.allocationSize(value.`isValid`) +
+            FfiConverterByteArray.allocationSize(value.`keySetRoot`) +
+            FfiConverterUInt.allocationSize(value.`threshold`) +
+            FfiConverterByteArray.allocationSize(value.`message`) +
+            FfiConverterOptionalString.allocationSize(value.`messageText`)
+    )
+
+    override fun write(value: Risc0VerifyThresholdOutput, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`isValid`, buf)
+            FfiConverterByteArray.write(value.`keySetRoot`, buf)
+            FfiConverterUInt.write(value.`threshold`, buf)
+            FfiConverterByteArray.write(value.`message`, buf)
+            FfiConverterOptionalString.write(value.`messageText`, buf)
+    }
+}
+
+
+
+/**
+ * Verify output for EIP-712 typed-data proofs.
+ */
+data class Risc0VerifyTypedDataOutput (
+    var `isValid`: kotlin.Boolean, 
+    /**
+     * 20-byte signer address.
+     */
+    var `address`: kotlin.ByteArray, 
+    /**
+     * Signer address as EIP-55 checksummed hex with `0x` prefix.
+     */
+    var `addressHex`: kotlin.String, 
+    var `domainSeparator`: kotlin.ByteArray, 
+    /**
+     * Encoded type of the signed struct, e.g. `Mail(address to,string contents)`.
+     */
+    var `structType`: kotlin.String, 
+    var `disclosedFields`: List<Risc0Eip712Field>
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0VerifyTypedDataOutput: FfiConverterRustBuffer<Risc0VerifyTypedDataOutput> {
+    override fun read(buf: ByteBuffer): Risc0VerifyTypedDataOutput {
+        return Risc0VerifyTypedDataOutput(
+            FfiConverterBoolean.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterSequenceTypeRisc0Eip712Field.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0VerifyTypedDataOutput) = (
+            FfiConverterBoolean.allocationSize(value.`isValid`) +
+            FfiConverterByteArray.allocationSize(value.`address`) +
+            FfiConverterString.allocationSize(value.`addressHex`) +
+            FfiConverterByteArray.allocationSize(value.`domainSeparator`) +
+            FfiConverterString.allocationSize(value.`structType`) +
+            FfiConverterSequenceTypeRisc0Eip712Field.allocationSize(value.`disclosedFields`)
+    )
+
+    override fun write(value: Risc0VerifyTypedDataOutput, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`isValid`, buf)
+            FfiConverterByteArray.write(value.`address`, buf)
+            FfiConverterString.write(value.`addressHex`, buf)
+            FfiConverterByteArray.write(value.`domainSeparator`, buf)
+            FfiConverterString.write(value.`structType`, buf)
+            FfiConverterSequenceTypeRisc0Eip712Field.write(value.`disclosedFields`, buf)
+    }
+}
+
+
+
+/**
+ * Verify output for proofs that the signer key is not revoked.
+ */
+data class Risc0VerifyUnrevokedOutput (
+    var `isValid`: kotlin.Boolean, 
+    var `message`: kotlin.ByteArray, 
+    /**
+     * The message as text, present only when it is valid UTF-8.
+     */
+    var `messageText`: kotlin.String?, 
+    var `signer`: Risc0PublicKey, 
+    /**
+     * Root of the revocation tree the signer was checked against.
+     */
+    var `revocationRoot`: kotlin.ByteArray
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0VerifyUnrevokedOutput: FfiConverterRustBuffer<Risc0VerifyUnrevokedOutput> {
+    override fun read(buf: ByteBuffer): Risc0VerifyUnrevokedOutput {
+        return Risc0VerifyUnrevokedOutput(
+            FfiConverterBoolean.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterTypeRisc0PublicKey.read(buf),
+            FfiConverterByteArray.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0VerifyUnrevokedOutput) = (
+            FfiConverterBoolean.allocationSize(value.`isValid`) +
+            FfiConverterByteArray.allocationSize(value.`message`) +
+            FfiConverterOptionalString.allocationSize(value.`messageText`) +
+            FfiConverterTypeRisc0PublicKey.allocationSize(value.`signer`) +
+            FfiConverterByteArray.allocationSize(value.`revocationRoot`)
+    )
+
+    override fun write(value: Risc0VerifyUnrevokedOutput, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`isValid`, buf)
+            FfiConverterByteArray.write(value.`message`, buf)
+            FfiConverterOptionalString.write(value.`messageText`, buf)
+            FfiConverterTypeRisc0PublicKey.write(value.`signer`, buf)
+            FfiConverterByteArray.write(value.`revocationRoot`, buf)
+    }
+}
+
+
+
+/**
+ * Verify output for receipts with a validity window.
+ */
+data class Risc0VerifyValidityOutput (
+    var `isValid`: kotlin.Boolean, 
+    var `message`: kotlin.ByteArray, 
+    /**
+     * The message as text, present only when it is valid UTF-8.
+     */
+    var `messageText`: kotlin.String?, 
+    var `signer`: Risc0PublicKey, 
+    /**
+     * Start of the validity window, in Unix seconds.
+     */
+    var `notBefore`: kotlin.ULong, 
+    /**
+     * End of the validity window (inclusive), in Unix seconds.
+     */
+    var `notAfter`: kotlin.ULong
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0VerifyValidityOutput: FfiConverterRustBuffer<Risc0VerifyValidityOutput> {
+    override fun read(buf: ByteBuffer): Risc0VerifyValidityOutput {
+        return Risc0VerifyValidityOutput(
+            FfiConverterBoolean.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterTypeRisc0PublicKey.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Risc0VerifyValidityOutput) = (
+            FfiConverterBoolean.allocationSize(value.`isValid`) +
+            FfiConverterByteArray.allocationSize(value.`message`) +
+            FfiConverterOptionalString.allocationSize(value.`messageText`) +
+            FfiConverterTypeRisc0PublicKey.allocationSize(value.`signer`) +
+            FfiConverterULong.allocationSize(value.`notBefore`) +
+            FfiConverterULong.allocationSize(value.`notAfter`)
+    )
+
+    override fun write(value: Risc0VerifyValidityOutput, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`isValid`, buf)
+            FfiConverterByteArray.write(value.`message`, buf)
+            FfiConverterOptionalString.write(value.`messageText`, buf)
+            FfiConverterTypeRisc0PublicKey.write(value.`signer`, buf)
+            FfiConverterULong.write(value.`notBefore`, buf)
+            FfiConverterULong.write(value.`notAfter`, buf)
+    }
+}
+
+
+
+
+
+sealed class MoproException: kotlin.Exception() {
+    
+    class CircomException(
+        
+        val v1: kotlin.String
+        ) : MoproException() {
+        override val message
+            get() = "v1=${ v1 }"
+    }
+    
+    class Halo2Exception(
+        
+        val v1: kotlin.String
+        ) : MoproException() {
+        override val message
+            get() = "v1=${ v1 }"
+    }
+    
+    class NoirException(
+        
+        val v1: kotlin.String
+        ) : MoproException() {
+        override val message
+            get() = "v1=${ v1 }"
+    }
+    
+
+    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<MoproException> {
+        override fun lift(error_buf: RustBuffer.ByValue): MoproException = FfiConverterTypeMoproError.lift(error_buf)
+    }
+
+    
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeMoproError : FfiConverterRustBuffer<MoproException> {
+    override fun read(buf: ByteBuffer): MoproException {
+        
+
+        return when(buf.getInt()) {
+            1 -> MoproException.CircomException(
+                FfiConverterString.read(buf),
+                )
+            2 -> MoproException.Halo2Exception(
+                FfiConverterString.read(buf),
+                )
+            3 -> MoproException.NoirException(
+                FfiConverterString.read(buf),
+                )
+            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: MoproException): ULong {
+        return when(value) {
+            is MoproException.CircomException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+            is MoproException.Halo2Exception -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+            is MoproException.NoirException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+        }
+    }
+
+    override fun write(value: MoproException, buf: ByteBuffer) {
+        when(value) {
+            is MoproException.CircomException -> {
+                buf.putInt(1)
+                FfiConverterString.write(value.v1, buf)
+                Unit
+            }
+            is MoproException.Halo2Exception -> {
+                buf.putInt(2)
+                FfiConverterString.write(value.v1, buf)
+                Unit
+            }
+            is MoproException.NoirException -> {
+                buf.putInt(3)
+                FfiConverterString.write(value.v1, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+
+}
+
+
+
+
+enum class ProofLib {
+    
+    ARKWORKS,
+    RAPIDSNARK;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeProofLib: FfiConverterRustBuffer<ProofLib> {
+    override fun read(buf: ByteBuffer) = try {
+        ProofLib.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: ProofLib) = 4UL
+
+    override fun write(value: ProofLib, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * Elliptic curve of a proven public key.
+ */
+
+enum class Risc0Curve {
+    
+    /**
+     * secp256r1 / NIST P-256.
+     */
+    P256,
+    /**
+     * secp256k1 ECDSA, as used by Ethereum and Bitcoin.
+     */
+    SECP256K1,
+    /**
+     * Ed25519 (EdDSA over Curve25519).
+     */
+    ED25519,
+    /**
+     * secp384r1 / NIST P-384, with SHA-384.
+     */
+    P384,
+    /**
+     * secp256k1 x-only key of a BIP-340 Schnorr signer, reported as the
+     * even-Y point BIP-340 assigns to it.
+     */
+    BIP340;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0Curve: FfiConverterRustBuffer<Risc0Curve> {
+    override fun read(buf: ByteBuffer) = try {
+        Risc0Curve.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: Risc0Curve) = 4UL
+
+    override fun write(value: Risc0Curve, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+
+sealed class Risc0Exception: kotlin.Exception() {
+    
+    class ProveException(
+        
+        val v1: kotlin.String
+        ) : Risc0Exception() {
+        override val message
+            get() = "v1=${ v1 }"
+    }
+    
+    class SerializeException(
+        
+        val v1: kotlin.String
+        ) : Risc0Exception() {
+        override val message
+            get() = "v1=${ v1 }"
+    }
+    
+    class VerifyException(
+        
+        val v1: kotlin.String
+        ) : Risc0Exception() {
+        override val message
+            get() = "v1=${ v1 }"
+    }
+    
+    class DecodeException(
+        
+        val v1: kotlin.String
+        ) : Risc0Exception() {
+        override val message
+            get() = "v1=${ v1 }"
+    }
+    
+    class InputException(
+        
+        val v1: kotlin.String
+        ) : Risc0Exception() {
+        override val message
+            get() = "v1=${ v1 }"
+    }
+    
+    class SignerMismatchException(
+        
+        val v1: kotlin.String
+        ) : Risc0Exception() {
+        override val message
+            get() = "v1=${ v1 }"
+    }
+    
+    class MessageMismatchException(
+        
+        val v1: kotlin.String
+        ) : Risc0Exception() {
+        override val message
+            get() = "v1=${ v1 }"
+    }
+    
+    class ChallengeMismatchException(
+        
+        val v1: kotlin.String
+        ) : Risc0Exception() {
+        override val message
+            get() = "v1=${ v1 }"
+    }
+    
+    class ContextMismatchException(
+        
+        val v1: kotlin.String
+        ) : Risc0Exception() {
+        override val message
+            get() = "v1=${ v1 }"
+    }
+    
+    class ExpiredException(
+        
+        val v1: kotlin.String
+        ) : Risc0Exception() {
+        override val message
+            get() = "v1=${ v1 }"
+    }
+    
+    class NotYetValidException(
+        
+        val v1: kotlin.String
+        ) : Risc0Exception() {
+        override val message
+            get() = "v1=${ v1 }"
+    }
+    
+
+    companion object ErrorHandler : UniffiRustCallStatusErrorHandler<Risc0Exception> {
+        override fun lift(error_buf: RustBuffer.ByValue): Risc0Exception = FfiConverterTypeRisc0Error.lift(error_buf)
+    }
+
+    
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0Error : FfiConverterRustBuffer<Risc0Exception> {
+    override fun read(buf: ByteBuffer): Risc0Exception {
+        
+
+        return when(buf.getInt()) {
+            1 -> Risc0Exception.ProveException(
+                FfiConverterString.read(buf),
+                )
+            2 -> Risc0Exception.SerializeException(
+                FfiConverterString.read(buf),
+                )
+            3 -> Risc0Exception.VerifyException(
+                FfiConverterString.read(buf),
+                )
+            4 -> Risc0Exception.DecodeException(
+                FfiConverterString.read(buf),
+                )
+            5 -> Risc0Exception.InputException(
+                FfiConverterString.read(buf),
+                )
+            6 -> Risc0Exception.SignerMismatchException(
+                FfiConverterString.read(buf),
+                )
+            7 -> Risc0Exception.MessageMismatchException(
+                FfiConverterString.read(buf),
+                )
+            8 -> Risc0Exception.ChallengeMismatchException(
+                FfiConverterString.read(buf),
+                )
+            9 -> Risc0Exception.ContextMismatchException(
+                FfiConverterString.read(buf),
+                )
+            10 -> Risc0Exception.ExpiredException(
+                FfiConverterString.read(buf),
+                )
+            11 -> Risc0Exception.NotYetValidException(
+                FfiConverterString.read(buf),
+                )
+            else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: Risc0Exception): ULong {
+        return when(value) {
+            is Risc0Exception.ProveException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+            is Risc0Exception.SerializeException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+            is Risc0Exception.VerifyException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+            is Risc0Exception.DecodeException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+            is Risc0Exception.InputException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+            is Risc0Exception.SignerMismatchException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+            is Risc0Exception.MessageMismatchException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+            is Risc0Exception.ChallengeMismatchException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+            is Risc0Exception.ContextMismatchException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+            is Risc0Exception.ExpiredException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+            is Risc0Exception.NotYetValidException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterString.allocationSize(value.v1)
+            )
+        }
+    }
+
+    override fun write(value: Risc0Exception, buf: ByteBuffer) {
+        when(value) {
+            is Risc0Exception.ProveException -> {
+                buf.putInt(1)
+                FfiConverterString.write(value.v1, buf)
+                Unit
+            }
+            is Risc0Exception.SerializeException -> {
+                buf.putInt(2)
+                FfiConverterString.write(value.v1, buf)
+                Unit
+            }
+            is Risc0Exception.VerifyException -> {
+                buf.putInt(3)
+                FfiConverterString.write(value.v1, buf)
+                Unit
+            }
+            is Risc0Exception.DecodeException -> {
+                buf.putInt(4)
+                FfiConverterString.write(value.v1, buf)
+                Unit
+            }
+            is Risc0Exception.InputException -> {
+                buf.putInt(5)
+                FfiConverterString.write(value.v1, buf)
+                Unit
+            }
+            is Risc0Exception.SignerMismatchException -> {
+                buf.putInt(6)
+                FfiConverterString.write(value.v1, buf)
+                Unit
+            }
+            is Risc0Exception.MessageMismatchException -> {
+                buf.putInt(7)
+                FfiConverterString.write(value.v1, buf)
+                Unit
+            }
+            is Risc0Exception.ChallengeMismatchException -> {
+                buf.putInt(8)
+                FfiConverterString.write(value.v1, buf)
+                Unit
+            }
+            is Risc0Exception.ContextMismatchException -> {
+                buf.putInt(9)
+                FfiConverterString.write(value.v1, buf)
+                Unit
+            }
+            is Risc0Exception.ExpiredException -> {
+                buf.putInt(10)
+                FfiConverterString.write(value.v1, buf)
+                Unit
+            }
+            is Risc0Exception.NotYetValidException -> {
+                buf.putInt(11)
+                FfiConverterString.write(value.v1, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+
+}
+
+
+
+/**
+ * How the signed message is handed to the guest.
+ */
+
+enum class Risc0MessageMode {
+    
+    /**
+     * The guest hashes the whole message and commits it to the journal.
+     */
+    FULL,
+    /**
+     * The host hashes the message with SHA-256; the guest verifies the
+     * signature over the digest and commits only the digest. Proving cost no
+     * longer grows with the message size.
+     */
+    PREHASHED;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0MessageMode: FfiConverterRustBuffer<Risc0MessageMode> {
+    override fun read(buf: ByteBuffer) = try {
+        Risc0MessageMode.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: Risc0MessageMode) = 4UL
+
+    override fun write(value: Risc0MessageMode, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * Kind of receipt that was produced.
+ */
+
+enum class Risc0ReceiptKind {
+    
+    /**
+     * See [`Risc0RequestedReceiptKind::Composite`].
+     */
+    COMPOSITE,
+    /**
+     * See [`Risc0RequestedReceiptKind::Succinct`].
+     */
+    SUCCINCT,
+    /**
+     * See [`Risc0RequestedReceiptKind::Groth16`].
+     */
+    GROTH16,
+    /**
+     * Dev-mode receipt without a real seal, produced instead of the
+     * requested kind when `RISC0_DEV_MODE` is set.
+     */
+    FAKE;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0ReceiptKind: FfiConverterRustBuffer<Risc0ReceiptKind> {
+    override fun read(buf: ByteBuffer) = try {
+        Risc0ReceiptKind.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: Risc0ReceiptKind) = 4UL
+
+    override fun write(value: Risc0ReceiptKind, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * Kind of receipt to prove, from largest and fastest to prove to smallest.
+ */
+
+enum class Risc0RequestedReceiptKind {
+    
+    /**
+     * One STARK seal per segment; the prover default. Size grows with the
+     * execution length.
+     */
+    COMPOSITE,
+    /**
+     * A single constant-size STARK seal (a few hundred KB).
+     */
+    SUCCINCT,
+    /**
+     * A Groth16 SNARK seal of a few hundred bytes, verifiable on-chain.
+     * Proving requires an x86 host and is not available on phones.
+     */
+    GROTH16;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0RequestedReceiptKind: FfiConverterRustBuffer<Risc0RequestedReceiptKind> {
+    override fun read(buf: ByteBuffer) = try {
+        Risc0RequestedReceiptKind.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: Risc0RequestedReceiptKind) = 4UL
+
+    override fun write(value: Risc0RequestedReceiptKind, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * RSA signature padding scheme.
+ */
+
+enum class Risc0RsaScheme {
+    
+    /**
+     * PKCS#1 v1.5, as used by RS256.
+     */
+    PKCS1V15,
+    /**
+     * PSS with a 32-byte salt, as used by PS256.
+     */
+    PSS;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeRisc0RsaScheme: FfiConverterRustBuffer<Risc0RsaScheme> {
+    override fun read(buf: ByteBuffer) = try {
+        Risc0RsaScheme.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: Risc0RsaScheme) = 4UL
+
+    override fun write(value: Risc0RsaScheme, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+/**
  * @suppress
  */
-public object FfiConverterOptionalString : FfiConverterRustBuffer<kotlin.String?> {
+public object FfiConverterOptionalString: FfiConverterRustBuffer<kotlin.String?> {
     override fun read(buf: ByteBuffer): kotlin.String? {
         if (buf.get().toInt() == 0) {
             return null
@@ -1794,10 +3662,7 @@ public object FfiConverterOptionalString : FfiConverterRustBuffer<kotlin.String?
         }
     }
 
-    override fun write(
-        value: kotlin.String?,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: kotlin.String?, buf: ByteBuffer) {
         if (value == null) {
             buf.put(0)
         } else {
@@ -1807,38 +3672,269 @@ public object FfiConverterOptionalString : FfiConverterRustBuffer<kotlin.String?
     }
 }
 
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalByteArray: FfiConverterRustBuffer<kotlin.ByteArray?> {
+    override fun read(buf: ByteBuffer): kotlin.ByteArray? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterByteArray.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.ByteArray?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterByteArray.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.ByteArray?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterByteArray.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceUInt: FfiConverterRustBuffer<List<kotlin.UInt>> {
+    override fun read(buf: ByteBuffer): List<kotlin.UInt> {
+        val len = buf.getInt()
+        return List<kotlin.UInt>(len) {
+            FfiConverterUInt.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<kotlin.UInt>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterUInt.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<kotlin.UInt>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterUInt.write(it, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceString: FfiConverterRustBuffer<List<kotlin.String>> {
+    override fun read(buf: ByteBuffer): List<kotlin.String> {
+        val len = buf.getInt()
+        return List<kotlin.String>(len) {
+            FfiConverterString.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<kotlin.String>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterString.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<kotlin.String>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterString.write(it, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceByteArray: FfiConverterRustBuffer<List<kotlin.ByteArray>> {
+    override fun read(buf: ByteBuffer): List<kotlin.ByteArray> {
+        val len = buf.getInt()
+        return List<kotlin.ByteArray>(len) {
+            FfiConverterByteArray.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<kotlin.ByteArray>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterByteArray.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<kotlin.ByteArray>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterByteArray.write(it, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeRisc0BatchEntry: FfiConverterRustBuffer<List<Risc0BatchEntry>> {
+    override fun read(buf: ByteBuffer): List<Risc0BatchEntry> {
+        val len = buf.getInt()
+        return List<Risc0BatchEntry>(len) {
+            FfiConverterTypeRisc0BatchEntry.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<Risc0BatchEntry>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeRisc0BatchEntry.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<Risc0BatchEntry>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeRisc0BatchEntry.write(it, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeRisc0Eip712Field: FfiConverterRustBuffer<List<Risc0Eip712Field>> {
+    override fun read(buf: ByteBuffer): List<Risc0Eip712Field> {
+        val len = buf.getInt()
+        return List<Risc0Eip712Field>(len) {
+            FfiConverterTypeRisc0Eip712Field.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<Risc0Eip712Field>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeRisc0Eip712Field.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<Risc0Eip712Field>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeRisc0Eip712Field.write(it, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeRisc0ThresholdSignature: FfiConverterRustBuffer<List<Risc0ThresholdSignature>> {
+    override fun read(buf: ByteBuffer): List<Risc0ThresholdSignature> {
+        val len = buf.getInt()
+        return List<Risc0ThresholdSignature>(len) {
+            FfiConverterTypeRisc0ThresholdSignature.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<Risc0ThresholdSignature>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeRisc0ThresholdSignature.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<Risc0ThresholdSignature>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeRisc0ThresholdSignature.write(it, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeRisc0VerifiedBatchEntry: FfiConverterRustBuffer<List<Risc0VerifiedBatchEntry>> {
+    override fun read(buf: ByteBuffer): List<Risc0VerifiedBatchEntry> {
+        val len = buf.getInt()
+        return List<Risc0VerifiedBatchEntry>(len) {
+            FfiConverterTypeRisc0VerifiedBatchEntry.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<Risc0VerifiedBatchEntry>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeRisc0VerifiedBatchEntry.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<Risc0VerifiedBatchEntry>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeRisc0VerifiedBatchEntry.write(it, buf)
+        }
+    }
+}
+
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterSequenceString : FfiConverterRustBuffer<List<kotlin.String>> {
-    override fun read(buf: ByteBuffer): List<kotlin.String> {
+public object FfiConverterSequenceTypeRisc0VerifyBytesOutput: FfiConverterRustBuffer<List<Risc0VerifyBytesOutput>> {
+    override fun read(buf: ByteBuffer): List<Risc0VerifyBytesOutput> {
         val len = buf.getInt()
-        return List<kotlin.String>(len) {
-            FfiConverterString.read(buf)
+        return List<Risc0VerifyBytesOutput>(len) {
+            FfiConverterTypeRisc0VerifyBytesOutput.read(buf)
         }
     }
 
-    override fun allocationSize(value: List<kotlin.String>): ULong {
+    override fun allocationSize(value: List<Risc0VerifyBytesOutput>): ULong {
         val sizeForLength = 4UL
-        val sizeForItems = value.map { FfiConverterString.allocationSize(it) }.sum()
+        val sizeForItems = value.map { FfiConverterTypeRisc0VerifyBytesOutput.allocationSize(it) }.sum()
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<kotlin.String>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<Risc0VerifyBytesOutput>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
-            FfiConverterString.write(it, buf)
+            FfiConverterTypeRisc0VerifyBytesOutput.write(it, buf)
         }
     }
 }
 
+
+
+
 /**
  * @suppress
  */
-public object FfiConverterMapStringSequenceString : FfiConverterRustBuffer<Map<kotlin.String, List<kotlin.String>>> {
+public object FfiConverterMapStringSequenceString: FfiConverterRustBuffer<Map<kotlin.String, List<kotlin.String>>> {
     override fun read(buf: ByteBuffer): Map<kotlin.String, List<kotlin.String>> {
         val len = buf.getInt()
         return buildMap<kotlin.String, List<kotlin.String>>(len) {
@@ -1852,19 +3948,14 @@ public object FfiConverterMapStringSequenceString : FfiConverterRustBuffer<Map<k
 
     override fun allocationSize(value: Map<kotlin.String, List<kotlin.String>>): ULong {
         val spaceForMapSize = 4UL
-        val spaceForChildren =
-            value
-                .map { (k, v) ->
-                    FfiConverterString.allocationSize(k) +
-                        FfiConverterSequenceString.allocationSize(v)
-                }.sum()
+        val spaceForChildren = value.map { (k, v) ->
+            FfiConverterString.allocationSize(k) +
+            FfiConverterSequenceString.allocationSize(v)
+        }.sum()
         return spaceForMapSize + spaceForChildren
     }
 
-    override fun write(
-        value: Map<kotlin.String, List<kotlin.String>>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: Map<kotlin.String, List<kotlin.String>>, buf: ByteBuffer) {
         buf.putInt(value.size)
         // The parens on `(k, v)` here ensure we're calling the right method,
         // which is important for compatibility with older android devices.
@@ -1875,152 +3966,1100 @@ public object FfiConverterMapStringSequenceString : FfiConverterRustBuffer<Map<k
         }
     }
 }
+        /**
+         * Same as [`crate::risc0_prove`] on Ed25519, with a freshly generated keypair.
+         */
+    @Throws(Risc0Exception::class) fun `ed25519Prove`(`message`: kotlin.String, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_ed25519_prove(
+        FfiConverterString.lower(`message`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Same as [`crate::risc0_prove_signature`] for a 32-byte Ed25519 public key
+         * and 64-byte signature.
+         *
+         * The signature is checked strictly, as the guest does, so weak keys and
+         * non-canonical signatures are rejected before proving.
+         */
+    @Throws(Risc0Exception::class) fun `ed25519ProveSignature`(`publicKey`: kotlin.ByteArray, `message`: kotlin.ByteArray, `signature`: kotlin.ByteArray, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_ed25519_prove_signature(
+        FfiConverterByteArray.lower(`publicKey`),FfiConverterByteArray.lower(`message`),FfiConverterByteArray.lower(`signature`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`ed25519_prove`] or [`ed25519_prove_signature`].
+         */
+    @Throws(Risc0Exception::class) fun `ed25519Verify`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyBytesOutput {
+            return FfiConverterTypeRisc0VerifyBytesOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_ed25519_verify(
+        FfiConverterByteArray.lower(`receiptBytes`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * EIP-712 encoding of an `address` member.
+         */
+    @Throws(Risc0Exception::class) fun `ethEncodeTypedAddress`(`address`: kotlin.ByteArray): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_eth_encode_typed_address(
+        FfiConverterByteArray.lower(`address`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * EIP-712 encoding of a `bool` member.
+         */ fun `ethEncodeTypedBool`(`value`: kotlin.Boolean): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_eth_encode_typed_bool(
+        FfiConverterBoolean.lower(`value`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * EIP-712 encoding of a `bytes` member: its Keccak-256.
+         */ fun `ethEncodeTypedBytes`(`value`: kotlin.ByteArray): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_eth_encode_typed_bytes(
+        FfiConverterByteArray.lower(`value`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * EIP-712 encoding of a `string` member: its Keccak-256.
+         */ fun `ethEncodeTypedString`(`value`: kotlin.String): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_eth_encode_typed_string(
+        FfiConverterString.lower(`value`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * EIP-712 encoding of a `uint` member that fits in 64 bits.
+         */ fun `ethEncodeTypedUint`(`value`: kotlin.ULong): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_eth_encode_typed_uint(
+        FfiConverterULong.lower(`value`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * EIP-712 `hashStruct`, which is also the encoding of a nested struct member.
+         *
+         * `struct_type` is the full encoded type including referenced types, e.g.
+         * `Mail(Person from,string contents)Person(string name,address wallet)`.
+         */
+    @Throws(Risc0Exception::class) fun `ethHashTypedStruct`(`structType`: kotlin.String, `encodedFields`: List<kotlin.ByteArray>): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_eth_hash_typed_struct(
+        FfiConverterString.lower(`structType`),FfiConverterSequenceByteArray.lower(`encodedFields`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Proves that the owner of an Ethereum address signed `message` with
+         * `personal_sign`.
+         *
+         * `signature` is the 65-byte `r || s || v` value wallets return. The journal
+         * commits the recovered address and the message.
+         */
+    @Throws(Risc0Exception::class) fun `ethProvePersonalSign`(`message`: kotlin.ByteArray, `signature`: kotlin.ByteArray, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_eth_prove_personal_sign(
+        FfiConverterByteArray.lower(`message`),FfiConverterByteArray.lower(`signature`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Proves that the owner of an Ethereum address signed EIP-712 typed data.
+         *
+         * The struct is passed as its encoded type and 32-byte member encodings (see
+         * the `eth_encode_typed_*` helpers). The guest computes the EIP-712 digest,
+         * recovers the signer from the 65-byte `r || s || v` signature, and commits
+         * the signer address, the domain separator, the struct type, and only the
+         * members listed in `disclosed_fields`.
+         */
+    @Throws(Risc0Exception::class) fun `ethProveTypedData`(`domain`: Risc0Eip712Domain, `structType`: kotlin.String, `encodedFields`: List<kotlin.ByteArray>, `disclosedFields`: List<kotlin.UInt>, `signature`: kotlin.ByteArray, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_eth_prove_typed_data(
+        FfiConverterTypeRisc0Eip712Domain.lower(`domain`),FfiConverterString.lower(`structType`),FfiConverterSequenceByteArray.lower(`encodedFields`),FfiConverterSequenceUInt.lower(`disclosedFields`),FfiConverterByteArray.lower(`signature`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * EIP-712 domain separator, to compare with
+         * [`Risc0VerifyTypedDataOutput::domain_separator`].
+         */
+    @Throws(Risc0Exception::class) fun `ethTypedDataDomainSeparator`(`domain`: Risc0Eip712Domain): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_eth_typed_data_domain_separator(
+        FfiConverterTypeRisc0Eip712Domain.lower(`domain`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`eth_prove_personal_sign`] and returns the signer
+         * address and message.
+         */
+    @Throws(Risc0Exception::class) fun `ethVerifyPersonalSign`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyEthOutput {
+            return FfiConverterTypeRisc0VerifyEthOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_eth_verify_personal_sign(
+        FfiConverterByteArray.lower(`receiptBytes`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`eth_prove_typed_data`] and returns the signer
+         * address, domain separator, struct type and disclosed fields.
+         */
+    @Throws(Risc0Exception::class) fun `ethVerifyTypedData`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyTypedDataOutput {
+            return FfiConverterTypeRisc0VerifyTypedDataOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_eth_verify_typed_data(
+        FfiConverterByteArray.lower(`receiptBytes`),_status)
+}
+    )
+    }
+    
 
-@Throws(MoproException::class)
-fun `generateCircomProof`(
-    `zkeyPath`: kotlin.String,
-    `circuitInputs`: kotlin.String,
-    `proofLib`: ProofLib,
-): CircomProofResult =
-    FfiConverterTypeCircomProofResult.lift(
-        uniffiRustCallWithError(MoproException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_generate_circom_proof(
-                FfiConverterString.lower(`zkeyPath`),
-                FfiConverterString.lower(`circuitInputs`),
-                FfiConverterTypeProofLib.lower(`proofLib`),
-                _status,
-            )
-        },
-    )
-
-@Throws(MoproException::class)
-fun `generateHalo2Proof`(
-    `srsPath`: kotlin.String,
-    `pkPath`: kotlin.String,
-    `circuitInputs`: Map<kotlin.String, List<kotlin.String>>,
-): Halo2ProofResult =
-    FfiConverterTypeHalo2ProofResult.lift(
-        uniffiRustCallWithError(MoproException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_generate_halo2_proof(
-                FfiConverterString.lower(`srsPath`),
-                FfiConverterString.lower(`pkPath`),
-                FfiConverterMapStringSequenceString.lower(`circuitInputs`),
-                _status,
-            )
-        },
-    )
-
-@Throws(MoproException::class)
-fun `generateNoirProof`(
-    `circuitPath`: kotlin.String,
-    `srsPath`: kotlin.String?,
-    `inputs`: List<kotlin.String>,
-    `onChain`: kotlin.Boolean,
-    `vk`: kotlin.ByteArray,
-    `lowMemoryMode`: kotlin.Boolean,
-): kotlin.ByteArray =
-    FfiConverterByteArray.lift(
-        uniffiRustCallWithError(MoproException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_generate_noir_proof(
-                FfiConverterString.lower(`circuitPath`),
-                FfiConverterOptionalString.lower(`srsPath`),
-                FfiConverterSequenceString.lower(`inputs`),
-                FfiConverterBoolean.lower(`onChain`),
-                FfiConverterByteArray.lower(`vk`),
-                FfiConverterBoolean.lower(`lowMemoryMode`),
-                _status,
-            )
-        },
-    )
-
-@Throws(MoproException::class)
-fun `getNoirVerificationKey`(
-    `circuitPath`: kotlin.String,
-    `srsPath`: kotlin.String?,
-    `onChain`: kotlin.Boolean,
-    `lowMemoryMode`: kotlin.Boolean,
-): kotlin.ByteArray =
-    FfiConverterByteArray.lift(
-        uniffiRustCallWithError(MoproException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_get_noir_verification_key(
-                FfiConverterString.lower(`circuitPath`),
-                FfiConverterOptionalString.lower(`srsPath`),
-                FfiConverterBoolean.lower(`onChain`),
-                FfiConverterBoolean.lower(`lowMemoryMode`),
-                _status,
-            )
-        },
-    )
-
-@Throws(Risc0Exception::class)
-fun `risc0Prove`(`message`: kotlin.String): Risc0ProofOutput =
-    FfiConverterTypeRisc0ProofOutput.lift(
-        uniffiRustCallWithError(Risc0Exception) { _status ->
-            UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_prove(FfiConverterString.lower(`message`), _status)
-        },
-    )
-
-@Throws(Risc0Exception::class)
-fun `risc0Verify`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyOutput =
-    FfiConverterTypeRisc0VerifyOutput.lift(
-        uniffiRustCallWithError(Risc0Exception) { _status ->
-            UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_verify(FfiConverterByteArray.lower(`receiptBytes`), _status)
-        },
-    )
-
-@Throws(MoproException::class)
-fun `verifyCircomProof`(
-    `zkeyPath`: kotlin.String,
-    `proofResult`: CircomProofResult,
-    `proofLib`: ProofLib,
-): kotlin.Boolean =
-    FfiConverterBoolean.lift(
-        uniffiRustCallWithError(MoproException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_verify_circom_proof(
-                FfiConverterString.lower(`zkeyPath`),
-                FfiConverterTypeCircomProofResult.lower(`proofResult`),
-                FfiConverterTypeProofLib.lower(`proofLib`),
-                _status,
-            )
-        },
-    )
-
-@Throws(MoproException::class)
-fun `verifyHalo2Proof`(
-    `srsPath`: kotlin.String,
-    `vkPath`: kotlin.String,
-    `proof`: kotlin.ByteArray,
-    `publicInput`: kotlin.ByteArray,
-): kotlin.Boolean =
-    FfiConverterBoolean.lift(
-        uniffiRustCallWithError(MoproException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_verify_halo2_proof(
-                FfiConverterString.lower(`srsPath`),
-                FfiConverterString.lower(`vkPath`),
-                FfiConverterByteArray.lower(`proof`),
-                FfiConverterByteArray.lower(`publicInput`),
-                _status,
-            )
-        },
-    )
-
-@Throws(MoproException::class)
-fun `verifyNoirProof`(
-    `circuitPath`: kotlin.String,
-    `proof`: kotlin.ByteArray,
-    `onChain`: kotlin.Boolean,
-    `vk`: kotlin.ByteArray,
-    `lowMemoryMode`: kotlin.Boolean,
-): kotlin.Boolean =
-    FfiConverterBoolean.lift(
-        uniffiRustCallWithError(MoproException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_verify_noir_proof(
-                FfiConverterString.lower(`circuitPath`),
-                FfiConverterByteArray.lower(`proof`),
-                FfiConverterBoolean.lower(`onChain`),
-                FfiConverterByteArray.lower(`vk`),
-                FfiConverterBoolean.lower(`lowMemoryMode`),
-                _status,
-            )
-        },
+    @Throws(MoproException::class) fun `generateCircomProof`(`zkeyPath`: kotlin.String, `circuitInputs`: kotlin.String, `proofLib`: ProofLib): CircomProofResult {
+            return FfiConverterTypeCircomProofResult.lift(
+    uniffiRustCallWithError(MoproException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_generate_circom_proof(
+        FfiConverterString.lower(`zkeyPath`),FfiConverterString.lower(`circuitInputs`),FfiConverterTypeProofLib.lower(`proofLib`),_status)
+}
+    )
+    }
+    
+
+    @Throws(MoproException::class) fun `generateHalo2Proof`(`srsPath`: kotlin.String, `pkPath`: kotlin.String, `circuitInputs`: Map<kotlin.String, List<kotlin.String>>): Halo2ProofResult {
+            return FfiConverterTypeHalo2ProofResult.lift(
+    uniffiRustCallWithError(MoproException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_generate_halo2_proof(
+        FfiConverterString.lower(`srsPath`),FfiConverterString.lower(`pkPath`),FfiConverterMapStringSequenceString.lower(`circuitInputs`),_status)
+}
+    )
+    }
+    
+
+    @Throws(MoproException::class) fun `generateNoirProof`(`circuitPath`: kotlin.String, `srsPath`: kotlin.String?, `inputs`: List<kotlin.String>, `onChain`: kotlin.Boolean, `vk`: kotlin.ByteArray, `lowMemoryMode`: kotlin.Boolean): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCallWithError(MoproException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_generate_noir_proof(
+        FfiConverterString.lower(`circuitPath`),FfiConverterOptionalString.lower(`srsPath`),FfiConverterSequenceString.lower(`inputs`),FfiConverterBoolean.lower(`onChain`),FfiConverterByteArray.lower(`vk`),FfiConverterBoolean.lower(`lowMemoryMode`),_status)
+}
+    )
+    }
+    
+
+    @Throws(MoproException::class) fun `getNoirVerificationKey`(`circuitPath`: kotlin.String, `srsPath`: kotlin.String?, `onChain`: kotlin.Boolean, `lowMemoryMode`: kotlin.Boolean): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCallWithError(MoproException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_get_noir_verification_key(
+        FfiConverterString.lower(`circuitPath`),FfiConverterOptionalString.lower(`srsPath`),FfiConverterBoolean.lower(`onChain`),FfiConverterBoolean.lower(`lowMemoryMode`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Same as [`crate::risc0_prove`] on P-384, with a freshly generated keypair.
+         */
+    @Throws(Risc0Exception::class) fun `p384Prove`(`message`: kotlin.String, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_p384_prove(
+        FfiConverterString.lower(`message`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Same as [`crate::risc0_prove_signature`] for a P-384 key and signature.
+         *
+         * The message is hashed with SHA-384. The signature may be `r || s` (96
+         * bytes) or DER, as found in X.509 certificates and ES384 tokens.
+         */
+    @Throws(Risc0Exception::class) fun `p384ProveSignature`(`publicKey`: kotlin.ByteArray, `message`: kotlin.ByteArray, `signature`: kotlin.ByteArray, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_p384_prove_signature(
+        FfiConverterByteArray.lower(`publicKey`),FfiConverterByteArray.lower(`message`),FfiConverterByteArray.lower(`signature`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`p384_prove`] or [`p384_prove_signature`].
+         */
+    @Throws(Risc0Exception::class) fun `p384Verify`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyBytesOutput {
+            return FfiConverterTypeRisc0VerifyBytesOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_p384_verify(
+        FfiConverterByteArray.lower(`receiptBytes`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Folds receipts from [`crate::risc0_prove`] into a single succinct or Groth16
+         * receipt.
+         *
+         * Every inner receipt is verified on the host first. The journal commits the
+         * inner image ID, the number of receipts and the Merkle root over their
+         * journals; with `commit_journals` it also commits every inner journal.
+         */
+    @Throws(Risc0Exception::class) fun `risc0Aggregate`(`receipts`: List<kotlin.ByteArray>, `commitJournals`: kotlin.Boolean, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_aggregate(
+        FfiConverterSequenceByteArray.lower(`receipts`),FfiConverterBoolean.lower(`commitJournals`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Returns the Merkle root over the journals of `receipts`, as committed by
+         * [`risc0_aggregate`]. Receipts must be in the order they were aggregated.
+         */
+    @Throws(Risc0Exception::class) fun `risc0AggregateRoot`(`receipts`: List<kotlin.ByteArray>): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_aggregate_root(
+        FfiConverterSequenceByteArray.lower(`receipts`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Returns the Merkle root over `(public key, message)` pairs, as committed by
+         * [`risc0_prove_batch`]. Pairs must be in batch order.
+         */
+    @Throws(Risc0Exception::class) fun `risc0BatchRoot`(`publicKeys`: List<kotlin.ByteArray>, `messages`: List<kotlin.ByteArray>): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_batch_root(
+        FfiConverterSequenceByteArray.lower(`publicKeys`),FfiConverterSequenceByteArray.lower(`messages`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Builds the next message of a chain: `previous_head || payload`.
+         *
+         * Pass the `head` of the latest link, or `None` to start a new chain.
+         */
+    @Throws(Risc0Exception::class) fun `risc0ChainMessage`(`previousHead`: kotlin.ByteArray?, `payload`: kotlin.ByteArray): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_chain_message(
+        FfiConverterOptionalByteArray.lower(`previousHead`),FfiConverterByteArray.lower(`payload`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Computes the commitment to `public_key` that [`risc0_prove_key_commitment`]
+         * publishes for the same `salt`.
+         */
+    @Throws(Risc0Exception::class) fun `risc0CommitPublicKey`(`publicKey`: kotlin.ByteArray, `salt`: kotlin.ByteArray): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_commit_public_key(
+        FfiConverterByteArray.lower(`publicKey`),FfiConverterByteArray.lower(`salt`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Compresses a receipt, e.g. a composite one from [`risc0_prove`], to
+         * `target_kind`, typically on a backend after a phone proved it quickly.
+         *
+         * Only succinct and Groth16 are valid targets. The journal is preserved, so
+         * the result verifies with the same `risc0_verify*` function as the input.
+         */
+    @Throws(Risc0Exception::class) fun `risc0CompressReceipt`(`receiptBytes`: kotlin.ByteArray, `targetKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_compress_receipt(
+        FfiConverterByteArray.lower(`receiptBytes`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`targetKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Builds a message bound to `context`: `context || 0x00 || payload`.
+         *
+         * The context must not contain a zero byte.
+         */
+    @Throws(Risc0Exception::class) fun `risc0ContextMessage`(`context`: kotlin.String, `payload`: kotlin.ByteArray): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_context_message(
+        FfiConverterString.lower(`context`),FfiConverterByteArray.lower(`payload`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Returns a fresh 32-byte challenge for a prover to bind its receipt to.
+         */ fun `risc0GenerateChallenge`(): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_generate_challenge(
+        _status)
+}
+    )
+    }
+    
+
+        /**
+         * Returns 32 random bytes, suitable as a blinding value for
+         * [`hidden::risc0_prove_hidden_message`] or a salt for
+         * [`key_commitment::risc0_prove_key_commitment`].
+         */ fun `risc0GenerateSalt`(): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_generate_salt(
+        _status)
+}
+    )
+    }
+    
+
+        /**
+         * Returns the Merkle root of a set of SEC1 public keys, as committed by
+         * [`risc0_prove_membership`]. The order of the keys matters.
+         */
+    @Throws(Risc0Exception::class) fun `risc0KeySetRoot`(`publicKeys`: List<kotlin.ByteArray>): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_key_set_root(
+        FfiConverterSequenceByteArray.lower(`publicKeys`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Opens a key commitment: returns whether `public_key` and `salt` produce
+         * `key_commitment`.
+         */
+    @Throws(Risc0Exception::class) fun `risc0OpenKeyCommitment`(`keyCommitment`: kotlin.ByteArray, `publicKey`: kotlin.ByteArray, `salt`: kotlin.ByteArray): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_open_key_commitment(
+        FfiConverterByteArray.lower(`keyCommitment`),FfiConverterByteArray.lower(`publicKey`),FfiConverterByteArray.lower(`salt`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Signs `message` with a fresh random key and proves the signature,
+         * producing a receipt of `receipt_kind`.
+         *
+         * Succinct and Groth16 receipts take longer to prove but are much smaller to
+         * upload; see [`Risc0ProofOutput::seal_size`].
+         */
+    @Throws(Risc0Exception::class) fun `risc0Prove`(`message`: kotlin.String, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_prove(
+        FfiConverterString.lower(`message`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Proves all `entries` in a single guest execution, returning one receipt.
+         *
+         * The journal commits the number of entries and the Merkle root over their
+         * `(key, SHA-256 of message)` leaves. With `commit_entries` it also commits
+         * every key and message, which grows the journal with the batch.
+         */
+    @Throws(Risc0Exception::class) fun `risc0ProveBatch`(`entries`: List<Risc0BatchEntry>, `commitEntries`: kotlin.Boolean, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_prove_batch(
+        FfiConverterSequenceTypeRisc0BatchEntry.lower(`entries`),FfiConverterBoolean.lower(`commitEntries`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Same as [`risc0_prove`] for arbitrary binary messages (CBOR, protobuf, DER, ...).
+         */
+    @Throws(Risc0Exception::class) fun `risc0ProveBytes`(`message`: kotlin.ByteArray, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_prove_bytes(
+        FfiConverterByteArray.lower(`message`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Proves the next link of a signature chain.
+         *
+         * Without `previous_receipt` this starts a new chain, and `message` must start
+         * with 32 zero bytes. Otherwise `previous_receipt` must be the latest link,
+         * signed by the same key, and `message` must start with its head; see
+         * [`risc0_chain_message`]. The result is a succinct or Groth16 receipt whose
+         * size does not grow with the chain; only a succinct link can be extended.
+         */
+    @Throws(Risc0Exception::class) fun `risc0ProveChainLink`(`previousReceipt`: kotlin.ByteArray?, `publicKey`: kotlin.ByteArray, `message`: kotlin.ByteArray, `signature`: kotlin.ByteArray, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_prove_chain_link(
+        FfiConverterOptionalByteArray.lower(`previousReceipt`),FfiConverterByteArray.lower(`publicKey`),FfiConverterByteArray.lower(`message`),FfiConverterByteArray.lower(`signature`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Proves a signature while committing only the SHA-256 of the message, so the
+         * receipt does not reveal the plaintext.
+         *
+         * A prover-chosen 32-byte `blinding` value is hashed in front of the message;
+         * use it for low-entropy messages that could otherwise be guessed from the
+         * hash. The verifier needs the same value to check a candidate message.
+         */
+    @Throws(Risc0Exception::class) fun `risc0ProveHiddenMessage`(`publicKey`: kotlin.ByteArray, `message`: kotlin.ByteArray, `signature`: kotlin.ByteArray, `blinding`: kotlin.ByteArray?, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_prove_hidden_message(
+        FfiConverterByteArray.lower(`publicKey`),FfiConverterByteArray.lower(`message`),FfiConverterByteArray.lower(`signature`),FfiConverterOptionalByteArray.lower(`blinding`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Proves a signature bound to an application `context` (e.g.
+         * `"com.example.wallet/login/v1"`), so the receipt cannot be reused by
+         * another application.
+         *
+         * With `require_context_prefix`, the guest also checks that the signed
+         * message starts with the context followed by a zero byte, so signatures
+         * made for one domain cannot be proven in another; see
+         * [`risc0_context_message`].
+         */
+    @Throws(Risc0Exception::class) fun `risc0ProveInContext`(`context`: kotlin.String, `publicKey`: kotlin.ByteArray, `message`: kotlin.ByteArray, `signature`: kotlin.ByteArray, `requireContextPrefix`: kotlin.Boolean, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_prove_in_context(
+        FfiConverterString.lower(`context`),FfiConverterByteArray.lower(`publicKey`),FfiConverterByteArray.lower(`message`),FfiConverterByteArray.lower(`signature`),FfiConverterBoolean.lower(`requireContextPrefix`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Proves a signature while committing only a salted commitment to the signer
+         * key, so the receipt cannot be linked to the key until the prover reveals the
+         * key and salt (see [`risc0_open_key_commitment`]).
+         */
+    @Throws(Risc0Exception::class) fun `risc0ProveKeyCommitment`(`publicKey`: kotlin.ByteArray, `message`: kotlin.ByteArray, `signature`: kotlin.ByteArray, `salt`: kotlin.ByteArray, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_prove_key_commitment(
+        FfiConverterByteArray.lower(`publicKey`),FfiConverterByteArray.lower(`message`),FfiConverterByteArray.lower(`signature`),FfiConverterByteArray.lower(`salt`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Proves that one of `public_keys` signed `message` without revealing which.
+         *
+         * The journal commits only the Merkle root of `public_keys` and the message;
+         * compare the root with [`risc0_key_set_root`] of the registered set.
+         */
+    @Throws(Risc0Exception::class) fun `risc0ProveMembership`(`publicKeys`: List<kotlin.ByteArray>, `publicKey`: kotlin.ByteArray, `message`: kotlin.ByteArray, `signature`: kotlin.ByteArray, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_prove_membership(
+        FfiConverterSequenceByteArray.lower(`publicKeys`),FfiConverterByteArray.lower(`publicKey`),FfiConverterByteArray.lower(`message`),FfiConverterByteArray.lower(`signature`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Proves a signature produced elsewhere, e.g. by a server or hardware token.
+         *
+         * `public_key` is a SEC1-encoded secp256r1 key and `signature` is either the
+         * fixed-size `r || s` encoding or ASN.1 DER. The signature is checked on the
+         * host first so an invalid one fails fast instead of panicking the guest.
+         */
+    @Throws(Risc0Exception::class) fun `risc0ProveSignature`(`publicKey`: kotlin.ByteArray, `message`: kotlin.ByteArray, `signature`: kotlin.ByteArray, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_prove_signature(
+        FfiConverterByteArray.lower(`publicKey`),FfiConverterByteArray.lower(`message`),FfiConverterByteArray.lower(`signature`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Same as [`risc0_prove_signature`], choosing how the message reaches the
+         * guest.
+         *
+         * Use [`Risc0MessageMode::Prehashed`] for large documents and verify the
+         * resulting receipt with [`prehash::risc0_verify_prehashed`].
+         */
+    @Throws(Risc0Exception::class) fun `risc0ProveSignatureWithMode`(`publicKey`: kotlin.ByteArray, `message`: kotlin.ByteArray, `signature`: kotlin.ByteArray, `mode`: Risc0MessageMode, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_prove_signature_with_mode(
+        FfiConverterByteArray.lower(`publicKey`),FfiConverterByteArray.lower(`message`),FfiConverterByteArray.lower(`signature`),FfiConverterTypeRisc0MessageMode.lower(`mode`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Proves that at least `threshold` distinct keys of `public_keys` signed
+         * `message`, without revealing which.
+         *
+         * Each of `signatures` names the index of its key in `public_keys` and is
+         * checked against that key only. The journal commits the Merkle root of
+         * `public_keys`, the threshold and the message; compare the root with
+         * [`crate::membership::risc0_key_set_root`].
+         */
+    @Throws(Risc0Exception::class) fun `risc0ProveThreshold`(`publicKeys`: List<kotlin.ByteArray>, `threshold`: kotlin.UInt, `message`: kotlin.ByteArray, `signatures`: List<Risc0ThresholdSignature>, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_prove_threshold(
+        FfiConverterSequenceByteArray.lower(`publicKeys`),FfiConverterUInt.lower(`threshold`),FfiConverterByteArray.lower(`message`),FfiConverterSequenceTypeRisc0ThresholdSignature.lower(`signatures`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Proves a signature and that the signer key is not in `revoked_public_keys`.
+         *
+         * The journal commits the key, the message, and the revocation root, so the
+         * verifier can check that the proof was made against the current list.
+         */
+    @Throws(Risc0Exception::class) fun `risc0ProveUnrevoked`(`revokedPublicKeys`: List<kotlin.ByteArray>, `publicKey`: kotlin.ByteArray, `message`: kotlin.ByteArray, `signature`: kotlin.ByteArray, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_prove_unrevoked(
+        FfiConverterSequenceByteArray.lower(`revokedPublicKeys`),FfiConverterByteArray.lower(`publicKey`),FfiConverterByteArray.lower(`message`),FfiConverterByteArray.lower(`signature`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Proves a signature bound to a verifier-supplied `challenge`, so the receipt
+         * cannot be replayed to a verifier that issued a different one.
+         *
+         * With `require_challenge_in_message`, the guest also checks that the signed
+         * message contains the challenge, which rules out signatures computed before
+         * the challenge was issued.
+         */
+    @Throws(Risc0Exception::class) fun `risc0ProveWithChallenge`(`publicKey`: kotlin.ByteArray, `message`: kotlin.ByteArray, `signature`: kotlin.ByteArray, `challenge`: kotlin.ByteArray, `requireChallengeInMessage`: kotlin.Boolean, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_prove_with_challenge(
+        FfiConverterByteArray.lower(`publicKey`),FfiConverterByteArray.lower(`message`),FfiConverterByteArray.lower(`signature`),FfiConverterByteArray.lower(`challenge`),FfiConverterBoolean.lower(`requireChallengeInMessage`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Proves a signature with a validity window committed next to the key and
+         * message. Timestamps are Unix seconds and `not_after` is inclusive.
+         */
+    @Throws(Risc0Exception::class) fun `risc0ProveWithValidity`(`publicKey`: kotlin.ByteArray, `message`: kotlin.ByteArray, `signature`: kotlin.ByteArray, `notBefore`: kotlin.ULong, `notAfter`: kotlin.ULong, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_prove_with_validity(
+        FfiConverterByteArray.lower(`publicKey`),FfiConverterByteArray.lower(`message`),FfiConverterByteArray.lower(`signature`),FfiConverterULong.lower(`notBefore`),FfiConverterULong.lower(`notAfter`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Returns the root of the revocation tree over `revoked_public_keys`, as
+         * committed by [`risc0_prove_unrevoked`]. The order of the keys does not matter.
+         */
+    @Throws(Risc0Exception::class) fun `risc0RevocationRoot`(`revokedPublicKeys`: List<kotlin.ByteArray>): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_revocation_root(
+        FfiConverterSequenceByteArray.lower(`revokedPublicKeys`),_status)
+}
+    )
+    }
+    
+
+    @Throws(Risc0Exception::class) fun `risc0Verify`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyOutput {
+            return FfiConverterTypeRisc0VerifyOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_verify(
+        FfiConverterByteArray.lower(`receiptBytes`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`risc0_aggregate`] and returns the committed
+         * count, root and, if present, inner statements.
+         *
+         * The receipt must aggregate `risc0_prove` receipts; aggregates over any
+         * other guest fail with a `VerifyError`.
+         */
+    @Throws(Risc0Exception::class) fun `risc0VerifyAggregate`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyAggregateOutput {
+            return FfiConverterTypeRisc0VerifyAggregateOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_verify_aggregate(
+        FfiConverterByteArray.lower(`receiptBytes`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`risc0_prove_batch`] and returns the committed
+         * count, root and, if present, entries.
+         *
+         * Without committed entries, compare the root with [`risc0_batch_root`] over
+         * the expected keys and messages.
+         */
+    @Throws(Risc0Exception::class) fun `risc0VerifyBatch`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyBatchOutput {
+            return FfiConverterTypeRisc0VerifyBatchOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_verify_batch(
+        FfiConverterByteArray.lower(`receiptBytes`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Same as [`risc0_verify`] but returns the message as raw bytes, so receipts
+         * over non-UTF-8 payloads verify instead of failing with a `DecodeError`.
+         */
+    @Throws(Risc0Exception::class) fun `risc0VerifyBytes`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyBytesOutput {
+            return FfiConverterTypeRisc0VerifyBytesOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_verify_bytes(
+        FfiConverterByteArray.lower(`receiptBytes`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`risc0_prove_chain_link`] and returns the chain's
+         * signer, length and head.
+         */
+    @Throws(Risc0Exception::class) fun `risc0VerifyChain`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyChainOutput {
+            return FfiConverterTypeRisc0VerifyChainOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_verify_chain(
+        FfiConverterByteArray.lower(`receiptBytes`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`risc0_prove_hidden_message`].
+         *
+         * When `candidate_message` is given, it is hashed with `blinding` and must
+         * match the committed hash, otherwise [`Risc0Error::MessageMismatchError`] is
+         * returned.
+         */
+    @Throws(Risc0Exception::class) fun `risc0VerifyHiddenMessage`(`receiptBytes`: kotlin.ByteArray, `candidateMessage`: kotlin.ByteArray?, `blinding`: kotlin.ByteArray?): Risc0VerifyCommitmentOutput {
+            return FfiConverterTypeRisc0VerifyCommitmentOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_verify_hidden_message(
+        FfiConverterByteArray.lower(`receiptBytes`),FfiConverterOptionalByteArray.lower(`candidateMessage`),FfiConverterOptionalByteArray.lower(`blinding`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`risc0_prove_in_context`] and checks that it was
+         * made for `expected_context`.
+         *
+         * With `require_context_prefix`, receipts whose message was not checked to
+         * start with the context are rejected too. Both cases fail with
+         * [`Risc0Error::ContextMismatchError`].
+         */
+    @Throws(Risc0Exception::class) fun `risc0VerifyInContext`(`receiptBytes`: kotlin.ByteArray, `expectedContext`: kotlin.String, `requireContextPrefix`: kotlin.Boolean): Risc0VerifyBytesOutput {
+            return FfiConverterTypeRisc0VerifyBytesOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_verify_in_context(
+        FfiConverterByteArray.lower(`receiptBytes`),FfiConverterString.lower(`expectedContext`),FfiConverterBoolean.lower(`requireContextPrefix`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`risc0_prove_key_commitment`] and returns the
+         * committed key commitment and message.
+         */
+    @Throws(Risc0Exception::class) fun `risc0VerifyKeyCommitment`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyKeyCommitmentOutput {
+            return FfiConverterTypeRisc0VerifyKeyCommitmentOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_verify_key_commitment(
+        FfiConverterByteArray.lower(`receiptBytes`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`risc0_prove_membership`] and returns the
+         * committed key set root and message.
+         */
+    @Throws(Risc0Exception::class) fun `risc0VerifyMembership`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyMembershipOutput {
+            return FfiConverterTypeRisc0VerifyMembershipOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_verify_membership(
+        FfiConverterByteArray.lower(`receiptBytes`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt produced in [`crate::Risc0MessageMode::Prehashed`] and
+         * returns the committed message digest. Compare it with the SHA-256 of the
+         * document the caller expects.
+         */
+    @Throws(Risc0Exception::class) fun `risc0VerifyPrehashed`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyDigestOutput {
+            return FfiConverterTypeRisc0VerifyDigestOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_verify_prehashed(
+        FfiConverterByteArray.lower(`receiptBytes`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`risc0_prove_threshold`] and returns the committed
+         * key set root, threshold and message.
+         */
+    @Throws(Risc0Exception::class) fun `risc0VerifyThreshold`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyThresholdOutput {
+            return FfiConverterTypeRisc0VerifyThresholdOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_verify_threshold(
+        FfiConverterByteArray.lower(`receiptBytes`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`risc0_prove_unrevoked`] and returns the committed
+         * key, message, and revocation root. Compare the root with
+         * [`risc0_revocation_root`] of the current revocation list.
+         */
+    @Throws(Risc0Exception::class) fun `risc0VerifyUnrevoked`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyUnrevokedOutput {
+            return FfiConverterTypeRisc0VerifyUnrevokedOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_verify_unrevoked(
+        FfiConverterByteArray.lower(`receiptBytes`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`risc0_prove_with_challenge`] and checks that it
+         * was bound to `expected_challenge`.
+         *
+         * With `require_challenge_in_message`, receipts whose signed message was not
+         * checked to contain the challenge are rejected too. Both cases fail with
+         * [`Risc0Error::ChallengeMismatchError`].
+         */
+    @Throws(Risc0Exception::class) fun `risc0VerifyWithChallenge`(`receiptBytes`: kotlin.ByteArray, `expectedChallenge`: kotlin.ByteArray, `requireChallengeInMessage`: kotlin.Boolean): Risc0VerifyBytesOutput {
+            return FfiConverterTypeRisc0VerifyBytesOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_verify_with_challenge(
+        FfiConverterByteArray.lower(`receiptBytes`),FfiConverterByteArray.lower(`expectedChallenge`),FfiConverterBoolean.lower(`requireChallengeInMessage`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt like [`risc0_verify`] and additionally requires the
+         * journal to match an expected signer and, optionally, message.
+         *
+         * Fails with [`Risc0Error::SignerMismatchError`] when the proven key is not in
+         * `allowed_public_keys` and with [`Risc0Error::MessageMismatchError`] when the
+         * message or its SHA-256 differ from the expected values.
+         */
+    @Throws(Risc0Exception::class) fun `risc0VerifyWithPolicy`(`receiptBytes`: kotlin.ByteArray, `allowedPublicKeys`: List<kotlin.ByteArray>, `expectedMessage`: kotlin.ByteArray?, `expectedMessageSha256`: kotlin.ByteArray?): Risc0VerifyOutput {
+            return FfiConverterTypeRisc0VerifyOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_verify_with_policy(
+        FfiConverterByteArray.lower(`receiptBytes`),FfiConverterSequenceByteArray.lower(`allowedPublicKeys`),FfiConverterOptionalByteArray.lower(`expectedMessage`),FfiConverterOptionalByteArray.lower(`expectedMessageSha256`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`risc0_prove_with_validity`] at time `now` (Unix
+         * seconds), failing with [`Risc0Error::NotYetValidError`] or
+         * [`Risc0Error::ExpiredError`] outside the committed window.
+         */
+    @Throws(Risc0Exception::class) fun `risc0VerifyWithValidity`(`receiptBytes`: kotlin.ByteArray, `now`: kotlin.ULong): Risc0VerifyValidityOutput {
+            return FfiConverterTypeRisc0VerifyValidityOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_risc0_verify_with_validity(
+        FfiConverterByteArray.lower(`receiptBytes`),FfiConverterULong.lower(`now`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Computes the modulus hash that [`rsa_verify`] reports, so verifiers can
+         * pin the expected issuer key.
+         */ fun `rsaModulusHash`(`modulus`: kotlin.ByteArray): kotlin.ByteArray {
+            return FfiConverterByteArray.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_rsa_modulus_hash(
+        FfiConverterByteArray.lower(`modulus`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Same as [`crate::risc0_prove`] on RSA, with a freshly generated 2048-bit
+         * key. Key generation takes noticeably longer than for elliptic curves.
+         */
+    @Throws(Risc0Exception::class) fun `rsaProve`(`message`: kotlin.String, `scheme`: Risc0RsaScheme, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_rsa_prove(
+        FfiConverterString.lower(`message`),FfiConverterTypeRisc0RsaScheme.lower(`scheme`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Same as [`crate::risc0_prove_signature`] for an RSA-2048, RSA-3072 or
+         * RSA-4096 key given as a big-endian modulus and public exponent.
+         *
+         * The message is hashed with SHA-256. The signature is checked on the host
+         * first so that invalid input fails fast instead of inside the prover.
+         */
+    @Throws(Risc0Exception::class) fun `rsaProveSignature`(`modulus`: kotlin.ByteArray, `exponent`: kotlin.ByteArray, `scheme`: Risc0RsaScheme, `message`: kotlin.ByteArray, `signature`: kotlin.ByteArray, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_rsa_prove_signature(
+        FfiConverterByteArray.lower(`modulus`),FfiConverterByteArray.lower(`exponent`),FfiConverterTypeRisc0RsaScheme.lower(`scheme`),FfiConverterByteArray.lower(`message`),FfiConverterByteArray.lower(`signature`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`rsa_prove`] or [`rsa_prove_signature`].
+         */
+    @Throws(Risc0Exception::class) fun `rsaVerify`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyRsaOutput {
+            return FfiConverterTypeRisc0VerifyRsaOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_rsa_verify(
+        FfiConverterByteArray.lower(`receiptBytes`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Same as [`crate::risc0_prove`] with a BIP-340 signature from a freshly
+         * generated Taproot key. BIP-340 signs 32-byte messages, so the key signs
+         * and the journal commits the SHA-256 of `message`.
+         */
+    @Throws(Risc0Exception::class) fun `schnorrProve`(`message`: kotlin.String, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_schnorr_prove(
+        FfiConverterString.lower(`message`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Same as [`crate::risc0_prove_signature`] for a 32-byte x-only public key
+         * and 64-byte BIP-340 signature over the raw 32-byte `message`, usually a
+         * sighash.
+         */
+    @Throws(Risc0Exception::class) fun `schnorrProveSignature`(`publicKey`: kotlin.ByteArray, `message`: kotlin.ByteArray, `signature`: kotlin.ByteArray, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_schnorr_prove_signature(
+        FfiConverterByteArray.lower(`publicKey`),FfiConverterByteArray.lower(`message`),FfiConverterByteArray.lower(`signature`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`schnorr_prove`] or [`schnorr_prove_signature`].
+         *
+         * The signer reports `Risc0Curve::Bip340` with the SEC1 encodings of the
+         * even-Y point BIP-340 assigns to the x-only key, so the x-only key is
+         * `compressed[1..]`.
+         */
+    @Throws(Risc0Exception::class) fun `schnorrVerify`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyBytesOutput {
+            return FfiConverterTypeRisc0VerifyBytesOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_schnorr_verify(
+        FfiConverterByteArray.lower(`receiptBytes`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Same as [`crate::risc0_prove`] on secp256k1, with a freshly generated keypair.
+         */
+    @Throws(Risc0Exception::class) fun `secp256k1Prove`(`message`: kotlin.String, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_secp256k1_prove(
+        FfiConverterString.lower(`message`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Same as [`crate::risc0_prove_signature`] for a secp256k1 key and signature.
+         *
+         * The message is hashed with SHA-256. The signature may be `r || s` or DER.
+         */
+    @Throws(Risc0Exception::class) fun `secp256k1ProveSignature`(`publicKey`: kotlin.ByteArray, `message`: kotlin.ByteArray, `signature`: kotlin.ByteArray, `receiptKind`: Risc0RequestedReceiptKind): Risc0ProofOutput {
+            return FfiConverterTypeRisc0ProofOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_secp256k1_prove_signature(
+        FfiConverterByteArray.lower(`publicKey`),FfiConverterByteArray.lower(`message`),FfiConverterByteArray.lower(`signature`),FfiConverterTypeRisc0RequestedReceiptKind.lower(`receiptKind`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Verifies a receipt from [`secp256k1_prove`] or [`secp256k1_prove_signature`].
+         */
+    @Throws(Risc0Exception::class) fun `secp256k1Verify`(`receiptBytes`: kotlin.ByteArray): Risc0VerifyBytesOutput {
+            return FfiConverterTypeRisc0VerifyBytesOutput.lift(
+    uniffiRustCallWithError(Risc0Exception) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_secp256k1_verify(
+        FfiConverterByteArray.lower(`receiptBytes`),_status)
+}
+    )
+    }
+    
+
+    @Throws(MoproException::class) fun `verifyCircomProof`(`zkeyPath`: kotlin.String, `proofResult`: CircomProofResult, `proofLib`: ProofLib): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCallWithError(MoproException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_verify_circom_proof(
+        FfiConverterString.lower(`zkeyPath`),FfiConverterTypeCircomProofResult.lower(`proofResult`),FfiConverterTypeProofLib.lower(`proofLib`),_status)
+}
+    )
+    }
+    
+
+    @Throws(MoproException::class) fun `verifyHalo2Proof`(`srsPath`: kotlin.String, `vkPath`: kotlin.String, `proof`: kotlin.ByteArray, `publicInput`: kotlin.ByteArray): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCallWithError(MoproException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_verify_halo2_proof(
+        FfiConverterString.lower(`srsPath`),FfiConverterString.lower(`vkPath`),FfiConverterByteArray.lower(`proof`),FfiConverterByteArray.lower(`publicInput`),_status)
+}
+    )
+    }
+    
+
+    @Throws(MoproException::class) fun `verifyNoirProof`(`circuitPath`: kotlin.String, `proof`: kotlin.ByteArray, `onChain`: kotlin.Boolean, `vk`: kotlin.ByteArray, `lowMemoryMode`: kotlin.Boolean): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCallWithError(MoproException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mopro_r0_example_app_fn_func_verify_noir_proof(
+        FfiConverterString.lower(`circuitPath`),FfiConverterByteArray.lower(`proof`),FfiConverterBoolean.lower(`onChain`),FfiConverterByteArray.lower(`vk`),FfiConverterBoolean.lower(`lowMemoryMode`),_status)
+}
     )
+    }
+    
+
+
@@ -312,16 +312,27 @@ public class MoproFlutterPlugin: NSObject, FlutterPlugin {
       }
     case "generateRisc0Proof":
       guard let args = call.arguments as? [String: Any],
-        let message = args["message"] as? String
+        let message = args["message"] as? String,
+        let receiptKindIndex = args["receiptKind"] as? Int
       else {
         result(FlutterError(code: "ARGUMENT_ERROR", message: "Missing arguments", details: nil))
         return
       }
 
       do {
-        let proofResult = try risc0Prove(message: message)
+        var receiptKind: Risc0RequestedReceiptKind
+        if receiptKindIndex == 1 {
+          receiptKind = Risc0RequestedReceiptKind.succinct
+        } else if receiptKindIndex == 2 {
+          receiptKind = Risc0RequestedReceiptKind.groth16
+        } else {
+          receiptKind = Risc0RequestedReceiptKind.composite
+        }
+        let proofResult = try risc0Prove(message: message, receiptKind: receiptKind)
         let resultMap: [String: Any] = [
           "receipt": proofResult.receipt,
+          "receiptKind": String(describing: proofResult.receiptKind),
+          "sealSize": Int(proofResult.sealSize),
         ]
         result(resultMap)
       } catch {
@@ -343,6 +354,12 @@ public class MoproFlutterPlugin: NSObject, FlutterPlugin {
         let resultMap: [String: Any] = [
           "isValid": verifyResult.isValid,
           "verifiedMessage": verifyResult.verifiedMessage,
+          "signer": [
+            "curve": String(describing: verifyResult.signer.curve),
+            "compressed": verifyResult.signer.compressed,
+            "uncompressed": verifyResult.signer.uncompressed,
+            "fingerprint": verifyResult.signer.fingerprint,
+          ],
         ]
         result(resultMap)
       } catch {
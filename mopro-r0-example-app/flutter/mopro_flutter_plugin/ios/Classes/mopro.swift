@@ -397,6 +397,38 @@ fileprivate final class UniffiHandleMap<T>: @unchecked Sendable {
 // Public interface members begin here.
 
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt32: FfiConverterPrimitive {
+    typealias FfiType = UInt32
+    typealias SwiftType = UInt32
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt32 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt64: FfiConverterPrimitive {
+    typealias FfiType = UInt64
+    typealias SwiftType = UInt64
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt64 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -871,31 +903,58 @@ public func FfiConverterTypeHalo2ProofResult_lower(_ value: Halo2ProofResult) ->
 }
 
 
-public struct Risc0ProofOutput {
-    public var receipt: Data
+/**
+ * One signed message of a batch to prove.
+ */
+public struct Risc0BatchEntry {
+    /**
+     * SEC1-encoded secp256r1 public key.
+     */
+    public var publicKey: Data
+    public var message: Data
+    /**
+     * `r || s` or DER-encoded ECDSA signature.
+     */
+    public var signature: Data
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(receipt: Data) {
-        self.receipt = receipt
+    public init(
+        /**
+         * SEC1-encoded secp256r1 public key.
+         */publicKey: Data, message: Data, 
+        /**
+         * `r || s` or DER-encoded ECDSA signature.
+         */signature: Data) {
+        self.publicKey = publicKey
+        self.message = message
+        self.signature = signature
     }
 }
 
 #if compiler(>=6)
-extension Risc0ProofOutput: Sendable {}
+extension Risc0BatchEntry: Sendable {}
 #endif
 
 
-extension Risc0ProofOutput: Equatable, Hashable {
-    public static func ==(lhs: Risc0ProofOutput, rhs: Risc0ProofOutput) -> Bool {
-        if lhs.receipt != rhs.receipt {
+extension Risc0BatchEntry: Equatable, Hashable {
+    public static func ==(lhs: Risc0BatchEntry, rhs: Risc0BatchEntry) -> Bool {
+        if lhs.publicKey != rhs.publicKey {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        if lhs.signature != rhs.signature {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(receipt)
+        hasher.combine(publicKey)
+        hasher.combine(message)
+        hasher.combine(signature)
     }
 }
 
@@ -904,16 +963,20 @@ extension Risc0ProofOutput: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRisc0ProofOutput: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0ProofOutput {
+public struct FfiConverterTypeRisc0BatchEntry: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0BatchEntry {
         return
-            try Risc0ProofOutput(
-                receipt: FfiConverterData.read(from: &buf)
+            try Risc0BatchEntry(
+                publicKey: FfiConverterData.read(from: &buf), 
+                message: FfiConverterData.read(from: &buf), 
+                signature: FfiConverterData.read(from: &buf)
         )
     }
 
-    public static func write(_ value: Risc0ProofOutput, into buf: inout [UInt8]) {
-        FfiConverterData.write(value.receipt, into: &buf)
+    public static func write(_ value: Risc0BatchEntry, into buf: inout [UInt8]) {
+        FfiConverterData.write(value.publicKey, into: &buf)
+        FfiConverterData.write(value.message, into: &buf)
+        FfiConverterData.write(value.signature, into: &buf)
     }
 }
 
@@ -921,49 +984,69 @@ public struct FfiConverterTypeRisc0ProofOutput: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRisc0ProofOutput_lift(_ buf: RustBuffer) throws -> Risc0ProofOutput {
-    return try FfiConverterTypeRisc0ProofOutput.lift(buf)
+public func FfiConverterTypeRisc0BatchEntry_lift(_ buf: RustBuffer) throws -> Risc0BatchEntry {
+    return try FfiConverterTypeRisc0BatchEntry.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRisc0ProofOutput_lower(_ value: Risc0ProofOutput) -> RustBuffer {
-    return FfiConverterTypeRisc0ProofOutput.lower(value)
+public func FfiConverterTypeRisc0BatchEntry_lower(_ value: Risc0BatchEntry) -> RustBuffer {
+    return FfiConverterTypeRisc0BatchEntry.lower(value)
 }
 
 
-public struct Risc0VerifyOutput {
-    public var isValid: Bool
-    public var verifiedMessage: String
+/**
+ * EIP-712 domain, given like a struct: its encoded type and member
+ * encodings, so any subset of the domain fields can be used.
+ */
+public struct Risc0Eip712Domain {
+    /**
+     * Encoded domain type, e.g.
+     * `EIP712Domain(string name,uint256 chainId,address verifyingContract)`.
+     */
+    public var domainType: String
+    /**
+     * 32-byte encodings of the members listed in `domain_type`; see the
+     * `eth_encode_typed_*` helpers.
+     */
+    public var encodedFields: [Data]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(isValid: Bool, verifiedMessage: String) {
-        self.isValid = isValid
-        self.verifiedMessage = verifiedMessage
+    public init(
+        /**
+         * Encoded domain type, e.g.
+         * `EIP712Domain(string name,uint256 chainId,address verifyingContract)`.
+         */domainType: String, 
+        /**
+         * 32-byte encodings of the members listed in `domain_type`; see the
+         * `eth_encode_typed_*` helpers.
+         */encodedFields: [Data]) {
+        self.domainType = domainType
+        self.encodedFields = encodedFields
     }
 }
 
 #if compiler(>=6)
-extension Risc0VerifyOutput: Sendable {}
+extension Risc0Eip712Domain: Sendable {}
 #endif
 
 
-extension Risc0VerifyOutput: Equatable, Hashable {
-    public static func ==(lhs: Risc0VerifyOutput, rhs: Risc0VerifyOutput) -> Bool {
-        if lhs.isValid != rhs.isValid {
+extension Risc0Eip712Domain: Equatable, Hashable {
+    public static func ==(lhs: Risc0Eip712Domain, rhs: Risc0Eip712Domain) -> Bool {
+        if lhs.domainType != rhs.domainType {
             return false
         }
-        if lhs.verifiedMessage != rhs.verifiedMessage {
+        if lhs.encodedFields != rhs.encodedFields {
             return false
         }
         return true
     }
 
     public func hash(into hasher: inout Hasher) {
-        hasher.combine(isValid)
-        hasher.combine(verifiedMessage)
+        hasher.combine(domainType)
+        hasher.combine(encodedFields)
     }
 }
 
@@ -972,18 +1055,18 @@ extension Risc0VerifyOutput: Equatable, Hashable {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRisc0VerifyOutput: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0VerifyOutput {
+public struct FfiConverterTypeRisc0Eip712Domain: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0Eip712Domain {
         return
-            try Risc0VerifyOutput(
-                isValid: FfiConverterBool.read(from: &buf), 
-                verifiedMessage: FfiConverterString.read(from: &buf)
+            try Risc0Eip712Domain(
+                domainType: FfiConverterString.read(from: &buf), 
+                encodedFields: FfiConverterSequenceData.read(from: &buf)
         )
     }
 
-    public static func write(_ value: Risc0VerifyOutput, into buf: inout [UInt8]) {
-        FfiConverterBool.write(value.isValid, into: &buf)
-        FfiConverterString.write(value.verifiedMessage, into: &buf)
+    public static func write(_ value: Risc0Eip712Domain, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.domainType, into: &buf)
+        FfiConverterSequenceData.write(value.encodedFields, into: &buf)
     }
 }
 
@@ -991,80 +1074,78 @@ public struct FfiConverterTypeRisc0VerifyOutput: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRisc0VerifyOutput_lift(_ buf: RustBuffer) throws -> Risc0VerifyOutput {
-    return try FfiConverterTypeRisc0VerifyOutput.lift(buf)
+public func FfiConverterTypeRisc0Eip712Domain_lift(_ buf: RustBuffer) throws -> Risc0Eip712Domain {
+    return try FfiConverterTypeRisc0Eip712Domain.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRisc0VerifyOutput_lower(_ value: Risc0VerifyOutput) -> RustBuffer {
-    return FfiConverterTypeRisc0VerifyOutput.lower(value)
+public func FfiConverterTypeRisc0Eip712Domain_lower(_ value: Risc0Eip712Domain) -> RustBuffer {
+    return FfiConverterTypeRisc0Eip712Domain.lower(value)
 }
 
 
-public enum MoproError {
+/**
+ * A disclosed member of a typed struct, by position in the struct type.
+ */
+public struct Risc0Eip712Field {
+    public var index: UInt32
+    /**
+     * 32-byte EIP-712 encoding of the member value.
+     */
+    public var value: Data
 
-    
-    
-    case CircomError(String
-    )
-    case Halo2Error(String
-    )
-    case NoirError(String
-    )
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(index: UInt32, 
+        /**
+         * 32-byte EIP-712 encoding of the member value.
+         */value: Data) {
+        self.index = index
+        self.value = value
+    }
 }
 
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
+#if compiler(>=6)
+extension Risc0Eip712Field: Sendable {}
 #endif
-public struct FfiConverterTypeMoproError: FfiConverterRustBuffer {
-    typealias SwiftType = MoproError
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MoproError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-
-        
 
-        
-        case 1: return .CircomError(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 2: return .Halo2Error(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 3: return .NoirError(
-            try FfiConverterString.read(from: &buf)
-            )
 
-         default: throw UniffiInternalError.unexpectedEnumCase
+extension Risc0Eip712Field: Equatable, Hashable {
+    public static func ==(lhs: Risc0Eip712Field, rhs: Risc0Eip712Field) -> Bool {
+        if lhs.index != rhs.index {
+            return false
         }
+        if lhs.value != rhs.value {
+            return false
+        }
+        return true
     }
 
-    public static func write(_ value: MoproError, into buf: inout [UInt8]) {
-        switch value {
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(index)
+        hasher.combine(value)
+    }
+}
 
-        
 
-        
-        
-        case let .CircomError(v1):
-            writeInt(&buf, Int32(1))
-            FfiConverterString.write(v1, into: &buf)
-            
-        
-        case let .Halo2Error(v1):
-            writeInt(&buf, Int32(2))
-            FfiConverterString.write(v1, into: &buf)
-            
-        
-        case let .NoirError(v1):
-            writeInt(&buf, Int32(3))
-            FfiConverterString.write(v1, into: &buf)
-            
-        }
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0Eip712Field: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0Eip712Field {
+        return
+            try Risc0Eip712Field(
+                index: FfiConverterUInt32.read(from: &buf), 
+                value: FfiConverterData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0Eip712Field, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.index, into: &buf)
+        FfiConverterData.write(value.value, into: &buf)
     }
 }
 
@@ -1072,73 +1153,89 @@ public struct FfiConverterTypeMoproError: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeMoproError_lift(_ buf: RustBuffer) throws -> MoproError {
-    return try FfiConverterTypeMoproError.lift(buf)
+public func FfiConverterTypeRisc0Eip712Field_lift(_ buf: RustBuffer) throws -> Risc0Eip712Field {
+    return try FfiConverterTypeRisc0Eip712Field.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeMoproError_lower(_ value: MoproError) -> RustBuffer {
-    return FfiConverterTypeMoproError.lower(value)
+public func FfiConverterTypeRisc0Eip712Field_lower(_ value: Risc0Eip712Field) -> RustBuffer {
+    return FfiConverterTypeRisc0Eip712Field.lower(value)
 }
 
 
-extension MoproError: Equatable, Hashable {}
-
-
+public struct Risc0ProofOutput {
+    public var receipt: Data
+    /**
+     * Kind of receipt that was produced.
+     */
+    public var receiptKind: Risc0ReceiptKind
+    /**
+     * Size of the seal in bytes, the bulk of the receipt.
+     */
+    public var sealSize: UInt64
 
-extension MoproError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(receipt: Data, 
+        /**
+         * Kind of receipt that was produced.
+         */receiptKind: Risc0ReceiptKind, 
+        /**
+         * Size of the seal in bytes, the bulk of the receipt.
+         */sealSize: UInt64) {
+        self.receipt = receipt
+        self.receiptKind = receiptKind
+        self.sealSize = sealSize
     }
 }
 
+#if compiler(>=6)
+extension Risc0ProofOutput: Sendable {}
+#endif
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
-public enum ProofLib {
-    
-    case arkworks
-    case rapidsnark
+extension Risc0ProofOutput: Equatable, Hashable {
+    public static func ==(lhs: Risc0ProofOutput, rhs: Risc0ProofOutput) -> Bool {
+        if lhs.receipt != rhs.receipt {
+            return false
+        }
+        if lhs.receiptKind != rhs.receiptKind {
+            return false
+        }
+        if lhs.sealSize != rhs.sealSize {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(receipt)
+        hasher.combine(receiptKind)
+        hasher.combine(sealSize)
+    }
 }
 
 
-#if compiler(>=6)
-extension ProofLib: Sendable {}
-#endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeProofLib: FfiConverterRustBuffer {
-    typealias SwiftType = ProofLib
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProofLib {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .arkworks
-        
-        case 2: return .rapidsnark
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
-        }
+public struct FfiConverterTypeRisc0ProofOutput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0ProofOutput {
+        return
+            try Risc0ProofOutput(
+                receipt: FfiConverterData.read(from: &buf), 
+                receiptKind: FfiConverterTypeRisc0ReceiptKind.read(from: &buf), 
+                sealSize: FfiConverterUInt64.read(from: &buf)
+        )
     }
 
-    public static func write(_ value: ProofLib, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .arkworks:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .rapidsnark:
-            writeInt(&buf, Int32(2))
-        
-        }
+    public static func write(_ value: Risc0ProofOutput, into buf: inout [UInt8]) {
+        FfiConverterData.write(value.receipt, into: &buf)
+        FfiConverterTypeRisc0ReceiptKind.write(value.receiptKind, into: &buf)
+        FfiConverterUInt64.write(value.sealSize, into: &buf)
     }
 }
 
@@ -1146,249 +1243,3879 @@ public struct FfiConverterTypeProofLib: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeProofLib_lift(_ buf: RustBuffer) throws -> ProofLib {
-    return try FfiConverterTypeProofLib.lift(buf)
+public func FfiConverterTypeRisc0ProofOutput_lift(_ buf: RustBuffer) throws -> Risc0ProofOutput {
+    return try FfiConverterTypeRisc0ProofOutput.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeProofLib_lower(_ value: ProofLib) -> RustBuffer {
-    return FfiConverterTypeProofLib.lower(value)
+public func FfiConverterTypeRisc0ProofOutput_lower(_ value: Risc0ProofOutput) -> RustBuffer {
+    return FfiConverterTypeRisc0ProofOutput.lower(value)
 }
 
 
-extension ProofLib: Equatable, Hashable {}
+/**
+ * Public key committed to the journal of a verified receipt.
+ */
+public struct Risc0PublicKey {
+    public var curve: Risc0Curve
+    /**
+     * SEC1 compressed encoding (33 bytes, or 49 for P-384). Ed25519 keys
+     * have a single 32-byte encoding, reported in both fields.
+     */
+    public var compressed: Data
+    /**
+     * SEC1 uncompressed encoding (65 bytes, or 97 for P-384).
+     */
+    public var uncompressed: Data
+    /**
+     * Lowercase hex SHA-256 of the compressed encoding.
+     */
+    public var fingerprint: String
 
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(curve: Risc0Curve, 
+        /**
+         * SEC1 compressed encoding (33 bytes, or 49 for P-384). Ed25519 keys
+         * have a single 32-byte encoding, reported in both fields.
+         */compressed: Data, 
+        /**
+         * SEC1 uncompressed encoding (65 bytes, or 97 for P-384).
+         */uncompressed: Data, 
+        /**
+         * Lowercase hex SHA-256 of the compressed encoding.
+         */fingerprint: String) {
+        self.curve = curve
+        self.compressed = compressed
+        self.uncompressed = uncompressed
+        self.fingerprint = fingerprint
+    }
+}
 
+#if compiler(>=6)
+extension Risc0PublicKey: Sendable {}
+#endif
 
 
-public enum Risc0Error {
+extension Risc0PublicKey: Equatable, Hashable {
+    public static func ==(lhs: Risc0PublicKey, rhs: Risc0PublicKey) -> Bool {
+        if lhs.curve != rhs.curve {
+            return false
+        }
+        if lhs.compressed != rhs.compressed {
+            return false
+        }
+        if lhs.uncompressed != rhs.uncompressed {
+            return false
+        }
+        if lhs.fingerprint != rhs.fingerprint {
+            return false
+        }
+        return true
+    }
 
-    
-    
-    case ProveError(String
-    )
-    case SerializeError(String
-    )
-    case VerifyError(String
-    )
-    case DecodeError(String
-    )
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(curve)
+        hasher.combine(compressed)
+        hasher.combine(uncompressed)
+        hasher.combine(fingerprint)
+    }
 }
 
 
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRisc0Error: FfiConverterRustBuffer {
-    typealias SwiftType = Risc0Error
+public struct FfiConverterTypeRisc0PublicKey: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0PublicKey {
+        return
+            try Risc0PublicKey(
+                curve: FfiConverterTypeRisc0Curve.read(from: &buf), 
+                compressed: FfiConverterData.read(from: &buf), 
+                uncompressed: FfiConverterData.read(from: &buf), 
+                fingerprint: FfiConverterString.read(from: &buf)
+        )
+    }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0Error {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
+    public static func write(_ value: Risc0PublicKey, into buf: inout [UInt8]) {
+        FfiConverterTypeRisc0Curve.write(value.curve, into: &buf)
+        FfiConverterData.write(value.compressed, into: &buf)
+        FfiConverterData.write(value.uncompressed, into: &buf)
+        FfiConverterString.write(value.fingerprint, into: &buf)
+    }
+}
 
-        
 
-        
-        case 1: return .ProveError(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 2: return .SerializeError(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 3: return .VerifyError(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 4: return .DecodeError(
-            try FfiConverterString.read(from: &buf)
-            )
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0PublicKey_lift(_ buf: RustBuffer) throws -> Risc0PublicKey {
+    return try FfiConverterTypeRisc0PublicKey.lift(buf)
+}
 
-         default: throw UniffiInternalError.unexpectedEnumCase
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0PublicKey_lower(_ value: Risc0PublicKey) -> RustBuffer {
+    return FfiConverterTypeRisc0PublicKey.lower(value)
+}
+
+
+/**
+ * A signature tagged with the index of its key in the key set.
+ */
+public struct Risc0ThresholdSignature {
+    /**
+     * Index of the signing key in `public_keys`.
+     */
+    public var keyIndex: UInt32
+    /**
+     * Signature as `r || s` or ASN.1 DER.
+     */
+    public var signature: Data
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Index of the signing key in `public_keys`.
+         */keyIndex: UInt32, 
+        /**
+         * Signature as `r || s` or ASN.1 DER.
+         */signature: Data) {
+        self.keyIndex = keyIndex
+        self.signature = signature
+    }
+}
+
+#if compiler(>=6)
+extension Risc0ThresholdSignature: Sendable {}
+#endif
+
+
+extension Risc0ThresholdSignature: Equatable, Hashable {
+    public static func ==(lhs: Risc0ThresholdSignature, rhs: Risc0ThresholdSignature) -> Bool {
+        if lhs.keyIndex != rhs.keyIndex {
+            return false
         }
+        if lhs.signature != rhs.signature {
+            return false
+        }
+        return true
     }
 
-    public static func write(_ value: Risc0Error, into buf: inout [UInt8]) {
-        switch value {
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(keyIndex)
+        hasher.combine(signature)
+    }
+}
 
-        
 
-        
-        
-        case let .ProveError(v1):
-            writeInt(&buf, Int32(1))
-            FfiConverterString.write(v1, into: &buf)
-            
-        
-        case let .SerializeError(v1):
-            writeInt(&buf, Int32(2))
-            FfiConverterString.write(v1, into: &buf)
-            
-        
-        case let .VerifyError(v1):
-            writeInt(&buf, Int32(3))
-            FfiConverterString.write(v1, into: &buf)
-            
-        
-        case let .DecodeError(v1):
-            writeInt(&buf, Int32(4))
-            FfiConverterString.write(v1, into: &buf)
-            
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0ThresholdSignature: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0ThresholdSignature {
+        return
+            try Risc0ThresholdSignature(
+                keyIndex: FfiConverterUInt32.read(from: &buf), 
+                signature: FfiConverterData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0ThresholdSignature, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.keyIndex, into: &buf)
+        FfiConverterData.write(value.signature, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0ThresholdSignature_lift(_ buf: RustBuffer) throws -> Risc0ThresholdSignature {
+    return try FfiConverterTypeRisc0ThresholdSignature.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0ThresholdSignature_lower(_ value: Risc0ThresholdSignature) -> RustBuffer {
+    return FfiConverterTypeRisc0ThresholdSignature.lower(value)
+}
+
+
+/**
+ * A verified batch entry, present when the batch was proven with its entries.
+ */
+public struct Risc0VerifiedBatchEntry {
+    public var signer: Risc0PublicKey
+    public var message: Data
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(signer: Risc0PublicKey, message: Data) {
+        self.signer = signer
+        self.message = message
+    }
+}
+
+#if compiler(>=6)
+extension Risc0VerifiedBatchEntry: Sendable {}
+#endif
+
+
+extension Risc0VerifiedBatchEntry: Equatable, Hashable {
+    public static func ==(lhs: Risc0VerifiedBatchEntry, rhs: Risc0VerifiedBatchEntry) -> Bool {
+        if lhs.signer != rhs.signer {
+            return false
         }
+        if lhs.message != rhs.message {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(signer)
+        hasher.combine(message)
     }
 }
 
 
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRisc0Error_lift(_ buf: RustBuffer) throws -> Risc0Error {
-    return try FfiConverterTypeRisc0Error.lift(buf)
+public struct FfiConverterTypeRisc0VerifiedBatchEntry: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0VerifiedBatchEntry {
+        return
+            try Risc0VerifiedBatchEntry(
+                signer: FfiConverterTypeRisc0PublicKey.read(from: &buf), 
+                message: FfiConverterData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0VerifiedBatchEntry, into buf: inout [UInt8]) {
+        FfiConverterTypeRisc0PublicKey.write(value.signer, into: &buf)
+        FfiConverterData.write(value.message, into: &buf)
+    }
 }
 
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRisc0Error_lower(_ value: Risc0Error) -> RustBuffer {
-    return FfiConverterTypeRisc0Error.lower(value)
+public func FfiConverterTypeRisc0VerifiedBatchEntry_lift(_ buf: RustBuffer) throws -> Risc0VerifiedBatchEntry {
+    return try FfiConverterTypeRisc0VerifiedBatchEntry.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifiedBatchEntry_lower(_ value: Risc0VerifiedBatchEntry) -> RustBuffer {
+    return FfiConverterTypeRisc0VerifiedBatchEntry.lower(value)
 }
 
 
-extension Risc0Error: Equatable, Hashable {}
+/**
+ * Verify output for aggregated receipts.
+ */
+public struct Risc0VerifyAggregateOutput {
+    public var isValid: Bool
+    /**
+     * Number of inner receipts folded into the aggregate.
+     */
+    public var count: UInt32
+    /**
+     * Merkle root over the inner journals, as returned by [`risc0_aggregate_root`].
+     */
+    public var root: Data
+    /**
+     * The inner statements in input order, or empty if they were not committed.
+     */
+    public var entries: [Risc0VerifyBytesOutput]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(isValid: Bool, 
+        /**
+         * Number of inner receipts folded into the aggregate.
+         */count: UInt32, 
+        /**
+         * Merkle root over the inner journals, as returned by [`risc0_aggregate_root`].
+         */root: Data, 
+        /**
+         * The inner statements in input order, or empty if they were not committed.
+         */entries: [Risc0VerifyBytesOutput]) {
+        self.isValid = isValid
+        self.count = count
+        self.root = root
+        self.entries = entries
+    }
+}
 
+#if compiler(>=6)
+extension Risc0VerifyAggregateOutput: Sendable {}
+#endif
 
 
-extension Risc0Error: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
+extension Risc0VerifyAggregateOutput: Equatable, Hashable {
+    public static func ==(lhs: Risc0VerifyAggregateOutput, rhs: Risc0VerifyAggregateOutput) -> Bool {
+        if lhs.isValid != rhs.isValid {
+            return false
+        }
+        if lhs.count != rhs.count {
+            return false
+        }
+        if lhs.root != rhs.root {
+            return false
+        }
+        if lhs.entries != rhs.entries {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(isValid)
+        hasher.combine(count)
+        hasher.combine(root)
+        hasher.combine(entries)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0VerifyAggregateOutput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0VerifyAggregateOutput {
+        return
+            try Risc0VerifyAggregateOutput(
+                isValid: FfiConverterBool.read(from: &buf), 
+                count: FfiConverterUInt32.read(from: &buf), 
+                root: FfiConverterData.read(from: &buf), 
+                entries: FfiConverterSequenceTypeRisc0VerifyBytesOutput.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0VerifyAggregateOutput, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isValid, into: &buf)
+        FfiConverterUInt32.write(value.count, into: &buf)
+        FfiConverterData.write(value.root, into: &buf)
+        FfiConverterSequenceTypeRisc0VerifyBytesOutput.write(value.entries, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyAggregateOutput_lift(_ buf: RustBuffer) throws -> Risc0VerifyAggregateOutput {
+    return try FfiConverterTypeRisc0VerifyAggregateOutput.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyAggregateOutput_lower(_ value: Risc0VerifyAggregateOutput) -> RustBuffer {
+    return FfiConverterTypeRisc0VerifyAggregateOutput.lower(value)
+}
+
+
+/**
+ * Verify output for batch proofs.
+ */
+public struct Risc0VerifyBatchOutput {
+    public var isValid: Bool
+    /**
+     * Number of signatures verified in the batch.
+     */
+    public var count: UInt32
+    /**
+     * Merkle root over the `(key, SHA-256 of message)` leaves, in batch order.
+     */
+    public var root: Data
+    /**
+     * The entries in batch order, or empty if they were not committed.
+     */
+    public var entries: [Risc0VerifiedBatchEntry]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(isValid: Bool, 
+        /**
+         * Number of signatures verified in the batch.
+         */count: UInt32, 
+        /**
+         * Merkle root over the `(key, SHA-256 of message)` leaves, in batch order.
+         */root: Data, 
+        /**
+         * The entries in batch order, or empty if they were not committed.
+         */entries: [Risc0VerifiedBatchEntry]) {
+        self.isValid = isValid
+        self.count = count
+        self.root = root
+        self.entries = entries
+    }
+}
+
+#if compiler(>=6)
+extension Risc0VerifyBatchOutput: Sendable {}
+#endif
+
+
+extension Risc0VerifyBatchOutput: Equatable, Hashable {
+    public static func ==(lhs: Risc0VerifyBatchOutput, rhs: Risc0VerifyBatchOutput) -> Bool {
+        if lhs.isValid != rhs.isValid {
+            return false
+        }
+        if lhs.count != rhs.count {
+            return false
+        }
+        if lhs.root != rhs.root {
+            return false
+        }
+        if lhs.entries != rhs.entries {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(isValid)
+        hasher.combine(count)
+        hasher.combine(root)
+        hasher.combine(entries)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0VerifyBatchOutput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0VerifyBatchOutput {
+        return
+            try Risc0VerifyBatchOutput(
+                isValid: FfiConverterBool.read(from: &buf), 
+                count: FfiConverterUInt32.read(from: &buf), 
+                root: FfiConverterData.read(from: &buf), 
+                entries: FfiConverterSequenceTypeRisc0VerifiedBatchEntry.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0VerifyBatchOutput, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isValid, into: &buf)
+        FfiConverterUInt32.write(value.count, into: &buf)
+        FfiConverterData.write(value.root, into: &buf)
+        FfiConverterSequenceTypeRisc0VerifiedBatchEntry.write(value.entries, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyBatchOutput_lift(_ buf: RustBuffer) throws -> Risc0VerifyBatchOutput {
+    return try FfiConverterTypeRisc0VerifyBatchOutput.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyBatchOutput_lower(_ value: Risc0VerifyBatchOutput) -> RustBuffer {
+    return FfiConverterTypeRisc0VerifyBatchOutput.lower(value)
+}
+
+
+/**
+ * Verify output for messages that are not necessarily UTF-8.
+ */
+public struct Risc0VerifyBytesOutput {
+    public var isValid: Bool
+    public var message: Data
+    /**
+     * The message as text, present only when it is valid UTF-8.
+     */
+    public var messageText: String?
+    public var signer: Risc0PublicKey
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(isValid: Bool, message: Data, 
+        /**
+         * The message as text, present only when it is valid UTF-8.
+         */messageText: String?, signer: Risc0PublicKey) {
+        self.isValid = isValid
+        self.message = message
+        self.messageText = messageText
+        self.signer = signer
     }
 }
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
-    typealias SwiftType = String?
-
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
-        }
-        writeInt(&buf, Int8(1))
-        FfiConverterString.write(value, into: &buf)
-    }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterString.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
-        }
-    }
+
+#if compiler(>=6)
+extension Risc0VerifyBytesOutput: Sendable {}
+#endif
+
+
+extension Risc0VerifyBytesOutput: Equatable, Hashable {
+    public static func ==(lhs: Risc0VerifyBytesOutput, rhs: Risc0VerifyBytesOutput) -> Bool {
+        if lhs.isValid != rhs.isValid {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        if lhs.messageText != rhs.messageText {
+            return false
+        }
+        if lhs.signer != rhs.signer {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(isValid)
+        hasher.combine(message)
+        hasher.combine(messageText)
+        hasher.combine(signer)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0VerifyBytesOutput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0VerifyBytesOutput {
+        return
+            try Risc0VerifyBytesOutput(
+                isValid: FfiConverterBool.read(from: &buf), 
+                message: FfiConverterData.read(from: &buf), 
+                messageText: FfiConverterOptionString.read(from: &buf), 
+                signer: FfiConverterTypeRisc0PublicKey.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0VerifyBytesOutput, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isValid, into: &buf)
+        FfiConverterData.write(value.message, into: &buf)
+        FfiConverterOptionString.write(value.messageText, into: &buf)
+        FfiConverterTypeRisc0PublicKey.write(value.signer, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyBytesOutput_lift(_ buf: RustBuffer) throws -> Risc0VerifyBytesOutput {
+    return try FfiConverterTypeRisc0VerifyBytesOutput.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyBytesOutput_lower(_ value: Risc0VerifyBytesOutput) -> RustBuffer {
+    return FfiConverterTypeRisc0VerifyBytesOutput.lower(value)
+}
+
+
+/**
+ * Verify output for signature chain proofs.
+ */
+public struct Risc0VerifyChainOutput {
+    public var isValid: Bool
+    /**
+     * Key that signed every message of the chain.
+     */
+    public var signer: Risc0PublicKey
+    /**
+     * Number of messages in the chain.
+     */
+    public var counter: UInt64
+    /**
+     * SHA-256 of the latest message, which the next message must start with.
+     */
+    public var head: Data
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(isValid: Bool, 
+        /**
+         * Key that signed every message of the chain.
+         */signer: Risc0PublicKey, 
+        /**
+         * Number of messages in the chain.
+         */counter: UInt64, 
+        /**
+         * SHA-256 of the latest message, which the next message must start with.
+         */head: Data) {
+        self.isValid = isValid
+        self.signer = signer
+        self.counter = counter
+        self.head = head
+    }
+}
+
+#if compiler(>=6)
+extension Risc0VerifyChainOutput: Sendable {}
+#endif
+
+
+extension Risc0VerifyChainOutput: Equatable, Hashable {
+    public static func ==(lhs: Risc0VerifyChainOutput, rhs: Risc0VerifyChainOutput) -> Bool {
+        if lhs.isValid != rhs.isValid {
+            return false
+        }
+        if lhs.signer != rhs.signer {
+            return false
+        }
+        if lhs.counter != rhs.counter {
+            return false
+        }
+        if lhs.head != rhs.head {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(isValid)
+        hasher.combine(signer)
+        hasher.combine(counter)
+        hasher.combine(head)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0VerifyChainOutput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0VerifyChainOutput {
+        return
+            try Risc0VerifyChainOutput(
+                isValid: FfiConverterBool.read(from: &buf), 
+                signer: FfiConverterTypeRisc0PublicKey.read(from: &buf), 
+                counter: FfiConverterUInt64.read(from: &buf), 
+                head: FfiConverterData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0VerifyChainOutput, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isValid, into: &buf)
+        FfiConverterTypeRisc0PublicKey.write(value.signer, into: &buf)
+        FfiConverterUInt64.write(value.counter, into: &buf)
+        FfiConverterData.write(value.head, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyChainOutput_lift(_ buf: RustBuffer) throws -> Risc0VerifyChainOutput {
+    return try FfiConverterTypeRisc0VerifyChainOutput.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyChainOutput_lower(_ value: Risc0VerifyChainOutput) -> RustBuffer {
+    return FfiConverterTypeRisc0VerifyChainOutput.lower(value)
+}
+
+
+/**
+ * Verify output for receipts that commit only a hash of the message.
+ */
+public struct Risc0VerifyCommitmentOutput {
+    public var isValid: Bool
+    /**
+     * SHA-256 of the blinding value (zeros if none) followed by the message.
+     */
+    public var messageCommitment: Data
+    public var signer: Risc0PublicKey
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(isValid: Bool, 
+        /**
+         * SHA-256 of the blinding value (zeros if none) followed by the message.
+         */messageCommitment: Data, signer: Risc0PublicKey) {
+        self.isValid = isValid
+        self.messageCommitment = messageCommitment
+        self.signer = signer
+    }
+}
+
+#if compiler(>=6)
+extension Risc0VerifyCommitmentOutput: Sendable {}
+#endif
+
+
+extension Risc0VerifyCommitmentOutput: Equatable, Hashable {
+    public static func ==(lhs: Risc0VerifyCommitmentOutput, rhs: Risc0VerifyCommitmentOutput) -> Bool {
+        if lhs.isValid != rhs.isValid {
+            return false
+        }
+        if lhs.messageCommitment != rhs.messageCommitment {
+            return false
+        }
+        if lhs.signer != rhs.signer {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(isValid)
+        hasher.combine(messageCommitment)
+        hasher.combine(signer)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0VerifyCommitmentOutput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0VerifyCommitmentOutput {
+        return
+            try Risc0VerifyCommitmentOutput(
+                isValid: FfiConverterBool.read(from: &buf), 
+                messageCommitment: FfiConverterData.read(from: &buf), 
+                signer: FfiConverterTypeRisc0PublicKey.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0VerifyCommitmentOutput, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isValid, into: &buf)
+        FfiConverterData.write(value.messageCommitment, into: &buf)
+        FfiConverterTypeRisc0PublicKey.write(value.signer, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyCommitmentOutput_lift(_ buf: RustBuffer) throws -> Risc0VerifyCommitmentOutput {
+    return try FfiConverterTypeRisc0VerifyCommitmentOutput.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyCommitmentOutput_lower(_ value: Risc0VerifyCommitmentOutput) -> RustBuffer {
+    return FfiConverterTypeRisc0VerifyCommitmentOutput.lower(value)
+}
+
+
+/**
+ * Verify output for receipts produced in [`crate::Risc0MessageMode::Prehashed`].
+ */
+public struct Risc0VerifyDigestOutput {
+    public var isValid: Bool
+    /**
+     * SHA-256 digest of the signed message.
+     */
+    public var messageDigest: Data
+    public var signer: Risc0PublicKey
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(isValid: Bool, 
+        /**
+         * SHA-256 digest of the signed message.
+         */messageDigest: Data, signer: Risc0PublicKey) {
+        self.isValid = isValid
+        self.messageDigest = messageDigest
+        self.signer = signer
+    }
+}
+
+#if compiler(>=6)
+extension Risc0VerifyDigestOutput: Sendable {}
+#endif
+
+
+extension Risc0VerifyDigestOutput: Equatable, Hashable {
+    public static func ==(lhs: Risc0VerifyDigestOutput, rhs: Risc0VerifyDigestOutput) -> Bool {
+        if lhs.isValid != rhs.isValid {
+            return false
+        }
+        if lhs.messageDigest != rhs.messageDigest {
+            return false
+        }
+        if lhs.signer != rhs.signer {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(isValid)
+        hasher.combine(messageDigest)
+        hasher.combine(signer)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0VerifyDigestOutput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0VerifyDigestOutput {
+        return
+            try Risc0VerifyDigestOutput(
+                isValid: FfiConverterBool.read(from: &buf), 
+                messageDigest: FfiConverterData.read(from: &buf), 
+                signer: FfiConverterTypeRisc0PublicKey.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0VerifyDigestOutput, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isValid, into: &buf)
+        FfiConverterData.write(value.messageDigest, into: &buf)
+        FfiConverterTypeRisc0PublicKey.write(value.signer, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyDigestOutput_lift(_ buf: RustBuffer) throws -> Risc0VerifyDigestOutput {
+    return try FfiConverterTypeRisc0VerifyDigestOutput.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyDigestOutput_lower(_ value: Risc0VerifyDigestOutput) -> RustBuffer {
+    return FfiConverterTypeRisc0VerifyDigestOutput.lower(value)
+}
+
+
+/**
+ * Verify output for Ethereum signature proofs.
+ */
+public struct Risc0VerifyEthOutput {
+    public var isValid: Bool
+    /**
+     * 20-byte signer address.
+     */
+    public var address: Data
+    /**
+     * Signer address as EIP-55 checksummed hex with `0x` prefix.
+     */
+    public var addressHex: String
+    public var message: Data
+    /**
+     * The message as text, present only when it is valid UTF-8.
+     */
+    public var messageText: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(isValid: Bool, 
+        /**
+         * 20-byte signer address.
+         */address: Data, 
+        /**
+         * Signer address as EIP-55 checksummed hex with `0x` prefix.
+         */addressHex: String, message: Data, 
+        /**
+         * The message as text, present only when it is valid UTF-8.
+         */messageText: String?) {
+        self.isValid = isValid
+        self.address = address
+        self.addressHex = addressHex
+        self.message = message
+        self.messageText = messageText
+    }
+}
+
+#if compiler(>=6)
+extension Risc0VerifyEthOutput: Sendable {}
+#endif
+
+
+extension Risc0VerifyEthOutput: Equatable, Hashable {
+    public static func ==(lhs: Risc0VerifyEthOutput, rhs: Risc0VerifyEthOutput) -> Bool {
+        if lhs.isValid != rhs.isValid {
+            return false
+        }
+        if lhs.address != rhs.address {
+            return false
+        }
+        if lhs.addressHex != rhs.addressHex {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        if lhs.messageText != rhs.messageText {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(isValid)
+        hasher.combine(address)
+        hasher.combine(addressHex)
+        hasher.combine(message)
+        hasher.combine(messageText)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0VerifyEthOutput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0VerifyEthOutput {
+        return
+            try Risc0VerifyEthOutput(
+                isValid: FfiConverterBool.read(from: &buf), 
+                address: FfiConverterData.read(from: &buf), 
+                addressHex: FfiConverterString.read(from: &buf), 
+                message: FfiConverterData.read(from: &buf), 
+                messageText: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0VerifyEthOutput, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isValid, into: &buf)
+        FfiConverterData.write(value.address, into: &buf)
+        FfiConverterString.write(value.addressHex, into: &buf)
+        FfiConverterData.write(value.message, into: &buf)
+        FfiConverterOptionString.write(value.messageText, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyEthOutput_lift(_ buf: RustBuffer) throws -> Risc0VerifyEthOutput {
+    return try FfiConverterTypeRisc0VerifyEthOutput.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyEthOutput_lower(_ value: Risc0VerifyEthOutput) -> RustBuffer {
+    return FfiConverterTypeRisc0VerifyEthOutput.lower(value)
+}
+
+
+/**
+ * Verify output for receipts that commit to the signer key instead of revealing it.
+ */
+public struct Risc0VerifyKeyCommitmentOutput {
+    public var isValid: Bool
+    /**
+     * SHA-256 of the salt followed by the compressed SEC1 signer key.
+     */
+    public var keyCommitment: Data
+    public var message: Data
+    /**
+     * The message as text, present only when it is valid UTF-8.
+     */
+    public var messageText: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(isValid: Bool, 
+        /**
+         * SHA-256 of the salt followed by the compressed SEC1 signer key.
+         */keyCommitment: Data, message: Data, 
+        /**
+         * The message as text, present only when it is valid UTF-8.
+         */messageText: String?) {
+        self.isValid = isValid
+        self.keyCommitment = keyCommitment
+        self.message = message
+        self.messageText = messageText
+    }
+}
+
+#if compiler(>=6)
+extension Risc0VerifyKeyCommitmentOutput: Sendable {}
+#endif
+
+
+extension Risc0VerifyKeyCommitmentOutput: Equatable, Hashable {
+    public static func ==(lhs: Risc0VerifyKeyCommitmentOutput, rhs: Risc0VerifyKeyCommitmentOutput) -> Bool {
+        if lhs.isValid != rhs.isValid {
+            return false
+        }
+        if lhs.keyCommitment != rhs.keyCommitment {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        if lhs.messageText != rhs.messageText {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(isValid)
+        hasher.combine(keyCommitment)
+        hasher.combine(message)
+        hasher.combine(messageText)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0VerifyKeyCommitmentOutput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0VerifyKeyCommitmentOutput {
+        return
+            try Risc0VerifyKeyCommitmentOutput(
+                isValid: FfiConverterBool.read(from: &buf), 
+                keyCommitment: FfiConverterData.read(from: &buf), 
+                message: FfiConverterData.read(from: &buf), 
+                messageText: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0VerifyKeyCommitmentOutput, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isValid, into: &buf)
+        FfiConverterData.write(value.keyCommitment, into: &buf)
+        FfiConverterData.write(value.message, into: &buf)
+        FfiConverterOptionString.write(value.messageText, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyKeyCommitmentOutput_lift(_ buf: RustBuffer) throws -> Risc0VerifyKeyCommitmentOutput {
+    return try FfiConverterTypeRisc0VerifyKeyCommitmentOutput.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyKeyCommitmentOutput_lower(_ value: Risc0VerifyKeyCommitmentOutput) -> RustBuffer {
+    return FfiConverterTypeRisc0VerifyKeyCommitmentOutput.lower(value)
+}
+
+
+/**
+ * Verify output for anonymous membership proofs.
+ */
+public struct Risc0VerifyMembershipOutput {
+    public var isValid: Bool
+    /**
+     * Merkle root of the key set the signer belongs to.
+     */
+    public var keySetRoot: Data
+    public var message: Data
+    /**
+     * The message as text, present only when it is valid UTF-8.
+     */
+    public var messageText: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(isValid: Bool, 
+        /**
+         * Merkle root of the key set the signer belongs to.
+         */keySetRoot: Data, message: Data, 
+        /**
+         * The message as text, present only when it is valid UTF-8.
+         */messageText: String?) {
+        self.isValid = isValid
+        self.keySetRoot = keySetRoot
+        self.message = message
+        self.messageText = messageText
+    }
+}
+
+#if compiler(>=6)
+extension Risc0VerifyMembershipOutput: Sendable {}
+#endif
+
+
+extension Risc0VerifyMembershipOutput: Equatable, Hashable {
+    public static func ==(lhs: Risc0VerifyMembershipOutput, rhs: Risc0VerifyMembershipOutput) -> Bool {
+        if lhs.isValid != rhs.isValid {
+            return false
+        }
+        if lhs.keySetRoot != rhs.keySetRoot {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        if lhs.messageText != rhs.messageText {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(isValid)
+        hasher.combine(keySetRoot)
+        hasher.combine(message)
+        hasher.combine(messageText)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0VerifyMembershipOutput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0VerifyMembershipOutput {
+        return
+            try Risc0VerifyMembershipOutput(
+                isValid: FfiConverterBool.read(from: &buf), 
+                keySetRoot: FfiConverterData.read(from: &buf), 
+                message: FfiConverterData.read(from: &buf), 
+                messageText: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0VerifyMembershipOutput, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isValid, into: &buf)
+        FfiConverterData.write(value.keySetRoot, into: &buf)
+        FfiConverterData.write(value.message, into: &buf)
+        FfiConverterOptionString.write(value.messageText, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyMembershipOutput_lift(_ buf: RustBuffer) throws -> Risc0VerifyMembershipOutput {
+    return try FfiConverterTypeRisc0VerifyMembershipOutput.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyMembershipOutput_lower(_ value: Risc0VerifyMembershipOutput) -> RustBuffer {
+    return FfiConverterTypeRisc0VerifyMembershipOutput.lower(value)
+}
+
+
+public struct Risc0VerifyOutput {
+    public var isValid: Bool
+    public var verifiedMessage: String
+    public var signer: Risc0PublicKey
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(isValid: Bool, verifiedMessage: String, signer: Risc0PublicKey) {
+        self.isValid = isValid
+        self.verifiedMessage = verifiedMessage
+        self.signer = signer
+    }
+}
+
+#if compiler(>=6)
+extension Risc0VerifyOutput: Sendable {}
+#endif
+
+
+extension Risc0VerifyOutput: Equatable, Hashable {
+    public static func ==(lhs: Risc0VerifyOutput, rhs: Risc0VerifyOutput) -> Bool {
+        if lhs.isValid != rhs.isValid {
+            return false
+        }
+        if lhs.verifiedMessage != rhs.verifiedMessage {
+            return false
+        }
+        if lhs.signer != rhs.signer {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(isValid)
+        hasher.combine(verifiedMessage)
+        hasher.combine(signer)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0VerifyOutput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0VerifyOutput {
+        return
+            try Risc0VerifyOutput(
+                isValid: FfiConverterBool.read(from: &buf), 
+                verifiedMessage: FfiConverterString.read(from: &buf), 
+                signer: FfiConverterTypeRisc0PublicKey.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0VerifyOutput, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isValid, into: &buf)
+        FfiConverterString.write(value.verifiedMessage, into: &buf)
+        FfiConverterTypeRisc0PublicKey.write(value.signer, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyOutput_lift(_ buf: RustBuffer) throws -> Risc0VerifyOutput {
+    return try FfiConverterTypeRisc0VerifyOutput.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyOutput_lower(_ value: Risc0VerifyOutput) -> RustBuffer {
+    return FfiConverterTypeRisc0VerifyOutput.lower(value)
+}
+
+
+/**
+ * Verify output for RSA signature proofs.
+ */
+public struct Risc0VerifyRsaOutput {
+    public var isValid: Bool
+    /**
+     * SHA-256 of the big-endian modulus, as returned by [`rsa_modulus_hash`].
+     */
+    public var modulusHash: Data
+    /**
+     * Big-endian public exponent.
+     */
+    public var exponent: Data
+    public var scheme: Risc0RsaScheme
+    public var message: Data
+    /**
+     * The message as text, present only when it is valid UTF-8.
+     */
+    public var messageText: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(isValid: Bool, 
+        /**
+         * SHA-256 of the big-endian modulus, as returned by [`rsa_modulus_hash`].
+         */modulusHash: Data, 
+        /**
+         * Big-endian public exponent.
+         */exponent: Data, scheme: Risc0RsaScheme, message: Data, 
+        /**
+         * The message as text, present only when it is valid UTF-8.
+         */messageText: String?) {
+        self.isValid = isValid
+        self.modulusHash = modulusHash
+        self.exponent = exponent
+        self.scheme = scheme
+        self.message = message
+        self.messageText = messageText
+    }
+}
+
+#if compiler(>=6)
+extension Risc0VerifyRsaOutput: Sendable {}
+#endif
+
+
+extension Risc0VerifyRsaOutput: Equatable, Hashable {
+    public static func ==(lhs: Risc0VerifyRsaOutput, rhs: Risc0VerifyRsaOutput) -> Bool {
+        if lhs.isValid != rhs.isValid {
+            return false
+        }
+        if lhs.modulusHash != rhs.modulusHash {
+            return false
+        }
+        if lhs.exponent != rhs.exponent {
+            return false
+        }
+        if lhs.scheme != rhs.scheme {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        if lhs.messageText != rhs.messageText {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(isValid)
+        hasher.combine(modulusHash)
+        hasher.combine(exponent)
+        hasher.combine(scheme)
+        hasher.combine(message)
+        hasher.combine(messageText)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0VerifyRsaOutput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0VerifyRsaOutput {
+        return
+            try Risc0VerifyRsaOutput(
+                isValid: FfiConverterBool.read(from: &buf), 
+                modulusHash: FfiConverterData.read(from: &buf), 
+                exponent: FfiConverterData.read(from: &buf), 
+                scheme: FfiConverterTypeRisc0RsaScheme.read(from: &buf), 
+                message: FfiConverterData.read(from: &buf), 
+                messageText: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0VerifyRsaOutput, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isValid, into: &buf)
+        FfiConverterData.write(value.modulusHash, into: &buf)
+        FfiConverterData.write(value.exponent, into: &buf)
+        FfiConverterTypeRisc0RsaScheme.write(value.scheme, into: &buf)
+        FfiConverterData.write(value.message, into: &buf)
+        FfiConverterOptionString.write(value.messageText, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyRsaOutput_lift(_ buf: RustBuffer) throws -> Risc0VerifyRsaOutput {
+    return try FfiConverterTypeRisc0VerifyRsaOutput.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyRsaOutput_lower(_ value: Risc0VerifyRsaOutput) -> RustBuffer {
+    return FfiConverterTypeRisc0VerifyRsaOutput.lower(value)
+}
+
+
+/**
+ * Verify output for k-of-n threshold proofs.
+ */
+public struct Risc0VerifyThresholdOutput {
+    public var isValid: Bool
+    /**
+     * Merkle root of the key set, as returned by
+     * [`crate::membership::risc0_key_set_root`].
+     */
+    public var keySetRoot: Data
+    /**
+     * Minimum number of distinct keys that signed the message.
+     */
+    public var threshold: UInt32
+    public var message: Data
+    /**
+     * The message as text, present only when it is valid UTF-8.
+     */
+    public var messageText: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(isValid: Bool, 
+        /**
+         * Merkle root of the key set, as returned by
+         * [`crate::membership::risc0_key_set_root`].
+         */keySetRoot: Data, 
+        /**
+         * Minimum number of distinct keys that signed the message.
+         */threshold: UInt32, message: Data, 
+        /**
+         * The message as text, present only when it is valid UTF-8.
+         */messageText: String?) {
+        self.isValid = isValid
+        self.keySetRoot = keySetRoot
+        self.threshold = threshold
+        self.message = message
+        self.messageText = messageText
+    }
+}
+
+#if compiler(>=6)
+extension Risc0VerifyThresholdOutput: Sendable {}
+#endif
+
+
+extension Risc0VerifyThresholdOutput: Equatable, Hashable {
+    public static func ==(lhs: Risc0VerifyThresholdOutput, rhs: Risc0VerifyThresholdOutput) -> Bool {
+        if lhs.isValid != rhs.isValid {
+            return false
+        }
+        if lhs.keySetRoot != rhs.keySetRoot {
+            return false
+        }
+        if lhs.threshold != rhs.threshold {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        if lhs.messageText != rhs.messageText {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(isValid)
+        hasher.combine(keySetRoot)
+        hasher.combine(threshold)
+        hasher.combine(message)
+        hasher.combine(messageText)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0VerifyThresholdOutput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0VerifyThresholdOutput {
+        return
+            try Risc0VerifyThresholdOutput(
+                isValid: FfiConverterBool.read(from: &buf), 
+                keySetRoot: FfiConverterData.read(from: &buf), 
+                threshold: FfiConverterUInt32.read(from: &buf), 
+                message: FfiConverterData.read(from: &buf), 
+                messageText: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0VerifyThresholdOutput, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isValid, into: &buf)
+        FfiConverterData.write(value.keySetRoot, into: &buf)
+        FfiConverterUInt32.write(value.threshold, into: &buf)
+        FfiConverterData.write(value.message, into: &buf)
+        FfiConverterOptionString.write(value.messageText, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyThresholdOutput_lift(_ buf: RustBuffer) throws -> Risc0VerifyThresholdOutput {
+    return try FfiConverterTypeRisc0VerifyThresholdOutput.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyThresholdOutput_lower(_ value: Risc0VerifyThresholdOutput) -> RustBuffer {
+    return FfiConverterTypeRisc0VerifyThresholdOutput.lower(value)
+}
+
+
+/**
+ * Verify output for EIP-712 typed-data proofs.
+ */
+public struct Risc0VerifyTypedDataOutput {
+    public var isValid: Bool
+    /**
+     * 20-byte signer address.
+     */
+    public var address: Data
+    /**
+     * Signer address as EIP-55 checksummed hex with `0x` prefix.
+     */
+    public var addressHex: String
+    public var domainSeparator: Data
+    /**
+     * Encoded type of the signed struct, e.g. `Mail(address to,string contents)`.
+     */
+    public var structType: String
+    public var disclosedFields: [Risc0Eip712Field]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(isValid: Bool, 
+        /**
+         * 20-byte signer address.
+         */address: Data, 
+        /**
+         * Signer address as EIP-55 checksummed hex with `0x` prefix.
+         */addressHex: String, domainSeparator: Data, 
+        /**
+         * Encoded type of the signed struct, e.g. `Mail(address to,string contents)`.
+         */structType: String, disclosedFields: [Risc0Eip712Field]) {
+        self.isValid = isValid
+        self.address = address
+        self.addressHex = addressHex
+        self.domainSeparator = domainSeparator
+        self.structType = structType
+        self.disclosedFields = disclosedFields
+    }
+}
+
+#if compiler(>=6)
+extension Risc0VerifyTypedDataOutput: Sendable {}
+#endif
+
+
+extension Risc0VerifyTypedDataOutput: Equatable, Hashable {
+    public static func ==(lhs: Risc0VerifyTypedDataOutput, rhs: Risc0VerifyTypedDataOutput) -> Bool {
+        if lhs.isValid != rhs.isValid {
+            return false
+        }
+        if lhs.address != rhs.address {
+            return false
+        }
+        if lhs.addressHex != rhs.addressHex {
+            return false
+        }
+        if lhs.domainSeparator != rhs.domainSeparator {
+            return false
+        }
+        if lhs.structType != rhs.structType {
+            return false
+        }
+        if lhs.disclosedFields != rhs.disclosedFields {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(isValid)
+        hasher.combine(address)
+        hasher.combine(addressHex)
+        hasher.combine(domainSeparator)
+        hasher.combine(structType)
+        hasher.combine(disclosedFields)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0VerifyTypedDataOutput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0VerifyTypedDataOutput {
+        return
+            try Risc0VerifyTypedDataOutput(
+                isValid: FfiConverterBool.read(from: &buf), 
+                address: FfiConverterData.read(from: &buf), 
+                addressHex: FfiConverterString.read(from: &buf), 
+                domainSeparator: FfiConverterData.read(from: &buf), 
+                structType: FfiConverterString.read(from: &buf), 
+                disclosedFields: FfiConverterSequenceTypeRisc0Eip712Field.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0VerifyTypedDataOutput, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isValid, into: &buf)
+        FfiConverterData.write(value.address, into: &buf)
+        FfiConverterString.write(value.addressHex, into: &buf)
+        FfiConverterData.write(value.domainSeparator, into: &buf)
+        FfiConverterString.write(value.structType, into: &buf)
+        FfiConverterSequenceTypeRisc0Eip712Field.write(value.disclosedFields, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyTypedDataOutput_lift(_ buf: RustBuffer) throws -> Risc0VerifyTypedDataOutput {
+    return try FfiConverterTypeRisc0VerifyTypedDataOutput.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyTypedDataOutput_lower(_ value: Risc0VerifyTypedDataOutput) -> RustBuffer {
+    return FfiConverterTypeRisc0VerifyTypedDataOutput.lower(value)
+}
+
+
+/**
+ * Verify output for proofs that the signer key is not revoked.
+ */
+public struct Risc0VerifyUnrevokedOutput {
+    public var isValid: Bool
+    public var message: Data
+    /**
+     * The message as text, present only when it is valid UTF-8.
+     */
+    public var messageText: String?
+    public var signer: Risc0PublicKey
+    /**
+     * Root of the revocation tree the signer was checked against.
+     */
+    public var revocationRoot: Data
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(isValid: Bool, message: Data, 
+        /**
+         * The message as text, present only when it is valid UTF-8.
+         */messageText: String?, signer: Risc0PublicKey, 
+        /**
+         * Root of the revocation tree the signer was checked against.
+         */revocationRoot: Data) {
+        self.isValid = isValid
+        self.message = message
+        self.messageText = messageText
+        self.signer = signer
+        self.revocationRoot = revocationRoot
+    }
+}
+
+#if compiler(>=6)
+extension Risc0VerifyUnrevokedOutput: Sendable {}
+#endif
+
+
+extension Risc0VerifyUnrevokedOutput: Equatable, Hashable {
+    public static func ==(lhs: Risc0VerifyUnrevokedOutput, rhs: Risc0VerifyUnrevokedOutput) -> Bool {
+        if lhs.isValid != rhs.isValid {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        if lhs.messageText != rhs.messageText {
+            return false
+        }
+        if lhs.signer != rhs.signer {
+            return false
+        }
+        if lhs.revocationRoot != rhs.revocationRoot {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(isValid)
+        hasher.combine(message)
+        hasher.combine(messageText)
+        hasher.combine(signer)
+        hasher.combine(revocationRoot)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0VerifyUnrevokedOutput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0VerifyUnrevokedOutput {
+        return
+            try Risc0VerifyUnrevokedOutput(
+                isValid: FfiConverterBool.read(from: &buf), 
+                message: FfiConverterData.read(from: &buf), 
+                messageText: FfiConverterOptionString.read(from: &buf), 
+                signer: FfiConverterTypeRisc0PublicKey.read(from: &buf), 
+                revocationRoot: FfiConverterData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0VerifyUnrevokedOutput, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isValid, into: &buf)
+        FfiConverterData.write(value.message, into: &buf)
+        FfiConverterOptionString.write(value.messageText, into: &buf)
+        FfiConverterTypeRisc0PublicKey.write(value.signer, into: &buf)
+        FfiConverterData.write(value.revocationRoot, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyUnrevokedOutput_lift(_ buf: RustBuffer) throws -> Risc0VerifyUnrevokedOutput {
+    return try FfiConverterTypeRisc0VerifyUnrevokedOutput.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyUnrevokedOutput_lower(_ value: Risc0VerifyUnrevokedOutput) -> RustBuffer {
+    return FfiConverterTypeRisc0VerifyUnrevokedOutput.lower(value)
+}
+
+
+/**
+ * Verify output for receipts with a validity window.
+ */
+public struct Risc0VerifyValidityOutput {
+    public var isValid: Bool
+    public var message: Data
+    /**
+     * The message as text, present only when it is valid UTF-8.
+     */
+    public var messageText: String?
+    public var signer: Risc0PublicKey
+    /**
+     * Start of the validity window, in Unix seconds.
+     */
+    public var notBefore: UInt64
+    /**
+     * End of the validity window (inclusive), in Unix seconds.
+     */
+    public var notAfter: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(isValid: Bool, message: Data, 
+        /**
+         * The message as text, present only when it is valid UTF-8.
+         */messageText: String?, signer: Risc0PublicKey, 
+        /**
+         * Start of the validity window, in Unix seconds.
+         */notBefore: UInt64, 
+        /**
+         * End of the validity window (inclusive), in Unix seconds.
+         */notAfter: UInt64) {
+        self.isValid = isValid
+        self.message = message
+        self.messageText = messageText
+        self.signer = signer
+        self.notBefore = notBefore
+        self.notAfter = notAfter
+    }
+}
+
+#if compiler(>=6)
+extension Risc0VerifyValidityOutput: Sendable {}
+#endif
+
+
+extension Risc0VerifyValidityOutput: Equatable, Hashable {
+    public static func ==(lhs: Risc0VerifyValidityOutput, rhs: Risc0VerifyValidityOutput) -> Bool {
+        if lhs.isValid != rhs.isValid {
+            return false
+        }
+        if lhs.message != rhs.message {
+            return false
+        }
+        if lhs.messageText != rhs.messageText {
+            return false
+        }
+        if lhs.signer != rhs.signer {
+            return false
+        }
+        if lhs.notBefore != rhs.notBefore {
+            return false
+        }
+        if lhs.notAfter != rhs.notAfter {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(isValid)
+        hasher.combine(message)
+        hasher.combine(messageText)
+        hasher.combine(signer)
+        hasher.combine(notBefore)
+        hasher.combine(notAfter)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0VerifyValidityOutput: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0VerifyValidityOutput {
+        return
+            try Risc0VerifyValidityOutput(
+                isValid: FfiConverterBool.read(from: &buf), 
+                message: FfiConverterData.read(from: &buf), 
+                messageText: FfiConverterOptionString.read(from: &buf), 
+                signer: FfiConverterTypeRisc0PublicKey.read(from: &buf), 
+                notBefore: FfiConverterUInt64.read(from: &buf), 
+                notAfter: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Risc0VerifyValidityOutput, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isValid, into: &buf)
+        FfiConverterData.write(value.message, into: &buf)
+        FfiConverterOptionString.write(value.messageText, into: &buf)
+        FfiConverterTypeRisc0PublicKey.write(value.signer, into: &buf)
+        FfiConverterUInt64.write(value.notBefore, into: &buf)
+        FfiConverterUInt64.write(value.notAfter, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyValidityOutput_lift(_ buf: RustBuffer) throws -> Risc0VerifyValidityOutput {
+    return try FfiConverterTypeRisc0VerifyValidityOutput.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0VerifyValidityOutput_lower(_ value: Risc0VerifyValidityOutput) -> RustBuffer {
+    return FfiConverterTypeRisc0VerifyValidityOutput.lower(value)
+}
+
+
+public enum MoproError {
+
+    
+    
+    case CircomError(String
+    )
+    case Halo2Error(String
+    )
+    case NoirError(String
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeMoproError: FfiConverterRustBuffer {
+    typealias SwiftType = MoproError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MoproError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .CircomError(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 2: return .Halo2Error(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 3: return .NoirError(
+            try FfiConverterString.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: MoproError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .CircomError(v1):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .Halo2Error(v1):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .NoirError(v1):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(v1, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMoproError_lift(_ buf: RustBuffer) throws -> MoproError {
+    return try FfiConverterTypeMoproError.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMoproError_lower(_ value: MoproError) -> RustBuffer {
+    return FfiConverterTypeMoproError.lower(value)
+}
+
+
+extension MoproError: Equatable, Hashable {}
+
+
+
+extension MoproError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ProofLib {
+    
+    case arkworks
+    case rapidsnark
+}
+
+
+#if compiler(>=6)
+extension ProofLib: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeProofLib: FfiConverterRustBuffer {
+    typealias SwiftType = ProofLib
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ProofLib {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .arkworks
+        
+        case 2: return .rapidsnark
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ProofLib, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .arkworks:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .rapidsnark:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProofLib_lift(_ buf: RustBuffer) throws -> ProofLib {
+    return try FfiConverterTypeProofLib.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeProofLib_lower(_ value: ProofLib) -> RustBuffer {
+    return FfiConverterTypeProofLib.lower(value)
+}
+
+
+extension ProofLib: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Elliptic curve of a proven public key.
+ */
+
+public enum Risc0Curve {
+    
+    /**
+     * secp256r1 / NIST P-256.
+     */
+    case p256
+    /**
+     * secp256k1 ECDSA, as used by Ethereum and Bitcoin.
+     */
+    case secp256k1
+    /**
+     * Ed25519 (EdDSA over Curve25519).
+     */
+    case ed25519
+    /**
+     * secp384r1 / NIST P-384, with SHA-384.
+     */
+    case p384
+    /**
+     * secp256k1 x-only key of a BIP-340 Schnorr signer, reported as the
+     * even-Y point BIP-340 assigns to it.
+     */
+    case bip340
+}
+
+
+#if compiler(>=6)
+extension Risc0Curve: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0Curve: FfiConverterRustBuffer {
+    typealias SwiftType = Risc0Curve
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0Curve {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .p256
+        
+        case 2: return .secp256k1
+        
+        case 3: return .ed25519
+        
+        case 4: return .p384
+        
+        case 5: return .bip340
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: Risc0Curve, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .p256:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .secp256k1:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .ed25519:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .p384:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .bip340:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0Curve_lift(_ buf: RustBuffer) throws -> Risc0Curve {
+    return try FfiConverterTypeRisc0Curve.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0Curve_lower(_ value: Risc0Curve) -> RustBuffer {
+    return FfiConverterTypeRisc0Curve.lower(value)
+}
+
+
+extension Risc0Curve: Equatable, Hashable {}
+
+
+
+
+public enum Risc0Error {
+
+    
+    
+    case ProveError(String
+    )
+    case SerializeError(String
+    )
+    case VerifyError(String
+    )
+    case DecodeError(String
+    )
+    case InputError(String
+    )
+    case SignerMismatchError(String
+    )
+    case MessageMismatchError(String
+    )
+    case ChallengeMismatchError(String
+    )
+    case ContextMismatchError(String
+    )
+    case ExpiredError(String
+    )
+    case NotYetValidError(String
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0Error: FfiConverterRustBuffer {
+    typealias SwiftType = Risc0Error
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0Error {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .ProveError(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 2: return .SerializeError(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 3: return .VerifyError(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 4: return .DecodeError(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 5: return .InputError(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 6: return .SignerMismatchError(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 7: return .MessageMismatchError(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 8: return .ChallengeMismatchError(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 9: return .ContextMismatchError(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 10: return .ExpiredError(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 11: return .NotYetValidError(
+            try FfiConverterString.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: Risc0Error, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .ProveError(v1):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .SerializeError(v1):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .VerifyError(v1):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .DecodeError(v1):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .InputError(v1):
+            writeInt(&buf, Int32(5))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .SignerMismatchError(v1):
+            writeInt(&buf, Int32(6))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .MessageMismatchError(v1):
+            writeInt(&buf, Int32(7))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .ChallengeMismatchError(v1):
+            writeInt(&buf, Int32(8))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .ContextMismatchError(v1):
+            writeInt(&buf, Int32(9))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .ExpiredError(v1):
+            writeInt(&buf, Int32(10))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .NotYetValidError(v1):
+            writeInt(&buf, Int32(11))
+            FfiConverterString.write(v1, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0Error_lift(_ buf: RustBuffer) throws -> Risc0Error {
+    return try FfiConverterTypeRisc0Error.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0Error_lower(_ value: Risc0Error) -> RustBuffer {
+    return FfiConverterTypeRisc0Error.lower(value)
+}
+
+
+extension Risc0Error: Equatable, Hashable {}
+
+
+
+extension Risc0Error: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * How the signed message is handed to the guest.
+ */
+
+public enum Risc0MessageMode {
+    
+    /**
+     * The guest hashes the whole message and commits it to the journal.
+     */
+    case full
+    /**
+     * The host hashes the message with SHA-256; the guest verifies the
+     * signature over the digest and commits only the digest. Proving cost no
+     * longer grows with the message size.
+     */
+    case prehashed
+}
+
+
+#if compiler(>=6)
+extension Risc0MessageMode: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0MessageMode: FfiConverterRustBuffer {
+    typealias SwiftType = Risc0MessageMode
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0MessageMode {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .full
+        
+        case 2: return .prehashed
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: Risc0MessageMode, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .full:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .prehashed:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0MessageMode_lift(_ buf: RustBuffer) throws -> Risc0MessageMode {
+    return try FfiConverterTypeRisc0MessageMode.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0MessageMode_lower(_ value: Risc0MessageMode) -> RustBuffer {
+    return FfiConverterTypeRisc0MessageMode.lower(value)
+}
+
+
+extension Risc0MessageMode: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Kind of receipt that was produced.
+ */
+
+public enum Risc0ReceiptKind {
+    
+    /**
+     * See [`Risc0RequestedReceiptKind::Composite`].
+     */
+    case composite
+    /**
+     * See [`Risc0RequestedReceiptKind::Succinct`].
+     */
+    case succinct
+    /**
+     * See [`Risc0RequestedReceiptKind::Groth16`].
+     */
+    case groth16
+    /**
+     * Dev-mode receipt without a real seal, produced instead of the
+     * requested kind when `RISC0_DEV_MODE` is set.
+     */
+    case fake
+}
+
+
+#if compiler(>=6)
+extension Risc0ReceiptKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0ReceiptKind: FfiConverterRustBuffer {
+    typealias SwiftType = Risc0ReceiptKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0ReceiptKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .composite
+        
+        case 2: return .succinct
+        
+        case 3: return .groth16
+        
+        case 4: return .fake
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: Risc0ReceiptKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .composite:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .succinct:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .groth16:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .fake:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0ReceiptKind_lift(_ buf: RustBuffer) throws -> Risc0ReceiptKind {
+    return try FfiConverterTypeRisc0ReceiptKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0ReceiptKind_lower(_ value: Risc0ReceiptKind) -> RustBuffer {
+    return FfiConverterTypeRisc0ReceiptKind.lower(value)
+}
+
+
+extension Risc0ReceiptKind: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Kind of receipt to prove, from largest and fastest to prove to smallest.
+ */
+
+public enum Risc0RequestedReceiptKind {
+    
+    /**
+     * One STARK seal per segment; the prover default. Size grows with the
+     * execution length.
+     */
+    case composite
+    /**
+     * A single constant-size STARK seal (a few hundred KB).
+     */
+    case succinct
+    /**
+     * A Groth16 SNARK seal of a few hundred bytes, verifiable on-chain.
+     * Proving requires an x86 host and is not available on phones.
+     */
+    case groth16
+}
+
+
+#if compiler(>=6)
+extension Risc0RequestedReceiptKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0RequestedReceiptKind: FfiConverterRustBuffer {
+    typealias SwiftType = Risc0RequestedReceiptKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0RequestedReceiptKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .composite
+        
+        case 2: return .succinct
+        
+        case 3: return .groth16
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: Risc0RequestedReceiptKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .composite:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .succinct:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .groth16:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0RequestedReceiptKind_lift(_ buf: RustBuffer) throws -> Risc0RequestedReceiptKind {
+    return try FfiConverterTypeRisc0RequestedReceiptKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0RequestedReceiptKind_lower(_ value: Risc0RequestedReceiptKind) -> RustBuffer {
+    return FfiConverterTypeRisc0RequestedReceiptKind.lower(value)
+}
+
+
+extension Risc0RequestedReceiptKind: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * RSA signature padding scheme.
+ */
+
+public enum Risc0RsaScheme {
+    
+    /**
+     * PKCS#1 v1.5, as used by RS256.
+     */
+    case pkcs1v15
+    /**
+     * PSS with a 32-byte salt, as used by PS256.
+     */
+    case pss
+}
+
+
+#if compiler(>=6)
+extension Risc0RsaScheme: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRisc0RsaScheme: FfiConverterRustBuffer {
+    typealias SwiftType = Risc0RsaScheme
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Risc0RsaScheme {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .pkcs1v15
+        
+        case 2: return .pss
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: Risc0RsaScheme, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .pkcs1v15:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .pss:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0RsaScheme_lift(_ buf: RustBuffer) throws -> Risc0RsaScheme {
+    return try FfiConverterTypeRisc0RsaScheme.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRisc0RsaScheme_lower(_ value: Risc0RsaScheme) -> RustBuffer {
+    return FfiConverterTypeRisc0RsaScheme.lower(value)
+}
+
+
+extension Risc0RsaScheme: Equatable, Hashable {}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionData: FfiConverterRustBuffer {
+    typealias SwiftType = Data?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterData.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterData.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = [UInt32]
+
+    public static func write(_ value: [UInt32], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterUInt32.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UInt32] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UInt32]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterUInt32.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]
+
+    public static func write(_ value: [String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterString.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [String]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterString.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceData: FfiConverterRustBuffer {
+    typealias SwiftType = [Data]
+
+    public static func write(_ value: [Data], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterData.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Data] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Data]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterData.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeRisc0BatchEntry: FfiConverterRustBuffer {
+    typealias SwiftType = [Risc0BatchEntry]
+
+    public static func write(_ value: [Risc0BatchEntry], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeRisc0BatchEntry.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Risc0BatchEntry] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Risc0BatchEntry]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeRisc0BatchEntry.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeRisc0Eip712Field: FfiConverterRustBuffer {
+    typealias SwiftType = [Risc0Eip712Field]
+
+    public static func write(_ value: [Risc0Eip712Field], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeRisc0Eip712Field.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Risc0Eip712Field] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Risc0Eip712Field]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeRisc0Eip712Field.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeRisc0ThresholdSignature: FfiConverterRustBuffer {
+    typealias SwiftType = [Risc0ThresholdSignature]
+
+    public static func write(_ value: [Risc0ThresholdSignature], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeRisc0ThresholdSignature.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Risc0ThresholdSignature] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Risc0ThresholdSignature]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeRisc0ThresholdSignature.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeRisc0VerifiedBatchEntry: FfiConverterRustBuffer {
+    typealias SwiftType = [Risc0VerifiedBatchEntry]
+
+    public static func write(_ value: [Risc0VerifiedBatchEntry], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeRisc0VerifiedBatchEntry.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Risc0VerifiedBatchEntry] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Risc0VerifiedBatchEntry]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeRisc0VerifiedBatchEntry.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeRisc0VerifyBytesOutput: FfiConverterRustBuffer {
+    typealias SwiftType = [Risc0VerifyBytesOutput]
+
+    public static func write(_ value: [Risc0VerifyBytesOutput], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeRisc0VerifyBytesOutput.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Risc0VerifyBytesOutput] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Risc0VerifyBytesOutput]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeRisc0VerifyBytesOutput.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDictionaryStringSequenceString: FfiConverterRustBuffer {
+    public static func write(_ value: [String: [String]], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for (key, value) in value {
+            FfiConverterString.write(key, into: &buf)
+            FfiConverterSequenceString.write(value, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: [String]] {
+        let len: Int32 = try readInt(&buf)
+        var dict = [String: [String]]()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try FfiConverterString.read(from: &buf)
+            let value = try FfiConverterSequenceString.read(from: &buf)
+            dict[key] = value
+        }
+        return dict
+    }
+}
+/**
+ * Same as [`crate::risc0_prove`] on Ed25519, with a freshly generated keypair.
+ */
+public func ed25519Prove(message: String, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_ed25519_prove(
+        FfiConverterString.lower(message),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Same as [`crate::risc0_prove_signature`] for a 32-byte Ed25519 public key
+ * and 64-byte signature.
+ *
+ * The signature is checked strictly, as the guest does, so weak keys and
+ * non-canonical signatures are rejected before proving.
+ */
+public func ed25519ProveSignature(publicKey: Data, message: Data, signature: Data, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_ed25519_prove_signature(
+        FfiConverterData.lower(publicKey),
+        FfiConverterData.lower(message),
+        FfiConverterData.lower(signature),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Verifies a receipt from [`ed25519_prove`] or [`ed25519_prove_signature`].
+ */
+public func ed25519Verify(receiptBytes: Data)throws  -> Risc0VerifyBytesOutput  {
+    return try  FfiConverterTypeRisc0VerifyBytesOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_ed25519_verify(
+        FfiConverterData.lower(receiptBytes),$0
+    )
+})
+}
+/**
+ * EIP-712 encoding of an `address` member.
+ */
+public func ethEncodeTypedAddress(address: Data)throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_eth_encode_typed_address(
+        FfiConverterData.lower(address),$0
+    )
+})
+}
+/**
+ * EIP-712 encoding of a `bool` member.
+ */
+public func ethEncodeTypedBool(value: Bool) -> Data  {
+    return try!  FfiConverterData.lift(try! rustCall() {
+    uniffi_mopro_r0_example_app_fn_func_eth_encode_typed_bool(
+        FfiConverterBool.lower(value),$0
+    )
+})
+}
+/**
+ * EIP-712 encoding of a `bytes` member: its Keccak-256.
+ */
+public func ethEncodeTypedBytes(value: Data) -> Data  {
+    return try!  FfiConverterData.lift(try! rustCall() {
+    uniffi_mopro_r0_example_app_fn_func_eth_encode_typed_bytes(
+        FfiConverterData.lower(value),$0
+    )
+})
+}
+/**
+ * EIP-712 encoding of a `string` member: its Keccak-256.
+ */
+public func ethEncodeTypedString(value: String) -> Data  {
+    return try!  FfiConverterData.lift(try! rustCall() {
+    uniffi_mopro_r0_example_app_fn_func_eth_encode_typed_string(
+        FfiConverterString.lower(value),$0
+    )
+})
+}
+/**
+ * EIP-712 encoding of a `uint` member that fits in 64 bits.
+ */
+public func ethEncodeTypedUint(value: UInt64) -> Data  {
+    return try!  FfiConverterData.lift(try! rustCall() {
+    uniffi_mopro_r0_example_app_fn_func_eth_encode_typed_uint(
+        FfiConverterUInt64.lower(value),$0
+    )
+})
+}
+/**
+ * EIP-712 `hashStruct`, which is also the encoding of a nested struct member.
+ *
+ * `struct_type` is the full encoded type including referenced types, e.g.
+ * `Mail(Person from,string contents)Person(string name,address wallet)`.
+ */
+public func ethHashTypedStruct(structType: String, encodedFields: [Data])throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_eth_hash_typed_struct(
+        FfiConverterString.lower(structType),
+        FfiConverterSequenceData.lower(encodedFields),$0
+    )
+})
+}
+/**
+ * Proves that the owner of an Ethereum address signed `message` with
+ * `personal_sign`.
+ *
+ * `signature` is the 65-byte `r || s || v` value wallets return. The journal
+ * commits the recovered address and the message.
+ */
+public func ethProvePersonalSign(message: Data, signature: Data, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_eth_prove_personal_sign(
+        FfiConverterData.lower(message),
+        FfiConverterData.lower(signature),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Proves that the owner of an Ethereum address signed EIP-712 typed data.
+ *
+ * The struct is passed as its encoded type and 32-byte member encodings (see
+ * the `eth_encode_typed_*` helpers). The guest computes the EIP-712 digest,
+ * recovers the signer from the 65-byte `r || s || v` signature, and commits
+ * the signer address, the domain separator, the struct type, and only the
+ * members listed in `disclosed_fields`.
+ */
+public func ethProveTypedData(domain: Risc0Eip712Domain, structType: String, encodedFields: [Data], disclosedFields: [UInt32], signature: Data, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_eth_prove_typed_data(
+        FfiConverterTypeRisc0Eip712Domain_lower(domain),
+        FfiConverterString.lower(structType),
+        FfiConverterSequenceData.lower(encodedFields),
+        FfiConverterSequenceUInt32.lower(disclosedFields),
+        FfiConverterData.lower(signature),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * EIP-712 domain separator, to compare with
+ * [`Risc0VerifyTypedDataOutput::domain_separator`].
+ */
+public func ethTypedDataDomainSeparator(domain: Risc0Eip712Domain)throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_eth_typed_data_domain_separator(
+        FfiConverterTypeRisc0Eip712Domain_lower(domain),$0
+    )
+})
+}
+/**
+ * Verifies a receipt from [`eth_prove_personal_sign`] and returns the signer
+ * address and message.
+ */
+public func ethVerifyPersonalSign(receiptBytes: Data)throws  -> Risc0VerifyEthOutput  {
+    return try  FfiConverterTypeRisc0VerifyEthOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_eth_verify_personal_sign(
+        FfiConverterData.lower(receiptBytes),$0
+    )
+})
+}
+/**
+ * Verifies a receipt from [`eth_prove_typed_data`] and returns the signer
+ * address, domain separator, struct type and disclosed fields.
+ */
+public func ethVerifyTypedData(receiptBytes: Data)throws  -> Risc0VerifyTypedDataOutput  {
+    return try  FfiConverterTypeRisc0VerifyTypedDataOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_eth_verify_typed_data(
+        FfiConverterData.lower(receiptBytes),$0
+    )
+})
+}
+public func generateCircomProof(zkeyPath: String, circuitInputs: String, proofLib: ProofLib)throws  -> CircomProofResult  {
+    return try  FfiConverterTypeCircomProofResult_lift(try rustCallWithError(FfiConverterTypeMoproError_lift) {
+    uniffi_mopro_r0_example_app_fn_func_generate_circom_proof(
+        FfiConverterString.lower(zkeyPath),
+        FfiConverterString.lower(circuitInputs),
+        FfiConverterTypeProofLib_lower(proofLib),$0
+    )
+})
+}
+public func generateHalo2Proof(srsPath: String, pkPath: String, circuitInputs: [String: [String]])throws  -> Halo2ProofResult  {
+    return try  FfiConverterTypeHalo2ProofResult_lift(try rustCallWithError(FfiConverterTypeMoproError_lift) {
+    uniffi_mopro_r0_example_app_fn_func_generate_halo2_proof(
+        FfiConverterString.lower(srsPath),
+        FfiConverterString.lower(pkPath),
+        FfiConverterDictionaryStringSequenceString.lower(circuitInputs),$0
+    )
+})
+}
+public func generateNoirProof(circuitPath: String, srsPath: String?, inputs: [String], onChain: Bool, vk: Data, lowMemoryMode: Bool)throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeMoproError_lift) {
+    uniffi_mopro_r0_example_app_fn_func_generate_noir_proof(
+        FfiConverterString.lower(circuitPath),
+        FfiConverterOptionString.lower(srsPath),
+        FfiConverterSequenceString.lower(inputs),
+        FfiConverterBool.lower(onChain),
+        FfiConverterData.lower(vk),
+        FfiConverterBool.lower(lowMemoryMode),$0
+    )
+})
+}
+public func getNoirVerificationKey(circuitPath: String, srsPath: String?, onChain: Bool, lowMemoryMode: Bool)throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeMoproError_lift) {
+    uniffi_mopro_r0_example_app_fn_func_get_noir_verification_key(
+        FfiConverterString.lower(circuitPath),
+        FfiConverterOptionString.lower(srsPath),
+        FfiConverterBool.lower(onChain),
+        FfiConverterBool.lower(lowMemoryMode),$0
+    )
+})
+}
+/**
+ * Same as [`crate::risc0_prove`] on P-384, with a freshly generated keypair.
+ */
+public func p384Prove(message: String, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_p384_prove(
+        FfiConverterString.lower(message),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Same as [`crate::risc0_prove_signature`] for a P-384 key and signature.
+ *
+ * The message is hashed with SHA-384. The signature may be `r || s` (96
+ * bytes) or DER, as found in X.509 certificates and ES384 tokens.
+ */
+public func p384ProveSignature(publicKey: Data, message: Data, signature: Data, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_p384_prove_signature(
+        FfiConverterData.lower(publicKey),
+        FfiConverterData.lower(message),
+        FfiConverterData.lower(signature),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Verifies a receipt from [`p384_prove`] or [`p384_prove_signature`].
+ */
+public func p384Verify(receiptBytes: Data)throws  -> Risc0VerifyBytesOutput  {
+    return try  FfiConverterTypeRisc0VerifyBytesOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_p384_verify(
+        FfiConverterData.lower(receiptBytes),$0
+    )
+})
+}
+/**
+ * Folds receipts from [`crate::risc0_prove`] into a single succinct or Groth16
+ * receipt.
+ *
+ * Every inner receipt is verified on the host first. The journal commits the
+ * inner image ID, the number of receipts and the Merkle root over their
+ * journals; with `commit_journals` it also commits every inner journal.
+ */
+public func risc0Aggregate(receipts: [Data], commitJournals: Bool, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_aggregate(
+        FfiConverterSequenceData.lower(receipts),
+        FfiConverterBool.lower(commitJournals),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Returns the Merkle root over the journals of `receipts`, as committed by
+ * [`risc0_aggregate`]. Receipts must be in the order they were aggregated.
+ */
+public func risc0AggregateRoot(receipts: [Data])throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_aggregate_root(
+        FfiConverterSequenceData.lower(receipts),$0
+    )
+})
+}
+/**
+ * Returns the Merkle root over `(public key, message)` pairs, as committed by
+ * [`risc0_prove_batch`]. Pairs must be in batch order.
+ */
+public func risc0BatchRoot(publicKeys: [Data], messages: [Data])throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_batch_root(
+        FfiConverterSequenceData.lower(publicKeys),
+        FfiConverterSequenceData.lower(messages),$0
+    )
+})
+}
+/**
+ * Builds the next message of a chain: `previous_head || payload`.
+ *
+ * Pass the `head` of the latest link, or `None` to start a new chain.
+ */
+public func risc0ChainMessage(previousHead: Data?, payload: Data)throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_chain_message(
+        FfiConverterOptionData.lower(previousHead),
+        FfiConverterData.lower(payload),$0
+    )
+})
+}
+/**
+ * Computes the commitment to `public_key` that [`risc0_prove_key_commitment`]
+ * publishes for the same `salt`.
+ */
+public func risc0CommitPublicKey(publicKey: Data, salt: Data)throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_commit_public_key(
+        FfiConverterData.lower(publicKey),
+        FfiConverterData.lower(salt),$0
+    )
+})
+}
+/**
+ * Compresses a receipt, e.g. a composite one from [`risc0_prove`], to
+ * `target_kind`, typically on a backend after a phone proved it quickly.
+ *
+ * Only succinct and Groth16 are valid targets. The journal is preserved, so
+ * the result verifies with the same `risc0_verify*` function as the input.
+ */
+public func risc0CompressReceipt(receiptBytes: Data, targetKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_compress_receipt(
+        FfiConverterData.lower(receiptBytes),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(targetKind),$0
+    )
+})
+}
+/**
+ * Builds a message bound to `context`: `context || 0x00 || payload`.
+ *
+ * The context must not contain a zero byte.
+ */
+public func risc0ContextMessage(context: String, payload: Data)throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_context_message(
+        FfiConverterString.lower(context),
+        FfiConverterData.lower(payload),$0
+    )
+})
+}
+/**
+ * Returns a fresh 32-byte challenge for a prover to bind its receipt to.
+ */
+public func risc0GenerateChallenge() -> Data  {
+    return try!  FfiConverterData.lift(try! rustCall() {
+    uniffi_mopro_r0_example_app_fn_func_risc0_generate_challenge($0
+    )
+})
+}
+/**
+ * Returns 32 random bytes, suitable as a blinding value for
+ * [`hidden::risc0_prove_hidden_message`] or a salt for
+ * [`key_commitment::risc0_prove_key_commitment`].
+ */
+public func risc0GenerateSalt() -> Data  {
+    return try!  FfiConverterData.lift(try! rustCall() {
+    uniffi_mopro_r0_example_app_fn_func_risc0_generate_salt($0
+    )
+})
+}
+/**
+ * Returns the Merkle root of a set of SEC1 public keys, as committed by
+ * [`risc0_prove_membership`]. The order of the keys matters.
+ */
+public func risc0KeySetRoot(publicKeys: [Data])throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_key_set_root(
+        FfiConverterSequenceData.lower(publicKeys),$0
+    )
+})
+}
+/**
+ * Opens a key commitment: returns whether `public_key` and `salt` produce
+ * `key_commitment`.
+ */
+public func risc0OpenKeyCommitment(keyCommitment: Data, publicKey: Data, salt: Data)throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_open_key_commitment(
+        FfiConverterData.lower(keyCommitment),
+        FfiConverterData.lower(publicKey),
+        FfiConverterData.lower(salt),$0
+    )
+})
+}
+/**
+ * Signs `message` with a fresh random key and proves the signature,
+ * producing a receipt of `receipt_kind`.
+ *
+ * Succinct and Groth16 receipts take longer to prove but are much smaller to
+ * upload; see [`Risc0ProofOutput::seal_size`].
+ */
+public func risc0Prove(message: String, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_prove(
+        FfiConverterString.lower(message),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Proves all `entries` in a single guest execution, returning one receipt.
+ *
+ * The journal commits the number of entries and the Merkle root over their
+ * `(key, SHA-256 of message)` leaves. With `commit_entries` it also commits
+ * every key and message, which grows the journal with the batch.
+ */
+public func risc0ProveBatch(entries: [Risc0BatchEntry], commitEntries: Bool, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_prove_batch(
+        FfiConverterSequenceTypeRisc0BatchEntry.lower(entries),
+        FfiConverterBool.lower(commitEntries),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Same as [`risc0_prove`] for arbitrary binary messages (CBOR, protobuf, DER, ...).
+ */
+public func risc0ProveBytes(message: Data, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_prove_bytes(
+        FfiConverterData.lower(message),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Proves the next link of a signature chain.
+ *
+ * Without `previous_receipt` this starts a new chain, and `message` must start
+ * with 32 zero bytes. Otherwise `previous_receipt` must be the latest link,
+ * signed by the same key, and `message` must start with its head; see
+ * [`risc0_chain_message`]. The result is a succinct or Groth16 receipt whose
+ * size does not grow with the chain; only a succinct link can be extended.
+ */
+public func risc0ProveChainLink(previousReceipt: Data?, publicKey: Data, message: Data, signature: Data, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_prove_chain_link(
+        FfiConverterOptionData.lower(previousReceipt),
+        FfiConverterData.lower(publicKey),
+        FfiConverterData.lower(message),
+        FfiConverterData.lower(signature),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Proves a signature while committing only the SHA-256 of the message, so the
+ * receipt does not reveal the plaintext.
+ *
+ * A prover-chosen 32-byte `blinding` value is hashed in front of the message;
+ * use it for low-entropy messages that could otherwise be guessed from the
+ * hash. The verifier needs the same value to check a candidate message.
+ */
+public func risc0ProveHiddenMessage(publicKey: Data, message: Data, signature: Data, blinding: Data?, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_prove_hidden_message(
+        FfiConverterData.lower(publicKey),
+        FfiConverterData.lower(message),
+        FfiConverterData.lower(signature),
+        FfiConverterOptionData.lower(blinding),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Proves a signature bound to an application `context` (e.g.
+ * `"com.example.wallet/login/v1"`), so the receipt cannot be reused by
+ * another application.
+ *
+ * With `require_context_prefix`, the guest also checks that the signed
+ * message starts with the context followed by a zero byte, so signatures
+ * made for one domain cannot be proven in another; see
+ * [`risc0_context_message`].
+ */
+public func risc0ProveInContext(context: String, publicKey: Data, message: Data, signature: Data, requireContextPrefix: Bool, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_prove_in_context(
+        FfiConverterString.lower(context),
+        FfiConverterData.lower(publicKey),
+        FfiConverterData.lower(message),
+        FfiConverterData.lower(signature),
+        FfiConverterBool.lower(requireContextPrefix),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Proves a signature while committing only a salted commitment to the signer
+ * key, so the receipt cannot be linked to the key until the prover reveals the
+ * key and salt (see [`risc0_open_key_commitment`]).
+ */
+public func risc0ProveKeyCommitment(publicKey: Data, message: Data, signature: Data, salt: Data, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_prove_key_commitment(
+        FfiConverterData.lower(publicKey),
+        FfiConverterData.lower(message),
+        FfiConverterData.lower(signature),
+        FfiConverterData.lower(salt),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Proves that one of `public_keys` signed `message` without revealing which.
+ *
+ * The journal commits only the Merkle root of `public_keys` and the message;
+ * compare the root with [`risc0_key_set_root`] of the registered set.
+ */
+public func risc0ProveMembership(publicKeys: [Data], publicKey: Data, message: Data, signature: Data, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_prove_membership(
+        FfiConverterSequenceData.lower(publicKeys),
+        FfiConverterData.lower(publicKey),
+        FfiConverterData.lower(message),
+        FfiConverterData.lower(signature),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Proves a signature produced elsewhere, e.g. by a server or hardware token.
+ *
+ * `public_key` is a SEC1-encoded secp256r1 key and `signature` is either the
+ * fixed-size `r || s` encoding or ASN.1 DER. The signature is checked on the
+ * host first so an invalid one fails fast instead of panicking the guest.
+ */
+public func risc0ProveSignature(publicKey: Data, message: Data, signature: Data, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_prove_signature(
+        FfiConverterData.lower(publicKey),
+        FfiConverterData.lower(message),
+        FfiConverterData.lower(signature),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Same as [`risc0_prove_signature`], choosing how the message reaches the
+ * guest.
+ *
+ * Use [`Risc0MessageMode::Prehashed`] for large documents and verify the
+ * resulting receipt with [`prehash::risc0_verify_prehashed`].
+ */
+public func risc0ProveSignatureWithMode(publicKey: Data, message: Data, signature: Data, mode: Risc0MessageMode, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_prove_signature_with_mode(
+        FfiConverterData.lower(publicKey),
+        FfiConverterData.lower(message),
+        FfiConverterData.lower(signature),
+        FfiConverterTypeRisc0MessageMode_lower(mode),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Proves that at least `threshold` distinct keys of `public_keys` signed
+ * `message`, without revealing which.
+ *
+ * Each of `signatures` names the index of its key in `public_keys` and is
+ * checked against that key only. The journal commits the Merkle root of
+ * `public_keys`, the threshold and the message; compare the root with
+ * [`crate::membership::risc0_key_set_root`].
+ */
+public func risc0ProveThreshold(publicKeys: [Data], threshold: UInt32, message: Data, signatures: [Risc0ThresholdSignature], receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_prove_threshold(
+        FfiConverterSequenceData.lower(publicKeys),
+        FfiConverterUInt32.lower(threshold),
+        FfiConverterData.lower(message),
+        FfiConverterSequenceTypeRisc0ThresholdSignature.lower(signatures),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Proves a signature and that the signer key is not in `revoked_public_keys`.
+ *
+ * The journal commits the key, the message, and the revocation root, so the
+ * verifier can check that the proof was made against the current list.
+ */
+public func risc0ProveUnrevoked(revokedPublicKeys: [Data], publicKey: Data, message: Data, signature: Data, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_prove_unrevoked(
+        FfiConverterSequenceData.lower(revokedPublicKeys),
+        FfiConverterData.lower(publicKey),
+        FfiConverterData.lower(message),
+        FfiConverterData.lower(signature),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Proves a signature bound to a verifier-supplied `challenge`, so the receipt
+ * cannot be replayed to a verifier that issued a different one.
+ *
+ * With `require_challenge_in_message`, the guest also checks that the signed
+ * message contains the challenge, which rules out signatures computed before
+ * the challenge was issued.
+ */
+public func risc0ProveWithChallenge(publicKey: Data, message: Data, signature: Data, challenge: Data, requireChallengeInMessage: Bool, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_prove_with_challenge(
+        FfiConverterData.lower(publicKey),
+        FfiConverterData.lower(message),
+        FfiConverterData.lower(signature),
+        FfiConverterData.lower(challenge),
+        FfiConverterBool.lower(requireChallengeInMessage),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Proves a signature with a validity window committed next to the key and
+ * message. Timestamps are Unix seconds and `not_after` is inclusive.
+ */
+public func risc0ProveWithValidity(publicKey: Data, message: Data, signature: Data, notBefore: UInt64, notAfter: UInt64, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_prove_with_validity(
+        FfiConverterData.lower(publicKey),
+        FfiConverterData.lower(message),
+        FfiConverterData.lower(signature),
+        FfiConverterUInt64.lower(notBefore),
+        FfiConverterUInt64.lower(notAfter),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Returns the root of the revocation tree over `revoked_public_keys`, as
+ * committed by [`risc0_prove_unrevoked`]. The order of the keys does not matter.
+ */
+public func risc0RevocationRoot(revokedPublicKeys: [Data])throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_revocation_root(
+        FfiConverterSequenceData.lower(revokedPublicKeys),$0
+    )
+})
+}
+public func risc0Verify(receiptBytes: Data)throws  -> Risc0VerifyOutput  {
+    return try  FfiConverterTypeRisc0VerifyOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_verify(
+        FfiConverterData.lower(receiptBytes),$0
+    )
+})
+}
+/**
+ * Verifies a receipt from [`risc0_aggregate`] and returns the committed
+ * count, root and, if present, inner statements.
+ *
+ * The receipt must aggregate `risc0_prove` receipts; aggregates over any
+ * other guest fail with a `VerifyError`.
+ */
+public func risc0VerifyAggregate(receiptBytes: Data)throws  -> Risc0VerifyAggregateOutput  {
+    return try  FfiConverterTypeRisc0VerifyAggregateOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_verify_aggregate(
+        FfiConverterData.lower(receiptBytes),$0
+    )
+})
+}
+/**
+ * Verifies a receipt from [`risc0_prove_batch`] and returns the committed
+ * count, root and, if present, entries.
+ *
+ * Without committed entries, compare the root with [`risc0_batch_root`] over
+ * the expected keys and messages.
+ */
+public func risc0VerifyBatch(receiptBytes: Data)throws  -> Risc0VerifyBatchOutput  {
+    return try  FfiConverterTypeRisc0VerifyBatchOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_verify_batch(
+        FfiConverterData.lower(receiptBytes),$0
+    )
+})
+}
+/**
+ * Same as [`risc0_verify`] but returns the message as raw bytes, so receipts
+ * over non-UTF-8 payloads verify instead of failing with a `DecodeError`.
+ */
+public func risc0VerifyBytes(receiptBytes: Data)throws  -> Risc0VerifyBytesOutput  {
+    return try  FfiConverterTypeRisc0VerifyBytesOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_verify_bytes(
+        FfiConverterData.lower(receiptBytes),$0
+    )
+})
+}
+/**
+ * Verifies a receipt from [`risc0_prove_chain_link`] and returns the chain's
+ * signer, length and head.
+ */
+public func risc0VerifyChain(receiptBytes: Data)throws  -> Risc0VerifyChainOutput  {
+    return try  FfiConverterTypeRisc0VerifyChainOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_verify_chain(
+        FfiConverterData.lower(receiptBytes),$0
+    )
+})
+}
+/**
+ * Verifies a receipt from [`risc0_prove_hidden_message`].
+ *
+ * When `candidate_message` is given, it is hashed with `blinding` and must
+ * match the committed hash, otherwise [`Risc0Error::MessageMismatchError`] is
+ * returned.
+ */
+public func risc0VerifyHiddenMessage(receiptBytes: Data, candidateMessage: Data?, blinding: Data?)throws  -> Risc0VerifyCommitmentOutput  {
+    return try  FfiConverterTypeRisc0VerifyCommitmentOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_verify_hidden_message(
+        FfiConverterData.lower(receiptBytes),
+        FfiConverterOptionData.lower(candidateMessage),
+        FfiConverterOptionData.lower(blinding),$0
+    )
+})
+}
+/**
+ * Verifies a receipt from [`risc0_prove_in_context`] and checks that it was
+ * made for `expected_context`.
+ *
+ * With `require_context_prefix`, receipts whose message was not checked to
+ * start with the context are rejected too. Both cases fail with
+ * [`Risc0Error::ContextMismatchError`].
+ */
+public func risc0VerifyInContext(receiptBytes: Data, expectedContext: String, requireContextPrefix: Bool)throws  -> Risc0VerifyBytesOutput  {
+    return try  FfiConverterTypeRisc0VerifyBytesOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_verify_in_context(
+        FfiConverterData.lower(receiptBytes),
+        FfiConverterString.lower(expectedContext),
+        FfiConverterBool.lower(requireContextPrefix),$0
+    )
+})
+}
+/**
+ * Verifies a receipt from [`risc0_prove_key_commitment`] and returns the
+ * committed key commitment and message.
+ */
+public func risc0VerifyKeyCommitment(receiptBytes: Data)throws  -> Risc0VerifyKeyCommitmentOutput  {
+    return try  FfiConverterTypeRisc0VerifyKeyCommitmentOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_verify_key_commitment(
+        FfiConverterData.lower(receiptBytes),$0
+    )
+})
+}
+/**
+ * Verifies a receipt from [`risc0_prove_membership`] and returns the
+ * committed key set root and message.
+ */
+public func risc0VerifyMembership(receiptBytes: Data)throws  -> Risc0VerifyMembershipOutput  {
+    return try  FfiConverterTypeRisc0VerifyMembershipOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_verify_membership(
+        FfiConverterData.lower(receiptBytes),$0
+    )
+})
+}
+/**
+ * Verifies a receipt produced in [`crate::Risc0MessageMode::Prehashed`] and
+ * returns the committed message digest. Compare it with the SHA-256 of the
+ * document the caller expects.
+ */
+public func risc0VerifyPrehashed(receiptBytes: Data)throws  -> Risc0VerifyDigestOutput  {
+    return try  FfiConverterTypeRisc0VerifyDigestOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_verify_prehashed(
+        FfiConverterData.lower(receiptBytes),$0
+    )
+})
+}
+/**
+ * Verifies a receipt from [`risc0_prove_threshold`] and returns the committed
+ * key set root, threshold and message.
+ */
+public func risc0VerifyThreshold(receiptBytes: Data)throws  -> Risc0VerifyThresholdOutput  {
+    return try  FfiConverterTypeRisc0VerifyThresholdOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_verify_threshold(
+        FfiConverterData.lower(receiptBytes),$0
+    )
+})
+}
+/**
+ * Verifies a receipt from [`risc0_prove_unrevoked`] and returns the committed
+ * key, message, and revocation root. Compare the root with
+ * [`risc0_revocation_root`] of the current revocation list.
+ */
+public func risc0VerifyUnrevoked(receiptBytes: Data)throws  -> Risc0VerifyUnrevokedOutput  {
+    return try  FfiConverterTypeRisc0VerifyUnrevokedOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_verify_unrevoked(
+        FfiConverterData.lower(receiptBytes),$0
+    )
+})
+}
+/**
+ * Verifies a receipt from [`risc0_prove_with_challenge`] and checks that it
+ * was bound to `expected_challenge`.
+ *
+ * With `require_challenge_in_message`, receipts whose signed message was not
+ * checked to contain the challenge are rejected too. Both cases fail with
+ * [`Risc0Error::ChallengeMismatchError`].
+ */
+public func risc0VerifyWithChallenge(receiptBytes: Data, expectedChallenge: Data, requireChallengeInMessage: Bool)throws  -> Risc0VerifyBytesOutput  {
+    return try  FfiConverterTypeRisc0VerifyBytesOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_verify_with_challenge(
+        FfiConverterData.lower(receiptBytes),
+        FfiConverterData.lower(expectedChallenge),
+        FfiConverterBool.lower(requireChallengeInMessage),$0
+    )
+})
+}
+/**
+ * Verifies a receipt like [`risc0_verify`] and additionally requires the
+ * journal to match an expected signer and, optionally, message.
+ *
+ * Fails with [`Risc0Error::SignerMismatchError`] when the proven key is not in
+ * `allowed_public_keys` and with [`Risc0Error::MessageMismatchError`] when the
+ * message or its SHA-256 differ from the expected values.
+ */
+public func risc0VerifyWithPolicy(receiptBytes: Data, allowedPublicKeys: [Data], expectedMessage: Data?, expectedMessageSha256: Data?)throws  -> Risc0VerifyOutput  {
+    return try  FfiConverterTypeRisc0VerifyOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_verify_with_policy(
+        FfiConverterData.lower(receiptBytes),
+        FfiConverterSequenceData.lower(allowedPublicKeys),
+        FfiConverterOptionData.lower(expectedMessage),
+        FfiConverterOptionData.lower(expectedMessageSha256),$0
+    )
+})
+}
+/**
+ * Verifies a receipt from [`risc0_prove_with_validity`] at time `now` (Unix
+ * seconds), failing with [`Risc0Error::NotYetValidError`] or
+ * [`Risc0Error::ExpiredError`] outside the committed window.
+ */
+public func risc0VerifyWithValidity(receiptBytes: Data, now: UInt64)throws  -> Risc0VerifyValidityOutput  {
+    return try  FfiConverterTypeRisc0VerifyValidityOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_risc0_verify_with_validity(
+        FfiConverterData.lower(receiptBytes),
+        FfiConverterUInt64.lower(now),$0
+    )
+})
 }
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
-    typealias SwiftType = [String]
-
-    public static func write(_ value: [String], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterString.write(item, into: &buf)
-        }
-    }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
-        let len: Int32 = try readInt(&buf)
-        var seq = [String]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterString.read(from: &buf))
-        }
-        return seq
-    }
+/**
+ * Computes the modulus hash that [`rsa_verify`] reports, so verifiers can
+ * pin the expected issuer key.
+ */
+public func rsaModulusHash(modulus: Data) -> Data  {
+    return try!  FfiConverterData.lift(try! rustCall() {
+    uniffi_mopro_r0_example_app_fn_func_rsa_modulus_hash(
+        FfiConverterData.lower(modulus),$0
+    )
+})
 }
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterDictionaryStringSequenceString: FfiConverterRustBuffer {
-    public static func write(_ value: [String: [String]], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for (key, value) in value {
-            FfiConverterString.write(key, into: &buf)
-            FfiConverterSequenceString.write(value, into: &buf)
-        }
-    }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: [String]] {
-        let len: Int32 = try readInt(&buf)
-        var dict = [String: [String]]()
-        dict.reserveCapacity(Int(len))
-        for _ in 0..<len {
-            let key = try FfiConverterString.read(from: &buf)
-            let value = try FfiConverterSequenceString.read(from: &buf)
-            dict[key] = value
-        }
-        return dict
-    }
+/**
+ * Same as [`crate::risc0_prove`] on RSA, with a freshly generated 2048-bit
+ * key. Key generation takes noticeably longer than for elliptic curves.
+ */
+public func rsaProve(message: String, scheme: Risc0RsaScheme, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_rsa_prove(
+        FfiConverterString.lower(message),
+        FfiConverterTypeRisc0RsaScheme_lower(scheme),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
 }
-public func generateCircomProof(zkeyPath: String, circuitInputs: String, proofLib: ProofLib)throws  -> CircomProofResult  {
-    return try  FfiConverterTypeCircomProofResult_lift(try rustCallWithError(FfiConverterTypeMoproError_lift) {
-    uniffi_mopro_r0_example_app_fn_func_generate_circom_proof(
-        FfiConverterString.lower(zkeyPath),
-        FfiConverterString.lower(circuitInputs),
-        FfiConverterTypeProofLib_lower(proofLib),$0
+/**
+ * Same as [`crate::risc0_prove_signature`] for an RSA-2048, RSA-3072 or
+ * RSA-4096 key given as a big-endian modulus and public exponent.
+ *
+ * The message is hashed with SHA-256. The signature is checked on the host
+ * first so that invalid input fails fast instead of inside the prover.
+ */
+public func rsaProveSignature(modulus: Data, exponent: Data, scheme: Risc0RsaScheme, message: Data, signature: Data, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_rsa_prove_signature(
+        FfiConverterData.lower(modulus),
+        FfiConverterData.lower(exponent),
+        FfiConverterTypeRisc0RsaScheme_lower(scheme),
+        FfiConverterData.lower(message),
+        FfiConverterData.lower(signature),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
     )
 })
 }
-public func generateHalo2Proof(srsPath: String, pkPath: String, circuitInputs: [String: [String]])throws  -> Halo2ProofResult  {
-    return try  FfiConverterTypeHalo2ProofResult_lift(try rustCallWithError(FfiConverterTypeMoproError_lift) {
-    uniffi_mopro_r0_example_app_fn_func_generate_halo2_proof(
-        FfiConverterString.lower(srsPath),
-        FfiConverterString.lower(pkPath),
-        FfiConverterDictionaryStringSequenceString.lower(circuitInputs),$0
+/**
+ * Verifies a receipt from [`rsa_prove`] or [`rsa_prove_signature`].
+ */
+public func rsaVerify(receiptBytes: Data)throws  -> Risc0VerifyRsaOutput  {
+    return try  FfiConverterTypeRisc0VerifyRsaOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_rsa_verify(
+        FfiConverterData.lower(receiptBytes),$0
     )
 })
 }
-public func generateNoirProof(circuitPath: String, srsPath: String?, inputs: [String], onChain: Bool, vk: Data, lowMemoryMode: Bool)throws  -> Data  {
-    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeMoproError_lift) {
-    uniffi_mopro_r0_example_app_fn_func_generate_noir_proof(
-        FfiConverterString.lower(circuitPath),
-        FfiConverterOptionString.lower(srsPath),
-        FfiConverterSequenceString.lower(inputs),
-        FfiConverterBool.lower(onChain),
-        FfiConverterData.lower(vk),
-        FfiConverterBool.lower(lowMemoryMode),$0
+/**
+ * Same as [`crate::risc0_prove`] with a BIP-340 signature from a freshly
+ * generated Taproot key. BIP-340 signs 32-byte messages, so the key signs
+ * and the journal commits the SHA-256 of `message`.
+ */
+public func schnorrProve(message: String, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_schnorr_prove(
+        FfiConverterString.lower(message),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
     )
 })
 }
-public func getNoirVerificationKey(circuitPath: String, srsPath: String?, onChain: Bool, lowMemoryMode: Bool)throws  -> Data  {
-    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeMoproError_lift) {
-    uniffi_mopro_r0_example_app_fn_func_get_noir_verification_key(
-        FfiConverterString.lower(circuitPath),
-        FfiConverterOptionString.lower(srsPath),
-        FfiConverterBool.lower(onChain),
-        FfiConverterBool.lower(lowMemoryMode),$0
+/**
+ * Same as [`crate::risc0_prove_signature`] for a 32-byte x-only public key
+ * and 64-byte BIP-340 signature over the raw 32-byte `message`, usually a
+ * sighash.
+ */
+public func schnorrProveSignature(publicKey: Data, message: Data, signature: Data, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_schnorr_prove_signature(
+        FfiConverterData.lower(publicKey),
+        FfiConverterData.lower(message),
+        FfiConverterData.lower(signature),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
     )
 })
 }
-public func risc0Prove(message: String)throws  -> Risc0ProofOutput  {
+/**
+ * Verifies a receipt from [`schnorr_prove`] or [`schnorr_prove_signature`].
+ *
+ * The signer reports `Risc0Curve::Bip340` with the SEC1 encodings of the
+ * even-Y point BIP-340 assigns to the x-only key, so the x-only key is
+ * `compressed[1..]`.
+ */
+public func schnorrVerify(receiptBytes: Data)throws  -> Risc0VerifyBytesOutput  {
+    return try  FfiConverterTypeRisc0VerifyBytesOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_schnorr_verify(
+        FfiConverterData.lower(receiptBytes),$0
+    )
+})
+}
+/**
+ * Same as [`crate::risc0_prove`] on secp256k1, with a freshly generated keypair.
+ */
+public func secp256k1Prove(message: String, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
     return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
-    uniffi_mopro_r0_example_app_fn_func_risc0_prove(
-        FfiConverterString.lower(message),$0
+    uniffi_mopro_r0_example_app_fn_func_secp256k1_prove(
+        FfiConverterString.lower(message),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
     )
 })
 }
-public func risc0Verify(receiptBytes: Data)throws  -> Risc0VerifyOutput  {
-    return try  FfiConverterTypeRisc0VerifyOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
-    uniffi_mopro_r0_example_app_fn_func_risc0_verify(
+/**
+ * Same as [`crate::risc0_prove_signature`] for a secp256k1 key and signature.
+ *
+ * The message is hashed with SHA-256. The signature may be `r || s` or DER.
+ */
+public func secp256k1ProveSignature(publicKey: Data, message: Data, signature: Data, receiptKind: Risc0RequestedReceiptKind)throws  -> Risc0ProofOutput  {
+    return try  FfiConverterTypeRisc0ProofOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_secp256k1_prove_signature(
+        FfiConverterData.lower(publicKey),
+        FfiConverterData.lower(message),
+        FfiConverterData.lower(signature),
+        FfiConverterTypeRisc0RequestedReceiptKind_lower(receiptKind),$0
+    )
+})
+}
+/**
+ * Verifies a receipt from [`secp256k1_prove`] or [`secp256k1_prove_signature`].
+ */
+public func secp256k1Verify(receiptBytes: Data)throws  -> Risc0VerifyBytesOutput  {
+    return try  FfiConverterTypeRisc0VerifyBytesOutput_lift(try rustCallWithError(FfiConverterTypeRisc0Error_lift) {
+    uniffi_mopro_r0_example_app_fn_func_secp256k1_verify(
         FfiConverterData.lower(receiptBytes),$0
     )
 })
@@ -1439,6 +5166,48 @@ private let initializationResult: InitializationResult = {
     if bindings_contract_version != scaffolding_contract_version {
         return InitializationResult.contractVersionMismatch
     }
+    if (uniffi_mopro_r0_example_app_checksum_func_ed25519_prove() != 55521) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_ed25519_prove_signature() != 56260) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_ed25519_verify() != 53299) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_eth_encode_typed_address() != 27483) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_eth_encode_typed_bool() != 15491) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_eth_encode_typed_bytes() != 25124) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_eth_encode_typed_string() != 30850) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_eth_encode_typed_uint() != 41024) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_eth_hash_typed_struct() != 54105) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_eth_prove_personal_sign() != 23921) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_eth_prove_typed_data() != 24941) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_eth_typed_data_domain_separator() != 62991) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_eth_verify_personal_sign() != 56823) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_eth_verify_typed_data() != 15429) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_mopro_r0_example_app_checksum_func_generate_circom_proof() != 15503) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -1451,12 +5220,168 @@ private let initializationResult: InitializationResult = {
     if (uniffi_mopro_r0_example_app_checksum_func_get_noir_verification_key() != 28810) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_mopro_r0_example_app_checksum_func_risc0_prove() != 42798) {
+    if (uniffi_mopro_r0_example_app_checksum_func_p384_prove() != 12542) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_p384_prove_signature() != 59051) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_p384_verify() != 6523) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_aggregate() != 25403) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_aggregate_root() != 24496) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_batch_root() != 13010) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_chain_message() != 56697) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_commit_public_key() != 23323) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_compress_receipt() != 30919) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_context_message() != 10569) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_generate_challenge() != 15184) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_generate_salt() != 44829) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_key_set_root() != 15125) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_open_key_commitment() != 10127) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_prove() != 18553) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_prove_batch() != 57256) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_prove_bytes() != 39424) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_prove_chain_link() != 10949) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_prove_hidden_message() != 49379) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_prove_in_context() != 62328) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_prove_key_commitment() != 65069) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_prove_membership() != 48818) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_prove_signature() != 12744) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_prove_signature_with_mode() != 59165) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_prove_threshold() != 30490) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_prove_unrevoked() != 48322) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_prove_with_challenge() != 26158) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_prove_with_validity() != 29544) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_revocation_root() != 41060) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_mopro_r0_example_app_checksum_func_risc0_verify() != 34311) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_verify_aggregate() != 57046) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_verify_batch() != 23322) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_verify_bytes() != 56509) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_verify_chain() != 31731) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_verify_hidden_message() != 10489) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_verify_in_context() != 34123) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_verify_key_commitment() != 2568) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_verify_membership() != 51982) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_verify_prehashed() != 58181) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_verify_threshold() != 55733) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_verify_unrevoked() != 23332) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_verify_with_challenge() != 6430) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_verify_with_policy() != 44135) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_risc0_verify_with_validity() != 56185) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_rsa_modulus_hash() != 55503) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_rsa_prove() != 6328) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_rsa_prove_signature() != 51727) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_rsa_verify() != 31768) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_schnorr_prove() != 54660) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_schnorr_prove_signature() != 647) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_schnorr_verify() != 54846) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_secp256k1_prove() != 24002) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_secp256k1_prove_signature() != 63216) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_mopro_r0_example_app_checksum_func_secp256k1_verify() != 26235) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_mopro_r0_example_app_checksum_func_verify_circom_proof() != 51913) {
         return InitializationResult.apiChecksumMismatch
     }
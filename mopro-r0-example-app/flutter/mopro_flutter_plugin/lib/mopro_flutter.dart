@@ -88,8 +88,10 @@ class MoproFlutter {
     });
   }
 
-  Future<Risc0ProofOutput> generateRisc0Proof(String message) async {
-    return await MoproFlutterPlatform.instance.generateRisc0Proof(message);
+  Future<Risc0ProofOutput> generateRisc0Proof(
+      String message, Risc0RequestedReceiptKind receiptKind) async {
+    return await MoproFlutterPlatform.instance
+        .generateRisc0Proof(message, receiptKind);
   }
 
   Future<Risc0VerifyOutput> verifyRisc0Proof(Uint8List receiptBytes) async {
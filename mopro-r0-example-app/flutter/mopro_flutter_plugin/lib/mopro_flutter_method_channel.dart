@@ -111,10 +111,12 @@ class MethodChannelMoproFlutter extends MoproFlutterPlatform {
   }
 
   @override
-  Future<Risc0ProofOutput> generateRisc0Proof(String message) async {
+  Future<Risc0ProofOutput> generateRisc0Proof(
+      String message, Risc0RequestedReceiptKind receiptKind) async {
     final proofResult = await methodChannel
         .invokeMethod<Map<Object?, Object?>>('generateRisc0Proof', {
       'message': message,
+      'receiptKind': receiptKind.index,
     });
 
     if (proofResult == null) {
@@ -59,7 +59,8 @@ abstract class MoproFlutterPlatform extends PlatformInterface {
     throw UnimplementedError('getNoirVerificationKey() has not been implemented.');
   }
 
-  Future<Risc0ProofOutput> generateRisc0Proof(String message) {
+  Future<Risc0ProofOutput> generateRisc0Proof(
+      String message, Risc0RequestedReceiptKind receiptKind) {
     throw UnimplementedError('generateRisc0Proof() has not been implemented.');
   }
 
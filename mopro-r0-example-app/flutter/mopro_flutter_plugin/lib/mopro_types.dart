@@ -96,42 +96,100 @@ class Halo2ProofResult {
   }
 }
 
+/// Kind of receipt to request from a RISC0 prover.
+enum Risc0RequestedReceiptKind { composite, succinct, groth16 }
+
+/// Kind of receipt produced by a RISC0 prover.
+enum Risc0ReceiptKind { composite, succinct, groth16, fake }
+
 class Risc0ProofOutput {
   final Uint8List receipt;
+  final Risc0ReceiptKind receiptKind;
+  final int sealSize;
 
-  Risc0ProofOutput(this.receipt);
+  Risc0ProofOutput(this.receipt, this.receiptKind, this.sealSize);
 
   factory Risc0ProofOutput.fromMap(Map<Object?, Object?> proofResult) {
-    return Risc0ProofOutput(proofResult["receipt"] as Uint8List);
+    return Risc0ProofOutput(
+        proofResult["receipt"] as Uint8List,
+        Risc0ReceiptKind.values.byName(proofResult["receiptKind"] as String),
+        proofResult["sealSize"] as int);
   }
 
   Map<String, dynamic> toMap() {
-    return {"receipt": receipt};
+    return {
+      "receipt": receipt,
+      "receiptKind": receiptKind.name,
+      "sealSize": sealSize
+    };
   }
 
   @override
   String toString() {
-    return "Risc0ProofOutput(receipt: ${receipt.length} bytes)";
+    return "Risc0ProofOutput(receipt: ${receipt.length} bytes, receiptKind: ${receiptKind.name}, sealSize: $sealSize)";
+  }
+}
+
+/// Elliptic curve of a proven public key.
+enum Risc0Curve { p256, secp256k1, ed25519, p384, bip340 }
+
+class Risc0PublicKey {
+  final Risc0Curve curve;
+  final Uint8List compressed;
+  final Uint8List uncompressed;
+  final String fingerprint;
+
+  Risc0PublicKey(
+      this.curve, this.compressed, this.uncompressed, this.fingerprint);
+
+  factory Risc0PublicKey.fromMap(Map<Object?, Object?> publicKey) {
+    return Risc0PublicKey(
+        Risc0Curve.values.byName(publicKey["curve"] as String),
+        publicKey["compressed"] as Uint8List,
+        publicKey["uncompressed"] as Uint8List,
+        publicKey["fingerprint"] as String);
+  }
+
+  Map<String, dynamic> toMap() {
+    return {
+      "curve": curve.name,
+      "compressed": compressed,
+      "uncompressed": uncompressed,
+      "fingerprint": fingerprint
+    };
+  }
+
+  @override
+  String toString() {
+    return "Risc0PublicKey(curve: ${curve.name}, fingerprint: $fingerprint)";
   }
 }
 
 class Risc0VerifyOutput {
   final bool isValid;
   final String verifiedMessage;
+  final Risc0PublicKey signer;
 
-  Risc0VerifyOutput(this.isValid, this.verifiedMessage);
+  Risc0VerifyOutput(this.isValid, this.verifiedMessage, this.signer);
 
   factory Risc0VerifyOutput.fromMap(Map<Object?, Object?> verifyResult) {
     return Risc0VerifyOutput(
-        verifyResult["isValid"] as bool, verifyResult["verifiedMessage"] as String);
+        verifyResult["isValid"] as bool,
+        verifyResult["verifiedMessage"] as String,
+        Risc0PublicKey.fromMap(
+            verifyResult["signer"] as Map<Object?, Object?>));
   }
 
   Map<String, dynamic> toMap() {
-    return {"isValid": isValid, "verifiedMessage": verifiedMessage};
+    return {
+      "isValid": isValid,
+      "verifiedMessage": verifiedMessage,
+      "signer": signer.toMap()
+    };
   }
 
   @override
   String toString() {
-    return "Risc0VerifyOutput(isValid: $isValid, verifiedMessage: $verifiedMessage)";
+    return "Risc0VerifyOutput(isValid: $isValid, verifiedMessage: $verifiedMessage, signer: $signer)";
   }
 }
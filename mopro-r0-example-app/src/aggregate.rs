@@ -0,0 +1,195 @@
+//! Recursive aggregation of [`crate::risc0_prove`] receipts into one receipt.
+//!
+//! The aggregator guest calls `env::verify` on each inner journal. The host
+//! adds the inner receipts as assumptions and proves with succinct options,
+//! which resolves them, so verifiers check one receipt instead of N. The
+//! result can then be compressed to Groth16.
+
+use ecdsa_methods::{ECDSA_AGGREGATE_ELF, ECDSA_AGGREGATE_ID, ECDSA_VERIFY_ID};
+use p256::EncodedPoint;
+use risc0_zkvm::Journal;
+
+use crate::{
+    decode_journal_key, ecdsa_verify_bytes_output, merkle, prove_with_assumptions, verify_receipt,
+    Risc0Error, Risc0ProofOutput, Risc0RequestedReceiptKind, Risc0VerifyBytesOutput,
+};
+
+/// Verify output for aggregated receipts.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0VerifyAggregateOutput {
+    pub is_valid: bool,
+    /// Number of inner receipts folded into the aggregate.
+    pub count: u32,
+    /// Merkle root over the inner journals, as returned by [`risc0_aggregate_root`].
+    pub root: Vec<u8>,
+    /// The inner statements in input order, or empty if they were not committed.
+    pub entries: Vec<Risc0VerifyBytesOutput>,
+}
+
+/// Returns the Merkle root over the journals of `receipts`, as committed by
+/// [`risc0_aggregate`]. Receipts must be in the order they were aggregated.
+#[uniffi::export]
+pub fn risc0_aggregate_root(receipts: Vec<Vec<u8>>) -> Result<Vec<u8>, Risc0Error> {
+    let leaves = receipts
+        .iter()
+        .map(|receipt_bytes| {
+            let receipt = verify_receipt(receipt_bytes, ECDSA_VERIFY_ID)?;
+            Ok(merkle::hash_leaf(&receipt.journal.bytes))
+        })
+        .collect::<Result<Vec<_>, Risc0Error>>()?;
+
+    Ok(merkle::MerkleTree::from_leaves(leaves, merkle::EMPTY_LEAF).root().to_vec())
+}
+
+/// Folds receipts from [`crate::risc0_prove`] into a single succinct or Groth16
+/// receipt.
+///
+/// Every inner receipt is verified on the host first. The journal commits the
+/// inner image ID, the number of receipts and the Merkle root over their
+/// journals; with `commit_journals` it also commits every inner journal.
+#[uniffi::export]
+pub fn risc0_aggregate(
+    receipts: Vec<Vec<u8>>,
+    commit_journals: bool,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    if receipts.is_empty() {
+        return Err(Risc0Error::InputError("No receipts to aggregate".to_string()));
+    }
+
+    let mut journals = Vec::with_capacity(receipts.len());
+    let mut assumptions = Vec::with_capacity(receipts.len());
+    for (index, receipt_bytes) in receipts.iter().enumerate() {
+        let receipt = verify_receipt(receipt_bytes, ECDSA_VERIFY_ID).map_err(|e| {
+            Risc0Error::InputError(format!("Receipt {} is not a valid ECDSA receipt: {}", index, e))
+        })?;
+        journals.push(receipt.journal.bytes.clone());
+        assumptions.push(receipt);
+    }
+
+    // Create input for zkVM (inner image ID, inner journals, commit journals)
+    let input = (ECDSA_VERIFY_ID, journals, commit_journals);
+    prove_with_assumptions(ECDSA_AGGREGATE_ELF, &input, assumptions, receipt_kind)
+}
+
+/// Verifies a receipt from [`risc0_aggregate`] and returns the committed
+/// count, root and, if present, inner statements.
+///
+/// The receipt must aggregate `risc0_prove` receipts; aggregates over any
+/// other guest fail with a `VerifyError`.
+#[uniffi::export]
+pub fn risc0_verify_aggregate(
+    receipt_bytes: Vec<u8>,
+) -> Result<Risc0VerifyAggregateOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, ECDSA_AGGREGATE_ID)?;
+
+    let (image_id, count, root, journals): ([u32; 8], u32, [u8; 32], Vec<Vec<u8>>) = receipt
+        .journal
+        .decode()
+        .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+    if image_id != ECDSA_VERIFY_ID {
+        return Err(Risc0Error::VerifyError(
+            "Aggregate is not over ECDSA receipts".to_string(),
+        ));
+    }
+
+    let entries = journals
+        .into_iter()
+        .map(|journal| {
+            let (encoded_verifying_key, message): (EncodedPoint, Vec<u8>) = Journal::new(journal)
+                .decode()
+                .map_err(|e| {
+                    Risc0Error::DecodeError(format!("Failed to decode inner journal: {}", e))
+                })?;
+            let verifying_key = decode_journal_key(&encoded_verifying_key)?;
+            Ok(ecdsa_verify_bytes_output(&verifying_key, message))
+        })
+        .collect::<Result<Vec<_>, Risc0Error>>()?;
+
+    Ok(Risc0VerifyAggregateOutput {
+        is_valid: true,
+        count,
+        root: root.to_vec(),
+        entries,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ecdsa_methods::SECP256K1_VERIFY_ID;
+
+    #[test]
+    fn test_aggregate_verify_roundtrip() {
+        let messages = ["First", "Second", "Third"];
+        let receipts: Vec<Vec<u8>> = messages
+            .iter()
+            .map(|message| {
+                crate::risc0_prove(message.to_string(), Risc0RequestedReceiptKind::Composite)
+                    .expect("Proving should succeed")
+                    .receipt
+            })
+            .collect();
+
+        let proof_output =
+            risc0_aggregate(receipts.clone(), true, Risc0RequestedReceiptKind::Succinct)
+                .expect("Aggregation should succeed");
+
+        let verify_output =
+            risc0_verify_aggregate(proof_output.receipt).expect("Verification should succeed");
+        assert!(verify_output.is_valid, "Proof should be valid");
+        assert_eq!(verify_output.count, 3);
+        assert_eq!(verify_output.root, risc0_aggregate_root(receipts.clone()).unwrap());
+        let aggregated_messages: Vec<Option<String>> = verify_output
+            .entries
+            .into_iter()
+            .map(|entry| entry.message_text)
+            .collect();
+        assert_eq!(
+            aggregated_messages,
+            messages.iter().map(|message| Some(message.to_string())).collect::<Vec<_>>()
+        );
+
+        // Composite receipts cannot resolve the inner receipts
+        let result = risc0_aggregate(receipts, true, Risc0RequestedReceiptKind::Composite);
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+    }
+
+    #[test]
+    fn test_aggregate_rejects_other_receipts() {
+        let receipt = crate::secp256k1::secp256k1_prove(
+            "Not P-256".to_string(),
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed")
+        .receipt;
+
+        let result = risc0_aggregate(vec![receipt], false, Risc0RequestedReceiptKind::Succinct);
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+        let result = risc0_aggregate(Vec::new(), false, Risc0RequestedReceiptKind::Succinct);
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+    }
+
+    #[test]
+    fn test_verify_aggregate_rejects_other_image() {
+        // Aggregate secp256k1 receipts by calling the aggregator guest directly
+        let receipt = crate::secp256k1::secp256k1_prove(
+            "Not P-256".to_string(),
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed")
+        .receipt;
+        let receipt = verify_receipt(&receipt, SECP256K1_VERIFY_ID).unwrap();
+        let input = (SECP256K1_VERIFY_ID, vec![receipt.journal.bytes.clone()], false);
+        let proof_output = prove_with_assumptions(
+            ECDSA_AGGREGATE_ELF,
+            &input,
+            vec![receipt],
+            Risc0RequestedReceiptKind::Succinct,
+        )
+        .expect("Proving should succeed");
+
+        let result = risc0_verify_aggregate(proof_output.receipt);
+        assert!(matches!(result, Err(Risc0Error::VerifyError(_))));
+    }
+}
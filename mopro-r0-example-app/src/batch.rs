@@ -0,0 +1,191 @@
+//! Batch proofs: many signatures verified in a single guest execution, with
+//! one Merkle root over the entries in the journal.
+
+use ecdsa_core::BatchJournal;
+use ecdsa_methods::{ECDSA_VERIFY_BATCH_ELF, ECDSA_VERIFY_BATCH_ID};
+
+use crate::merkle::{self, EMPTY_LEAF, MerkleTree};
+use crate::{
+    decode_journal_key, parse_public_keys, parse_signed_message, prove_input, signer_public_key,
+    verify_receipt, Risc0Error, Risc0ProofOutput, Risc0PublicKey, Risc0RequestedReceiptKind,
+};
+
+/// One signed message of a batch to prove.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0BatchEntry {
+    /// SEC1-encoded secp256r1 public key.
+    pub public_key: Vec<u8>,
+    pub message: Vec<u8>,
+    /// `r || s` or DER-encoded ECDSA signature.
+    pub signature: Vec<u8>,
+}
+
+/// A verified batch entry, present when the batch was proven with its entries.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0VerifiedBatchEntry {
+    pub signer: Risc0PublicKey,
+    pub message: Vec<u8>,
+}
+
+/// Verify output for batch proofs.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0VerifyBatchOutput {
+    pub is_valid: bool,
+    /// Number of signatures verified in the batch.
+    pub count: u32,
+    /// Merkle root over the `(key, SHA-256 of message)` leaves, in batch order.
+    pub root: Vec<u8>,
+    /// The entries in batch order, or empty if they were not committed.
+    pub entries: Vec<Risc0VerifiedBatchEntry>,
+}
+
+/// Returns the Merkle root over `(public key, message)` pairs, as committed by
+/// [`risc0_prove_batch`]. Pairs must be in batch order.
+#[uniffi::export]
+pub fn risc0_batch_root(
+    public_keys: Vec<Vec<u8>>,
+    messages: Vec<Vec<u8>>,
+) -> Result<Vec<u8>, Risc0Error> {
+    if public_keys.len() != messages.len() {
+        return Err(Risc0Error::InputError(format!(
+            "Got {} public keys for {} messages",
+            public_keys.len(),
+            messages.len()
+        )));
+    }
+    let keys = parse_public_keys(&public_keys)?;
+    let leaves = keys
+        .iter()
+        .zip(&messages)
+        .map(|(key, message)| merkle::batch_leaf(key, message))
+        .collect();
+
+    Ok(MerkleTree::from_leaves(leaves, EMPTY_LEAF).root().to_vec())
+}
+
+/// Proves all `entries` in a single guest execution, returning one receipt.
+///
+/// The journal commits the number of entries and the Merkle root over their
+/// `(key, SHA-256 of message)` leaves. With `commit_entries` it also commits
+/// every key and message, which grows the journal with the batch.
+#[uniffi::export]
+pub fn risc0_prove_batch(
+    entries: Vec<Risc0BatchEntry>,
+    commit_entries: bool,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    if entries.is_empty() {
+        return Err(Risc0Error::InputError("Batch is empty".to_string()));
+    }
+    if u32::try_from(entries.len()).is_err() {
+        return Err(Risc0Error::InputError("Batch is too large".to_string()));
+    }
+
+    let entries = entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let (verifying_key, signature) =
+                parse_signed_message(&entry.public_key, &entry.message, &entry.signature)
+                    .map_err(|e| match e {
+                        Risc0Error::InputError(msg) => {
+                            Risc0Error::InputError(format!("Entry {}: {}", index, msg))
+                        }
+                        e => e,
+                    })?;
+            Ok((verifying_key.to_encoded_point(true), entry.message.clone(), signature))
+        })
+        .collect::<Result<Vec<_>, Risc0Error>>()?;
+
+    // Create input for zkVM (entries, commit entries)
+    prove_input(ECDSA_VERIFY_BATCH_ELF, &(entries, commit_entries), receipt_kind)
+}
+
+/// Verifies a receipt from [`risc0_prove_batch`] and returns the committed
+/// count, root and, if present, entries.
+///
+/// Without committed entries, compare the root with [`risc0_batch_root`] over
+/// the expected keys and messages.
+#[uniffi::export]
+pub fn risc0_verify_batch(receipt_bytes: Vec<u8>) -> Result<Risc0VerifyBatchOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, ECDSA_VERIFY_BATCH_ID)?;
+
+    let journal: BatchJournal = receipt
+        .journal
+        .decode()
+        .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+    let entries = journal
+        .entries
+        .into_iter()
+        .map(|(encoded_verifying_key, message)| {
+            let verifying_key = decode_journal_key(&encoded_verifying_key)?;
+            Ok(Risc0VerifiedBatchEntry {
+                signer: signer_public_key(&verifying_key),
+                message,
+            })
+        })
+        .collect::<Result<Vec<_>, Risc0Error>>()?;
+
+    Ok(Risc0VerifyBatchOutput {
+        is_valid: true,
+        count: journal.count,
+        root: journal.root.to_vec(),
+        entries,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_key, sign};
+
+    #[test]
+    fn test_prove_verify_batch() {
+        let mut entries: Vec<Risc0BatchEntry> = (0..3)
+            .map(|index| {
+                let (signing_key, public_key) = random_key();
+                let message = format!("Batch message {}", index).into_bytes();
+                Risc0BatchEntry {
+                    public_key,
+                    signature: sign(&signing_key, &message),
+                    message,
+                }
+            })
+            .collect();
+        let expected_root = risc0_batch_root(
+            entries.iter().map(|entry| entry.public_key.clone()).collect(),
+            entries.iter().map(|entry| entry.message.clone()).collect(),
+        )
+        .unwrap();
+
+        for commit_entries in [false, true] {
+            let proof_output = risc0_prove_batch(
+                entries.clone(),
+                commit_entries,
+                Risc0RequestedReceiptKind::Composite,
+            )
+            .expect("Proving should succeed for a valid batch");
+
+            let verify_output =
+                risc0_verify_batch(proof_output.receipt).expect("Verification should succeed");
+            assert!(verify_output.is_valid, "Proof should be valid");
+            assert_eq!(verify_output.count, 3);
+            assert_eq!(verify_output.root, expected_root);
+            if commit_entries {
+                assert_eq!(verify_output.entries.len(), 3);
+                assert_eq!(verify_output.entries[2].message, entries[2].message);
+                assert_eq!(verify_output.entries[2].signer.compressed, entries[2].public_key);
+            } else {
+                assert!(verify_output.entries.is_empty());
+            }
+        }
+
+        // One bad signature fails the whole batch
+        entries[1].message = b"tampered".to_vec();
+        let result = risc0_prove_batch(entries, false, Risc0RequestedReceiptKind::Composite);
+        assert!(matches!(result, Err(Risc0Error::InputError(msg)) if msg.starts_with("Entry 1:")));
+
+        let result = risc0_prove_batch(Vec::new(), false, Risc0RequestedReceiptKind::Composite);
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+    }
+}
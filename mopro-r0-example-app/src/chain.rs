@@ -0,0 +1,196 @@
+//! Signature chains: IVC-style proofs that one key signed an unbroken sequence
+//! of messages, each starting with the SHA-256 head of the previous one.
+//!
+//! Each link verifies the previous link's receipt with `env::verify`, so the
+//! latest receipt alone attests to the whole chain without reproving it.
+
+use ecdsa_methods::{ECDSA_CHAIN_ELF, ECDSA_CHAIN_ID};
+use p256::EncodedPoint;
+use risc0_zkvm::Receipt;
+
+use crate::{
+    decode_journal_key, parse_bytes32, parse_signed_message, prove_with_assumptions,
+    signer_public_key, verify_receipt, Risc0Error, Risc0ProofOutput, Risc0PublicKey,
+    Risc0RequestedReceiptKind,
+};
+
+/// Head that the first message of a chain links to.
+const GENESIS_HEAD: [u8; 32] = [0u8; 32];
+
+/// Journal of a chain link: image ID, compressed key, counter and head.
+type ChainJournal = ([u32; 8], EncodedPoint, u64, [u8; 32]);
+
+/// Verify output for signature chain proofs.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0VerifyChainOutput {
+    pub is_valid: bool,
+    /// Key that signed every message of the chain.
+    pub signer: Risc0PublicKey,
+    /// Number of messages in the chain.
+    pub counter: u64,
+    /// SHA-256 of the latest message, which the next message must start with.
+    pub head: Vec<u8>,
+}
+
+/// Decodes a chain link journal and checks it was produced by the chain guest.
+fn decode_chain_journal(receipt: &Receipt) -> Result<ChainJournal, Risc0Error> {
+    let journal: ChainJournal = receipt
+        .journal
+        .decode()
+        .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+    if journal.0 != ECDSA_CHAIN_ID {
+        return Err(Risc0Error::DecodeError(
+            "Chain link commits a different image ID".to_string(),
+        ));
+    }
+    Ok(journal)
+}
+
+/// Builds the next message of a chain: `previous_head || payload`.
+///
+/// Pass the `head` of the latest link, or `None` to start a new chain.
+#[uniffi::export]
+pub fn risc0_chain_message(
+    previous_head: Option<Vec<u8>>,
+    payload: Vec<u8>,
+) -> Result<Vec<u8>, Risc0Error> {
+    let previous_head = match previous_head {
+        Some(head) => parse_bytes32(&head, "Chain head")?,
+        None => GENESIS_HEAD,
+    };
+    Ok([previous_head.as_slice(), &payload].concat())
+}
+
+/// Proves the next link of a signature chain.
+///
+/// Without `previous_receipt` this starts a new chain, and `message` must start
+/// with 32 zero bytes. Otherwise `previous_receipt` must be the latest link,
+/// signed by the same key, and `message` must start with its head; see
+/// [`risc0_chain_message`]. The result is a succinct or Groth16 receipt whose
+/// size does not grow with the chain; only a succinct link can be extended.
+#[uniffi::export]
+pub fn risc0_prove_chain_link(
+    previous_receipt: Option<Vec<u8>>,
+    public_key: Vec<u8>,
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let (verifying_key, signature) = parse_signed_message(&public_key, &message, &signature)?;
+    let encoded_verifying_key = verifying_key.to_encoded_point(true);
+
+    let (previous_journal, previous_head, assumptions) = match previous_receipt {
+        Some(previous_receipt) => {
+            let previous_receipt = verify_receipt(&previous_receipt, ECDSA_CHAIN_ID)?;
+            let (_, previous_key, _, previous_head) = decode_chain_journal(&previous_receipt)?;
+            if previous_key != encoded_verifying_key {
+                return Err(Risc0Error::SignerMismatchError(
+                    "Chain was signed by a different key".to_string(),
+                ));
+            }
+            (
+                Some(previous_receipt.journal.bytes.clone()),
+                previous_head,
+                vec![previous_receipt],
+            )
+        }
+        None => (None, GENESIS_HEAD, Vec::new()),
+    };
+    if !message.starts_with(&previous_head) {
+        return Err(Risc0Error::InputError(
+            "Message does not start with the chain head".to_string(),
+        ));
+    }
+
+    // Create input for zkVM (image ID, previous journal, public key, message, signature)
+    let input = (
+        ECDSA_CHAIN_ID,
+        previous_journal,
+        encoded_verifying_key,
+        message,
+        signature,
+    );
+    prove_with_assumptions(ECDSA_CHAIN_ELF, &input, assumptions, receipt_kind)
+}
+
+/// Verifies a receipt from [`risc0_prove_chain_link`] and returns the chain's
+/// signer, length and head.
+#[uniffi::export]
+pub fn risc0_verify_chain(receipt_bytes: Vec<u8>) -> Result<Risc0VerifyChainOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, ECDSA_CHAIN_ID)?;
+    let (_, encoded_verifying_key, counter, head) = decode_chain_journal(&receipt)?;
+    let verifying_key = decode_journal_key(&encoded_verifying_key)?;
+
+    Ok(Risc0VerifyChainOutput {
+        is_valid: true,
+        signer: signer_public_key(&verifying_key),
+        counter,
+        head: head.to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_key, sign};
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn test_prove_verify_chain() {
+        let (signing_key, public_key) = random_key();
+
+        // First link
+        let first_message = risc0_chain_message(None, b"reading=17".to_vec()).unwrap();
+        let first_link = risc0_prove_chain_link(
+            None,
+            public_key.clone(),
+            first_message.clone(),
+            sign(&signing_key, &first_message),
+            Risc0RequestedReceiptKind::Succinct,
+        )
+        .expect("Proving the first link should succeed");
+        let first_output =
+            risc0_verify_chain(first_link.receipt.clone()).expect("Verification should succeed");
+        assert_eq!(first_output.counter, 1);
+        assert_eq!(first_output.head, Sha256::digest(&first_message).to_vec());
+
+        // Second link, extending the first
+        let second_message =
+            risc0_chain_message(Some(first_output.head), b"reading=18".to_vec()).unwrap();
+        let second_link = risc0_prove_chain_link(
+            Some(first_link.receipt.clone()),
+            public_key.clone(),
+            second_message.clone(),
+            sign(&signing_key, &second_message),
+            Risc0RequestedReceiptKind::Succinct,
+        )
+        .expect("Proving the second link should succeed");
+        let second_output =
+            risc0_verify_chain(second_link.receipt).expect("Verification should succeed");
+        assert!(second_output.is_valid, "Proof should be valid");
+        assert_eq!(second_output.counter, 2);
+        assert_eq!(second_output.head, Sha256::digest(&second_message).to_vec());
+        assert_eq!(second_output.signer.compressed, public_key);
+
+        // A message that does not extend the head breaks the chain
+        let result = risc0_prove_chain_link(
+            Some(first_link.receipt.clone()),
+            public_key,
+            b"reading=19".to_vec(),
+            sign(&signing_key, b"reading=19"),
+            Risc0RequestedReceiptKind::Succinct,
+        );
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+
+        // Another key cannot continue the chain
+        let (other_key, other_public_key) = random_key();
+        let result = risc0_prove_chain_link(
+            Some(first_link.receipt),
+            other_public_key,
+            second_message.clone(),
+            sign(&other_key, &second_message),
+            Risc0RequestedReceiptKind::Succinct,
+        );
+        assert!(matches!(result, Err(Risc0Error::SignerMismatchError(_))));
+    }
+}
@@ -0,0 +1,137 @@
+//! Proofs bound to a verifier-issued challenge, so receipts cannot be
+//! replayed to another verifier.
+
+use ecdsa_methods::{ECDSA_VERIFY_CHALLENGE_ELF, ECDSA_VERIFY_CHALLENGE_ID};
+use p256::EncodedPoint;
+use rand_core::{OsRng, RngCore};
+
+use crate::{
+    decode_journal_key, ecdsa_verify_bytes_output, parse_signed_message, prove_input,
+    verify_receipt, Risc0Error, Risc0ProofOutput, Risc0RequestedReceiptKind,
+    Risc0VerifyBytesOutput,
+};
+
+/// Returns a fresh 32-byte challenge for a prover to bind its receipt to.
+#[uniffi::export]
+pub fn risc0_generate_challenge() -> Vec<u8> {
+    let mut challenge = [0u8; 32];
+    OsRng.fill_bytes(&mut challenge);
+    challenge.to_vec()
+}
+
+/// Proves a signature bound to a verifier-supplied `challenge`, so the receipt
+/// cannot be replayed to a verifier that issued a different one.
+///
+/// With `require_challenge_in_message`, the guest also checks that the signed
+/// message contains the challenge, which rules out signatures computed before
+/// the challenge was issued.
+#[uniffi::export]
+pub fn risc0_prove_with_challenge(
+    public_key: Vec<u8>,
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    challenge: Vec<u8>,
+    require_challenge_in_message: bool,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let (verifying_key, signature) = parse_signed_message(&public_key, &message, &signature)?;
+
+    if challenge.is_empty() {
+        return Err(Risc0Error::InputError("Challenge must not be empty".to_string()));
+    }
+    if require_challenge_in_message
+        && !message
+            .windows(challenge.len())
+            .any(|window| window == &challenge[..])
+    {
+        return Err(Risc0Error::InputError(
+            "Signed message does not contain the challenge".to_string(),
+        ));
+    }
+
+    // Create input for zkVM (public key, message, signature, challenge, challenge in message)
+    let input = (
+        verifying_key.to_encoded_point(true),
+        message,
+        signature,
+        challenge,
+        require_challenge_in_message,
+    );
+    prove_input(ECDSA_VERIFY_CHALLENGE_ELF, &input, receipt_kind)
+}
+
+/// Verifies a receipt from [`risc0_prove_with_challenge`] and checks that it
+/// was bound to `expected_challenge`.
+///
+/// With `require_challenge_in_message`, receipts whose signed message was not
+/// checked to contain the challenge are rejected too. Both cases fail with
+/// [`Risc0Error::ChallengeMismatchError`].
+#[uniffi::export]
+pub fn risc0_verify_with_challenge(
+    receipt_bytes: Vec<u8>,
+    expected_challenge: Vec<u8>,
+    require_challenge_in_message: bool,
+) -> Result<Risc0VerifyBytesOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, ECDSA_VERIFY_CHALLENGE_ID)?;
+
+    let (receipt_verifying_key, message, challenge, challenge_in_message): (
+        EncodedPoint,
+        Vec<u8>,
+        Vec<u8>,
+        bool,
+    ) = receipt
+        .journal
+        .decode()
+        .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+    let verifying_key = decode_journal_key(&receipt_verifying_key)?;
+
+    if challenge != expected_challenge {
+        return Err(Risc0Error::ChallengeMismatchError(
+            "Receipt is bound to a different challenge".to_string(),
+        ));
+    }
+    if require_challenge_in_message && !challenge_in_message {
+        return Err(Risc0Error::ChallengeMismatchError(
+            "Signed message was not required to contain the challenge".to_string(),
+        ));
+    }
+
+    Ok(ecdsa_verify_bytes_output(&verifying_key, message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_key, sign};
+
+    #[test]
+    fn test_prove_verify_with_challenge() {
+        let (signing_key, public_key) = random_key();
+        let challenge = risc0_generate_challenge();
+
+        // The signed payload embeds the verifier's challenge
+        let mut message = b"login:".to_vec();
+        message.extend_from_slice(&challenge);
+
+        let proof_output = risc0_prove_with_challenge(
+            public_key,
+            message.clone(),
+            sign(&signing_key, &message),
+            challenge.clone(),
+            true,
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed");
+
+        let verify_output =
+            risc0_verify_with_challenge(proof_output.receipt.clone(), challenge, true)
+                .expect("Verification should succeed for the issued challenge");
+        assert!(verify_output.is_valid, "Proof should be valid");
+        assert_eq!(verify_output.message, message);
+
+        // Replaying the receipt to a verifier with another challenge fails
+        let result =
+            risc0_verify_with_challenge(proof_output.receipt, risc0_generate_challenge(), false);
+        assert!(matches!(result, Err(Risc0Error::ChallengeMismatchError(_))));
+    }
+}
@@ -0,0 +1,163 @@
+//! Proofs bound to an application context string, so receipts made for one
+//! application cannot be reused by another.
+
+use ecdsa_core::{CONTEXT_SEPARATOR, is_context_prefixed};
+use ecdsa_methods::{ECDSA_VERIFY_CONTEXT_ELF, ECDSA_VERIFY_CONTEXT_ID};
+use p256::EncodedPoint;
+
+use crate::{
+    decode_journal_key, ecdsa_verify_bytes_output, parse_signed_message, prove_input,
+    verify_receipt, Risc0Error, Risc0ProofOutput, Risc0RequestedReceiptKind,
+    Risc0VerifyBytesOutput,
+};
+
+/// Builds a message bound to `context`: `context || 0x00 || payload`.
+///
+/// The context must not contain a zero byte.
+#[uniffi::export]
+pub fn risc0_context_message(context: String, payload: Vec<u8>) -> Result<Vec<u8>, Risc0Error> {
+    if context.as_bytes().contains(&CONTEXT_SEPARATOR) {
+        return Err(Risc0Error::InputError(
+            "Context must not contain a zero byte".to_string(),
+        ));
+    }
+    Ok([context.as_bytes(), &[CONTEXT_SEPARATOR], &payload].concat())
+}
+
+/// Proves a signature bound to an application `context` (e.g.
+/// `"com.example.wallet/login/v1"`), so the receipt cannot be reused by
+/// another application.
+///
+/// With `require_context_prefix`, the guest also checks that the signed
+/// message starts with the context followed by a zero byte, so signatures
+/// made for one domain cannot be proven in another; see
+/// [`risc0_context_message`].
+#[uniffi::export]
+pub fn risc0_prove_in_context(
+    context: String,
+    public_key: Vec<u8>,
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    require_context_prefix: bool,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let (verifying_key, signature) = parse_signed_message(&public_key, &message, &signature)?;
+
+    if require_context_prefix && !is_context_prefixed(&message, &context) {
+        return Err(Risc0Error::InputError(
+            "Signed message is not prefixed with the context".to_string(),
+        ));
+    }
+
+    // Create input for zkVM (context, public key, message, signature, context prefix)
+    let input = (
+        context,
+        verifying_key.to_encoded_point(true),
+        message,
+        signature,
+        require_context_prefix,
+    );
+    prove_input(ECDSA_VERIFY_CONTEXT_ELF, &input, receipt_kind)
+}
+
+/// Verifies a receipt from [`risc0_prove_in_context`] and checks that it was
+/// made for `expected_context`.
+///
+/// With `require_context_prefix`, receipts whose message was not checked to
+/// start with the context are rejected too. Both cases fail with
+/// [`Risc0Error::ContextMismatchError`].
+#[uniffi::export]
+pub fn risc0_verify_in_context(
+    receipt_bytes: Vec<u8>,
+    expected_context: String,
+    require_context_prefix: bool,
+) -> Result<Risc0VerifyBytesOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, ECDSA_VERIFY_CONTEXT_ID)?;
+
+    let (context, receipt_verifying_key, message, context_prefixed): (
+        String,
+        EncodedPoint,
+        Vec<u8>,
+        bool,
+    ) = receipt
+        .journal
+        .decode()
+        .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+    let verifying_key = decode_journal_key(&receipt_verifying_key)?;
+
+    if context != expected_context {
+        return Err(Risc0Error::ContextMismatchError(format!(
+            "Receipt was made for context {:?}",
+            context
+        )));
+    }
+    if require_context_prefix && !context_prefixed {
+        return Err(Risc0Error::ContextMismatchError(
+            "Signed message was not required to start with the context".to_string(),
+        ));
+    }
+
+    Ok(ecdsa_verify_bytes_output(&verifying_key, message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_key, sign};
+
+    #[test]
+    fn test_prove_verify_in_context() {
+        let (signing_key, public_key) = random_key();
+        let context = "com.example.wallet/login/v1".to_string();
+        let message = risc0_context_message(context.clone(), b"nonce=42".to_vec()).unwrap();
+        let signature = sign(&signing_key, &message);
+
+        let proof_output = risc0_prove_in_context(
+            context.clone(),
+            public_key.clone(),
+            message.clone(),
+            signature.clone(),
+            true,
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed");
+
+        let verify_output = risc0_verify_in_context(proof_output.receipt.clone(), context, true)
+            .expect("Verification should succeed for the expected context");
+        assert!(verify_output.is_valid, "Proof should be valid");
+        assert_eq!(verify_output.message, message);
+
+        let result = risc0_verify_in_context(
+            proof_output.receipt,
+            "com.example.other-app/login/v1".to_string(),
+            false,
+        );
+        assert!(matches!(result, Err(Risc0Error::ContextMismatchError(_))));
+
+        // A message signed for another domain cannot be proven with the prefix check,
+        // even when that domain's context starts with this one
+        for other_context in ["com.example.other-app/login/v1", "com.example.wallet/login"] {
+            let result = risc0_prove_in_context(
+                other_context.to_string(),
+                public_key.clone(),
+                message.clone(),
+                signature.clone(),
+                true,
+                Risc0RequestedReceiptKind::Composite,
+            );
+            assert!(matches!(result, Err(Risc0Error::InputError(_))));
+        }
+    }
+
+    #[test]
+    fn test_context_message() {
+        let message = risc0_context_message("app/v1".to_string(), b"payload".to_vec()).unwrap();
+        assert_eq!(message, b"app/v1\0payload");
+        assert!(is_context_prefixed(&message, "app/v1"));
+        assert!(!is_context_prefixed(&message, "app/"));
+        assert!(!is_context_prefixed(b"app/v1payload", "app/v1"));
+
+        let result = risc0_context_message("app\0v1".to_string(), Vec::new());
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+    }
+}
@@ -0,0 +1,165 @@
+//! Ed25519 proofs, for keys held by SSH agents, Solana wallets and other
+//! EdDSA signers.
+//!
+//! The guest verifies with `verify_strict` and commits `(32-byte key, message)`.
+
+use ecdsa_methods::{ED25519_VERIFY_ELF, ED25519_VERIFY_ID};
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use rand_core::OsRng;
+
+use crate::{
+    prove_input, public_key_record, verify_receipt, Risc0Curve, Risc0Error, Risc0ProofOutput,
+    Risc0RequestedReceiptKind, Risc0VerifyBytesOutput,
+};
+
+/// Proves an Ed25519 signature over `message` inside the Ed25519 guest.
+fn prove_ed25519(
+    verifying_key: &VerifyingKey,
+    message: &[u8],
+    signature: &Signature,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    // Create input for zkVM (public key, message, signature)
+    let input = (
+        verifying_key.to_bytes(),
+        message,
+        signature.to_bytes().to_vec(),
+    );
+    prove_input(ED25519_VERIFY_ELF, &input, receipt_kind)
+}
+
+/// Same as [`crate::risc0_prove`] on Ed25519, with a freshly generated keypair.
+#[uniffi::export]
+pub fn ed25519_prove(
+    message: String,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let signing_key = SigningKey::generate(&mut OsRng);
+    let signature = signing_key.sign(message.as_bytes());
+
+    prove_ed25519(
+        &signing_key.verifying_key(),
+        message.as_bytes(),
+        &signature,
+        receipt_kind,
+    )
+}
+
+/// Same as [`crate::risc0_prove_signature`] for a 32-byte Ed25519 public key
+/// and 64-byte signature.
+///
+/// The signature is checked strictly, as the guest does, so weak keys and
+/// non-canonical signatures are rejected before proving.
+#[uniffi::export]
+pub fn ed25519_prove_signature(
+    public_key: Vec<u8>,
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let public_key: [u8; 32] = public_key.as_slice().try_into().map_err(|_| {
+        Risc0Error::InputError(format!(
+            "Invalid public key: expected 32 bytes, got {}",
+            public_key.len()
+        ))
+    })?;
+    let verifying_key = VerifyingKey::from_bytes(&public_key)
+        .map_err(|e| Risc0Error::InputError(format!("Invalid public key: {}", e)))?;
+    let signature = Signature::from_slice(&signature)
+        .map_err(|e| Risc0Error::InputError(format!("Invalid signature: {}", e)))?;
+
+    verifying_key
+        .verify_strict(&message, &signature)
+        .map_err(|e| Risc0Error::InputError(format!("Signature does not verify: {}", e)))?;
+
+    prove_ed25519(&verifying_key, &message, &signature, receipt_kind)
+}
+
+/// Verifies a receipt from [`ed25519_prove`] or [`ed25519_prove_signature`].
+#[uniffi::export]
+pub fn ed25519_verify(receipt_bytes: Vec<u8>) -> Result<Risc0VerifyBytesOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, ED25519_VERIFY_ID)?;
+
+    let (receipt_verifying_key, message): ([u8; 32], Vec<u8>) = receipt
+        .journal
+        .decode()
+        .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+    let message_text = String::from_utf8(message.clone()).ok();
+
+    Ok(Risc0VerifyBytesOutput {
+        is_valid: true,
+        message,
+        message_text,
+        signer: public_key_record(
+            Risc0Curve::Ed25519,
+            receipt_verifying_key.to_vec(),
+            receipt_verifying_key.to_vec(),
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ed25519_prove_verify_roundtrip() {
+        let message = "Hello, Ed25519!".to_string();
+        let proof_output = ed25519_prove(message.clone(), Risc0RequestedReceiptKind::Composite)
+            .expect("Proving should succeed");
+
+        let verify_output =
+            ed25519_verify(proof_output.receipt.clone()).expect("Verification should succeed");
+        assert!(verify_output.is_valid, "Proof should be valid");
+        assert_eq!(verify_output.message_text, Some(message));
+        assert_eq!(verify_output.signer.curve, Risc0Curve::Ed25519);
+        assert_eq!(verify_output.signer.compressed.len(), 32);
+
+        // An Ed25519 receipt is not a secp256r1 one
+        assert!(crate::risc0_verify(proof_output.receipt).is_err());
+    }
+
+    #[test]
+    fn test_ed25519_prove_signature() {
+        let signing_key = SigningKey::generate(&mut OsRng);
+        let message = b"Signed by an SSH agent".to_vec();
+        let signature = signing_key.sign(&message);
+        let public_key = signing_key.verifying_key().to_bytes().to_vec();
+
+        let proof_output = ed25519_prove_signature(
+            public_key.clone(),
+            message.clone(),
+            signature.to_bytes().to_vec(),
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed for an external signature");
+
+        let verify_output =
+            ed25519_verify(proof_output.receipt).expect("Verification should succeed");
+        assert_eq!(verify_output.message, message);
+        assert_eq!(verify_output.signer.compressed, public_key);
+    }
+
+    #[test]
+    fn test_ed25519_prove_signature_rejects_bad_input() {
+        let signing_key = SigningKey::generate(&mut OsRng);
+        let signature = signing_key.sign(b"original").to_bytes().to_vec();
+        let public_key = signing_key.verifying_key().to_bytes().to_vec();
+
+        let result = ed25519_prove_signature(
+            public_key.clone(),
+            b"tampered".to_vec(),
+            signature.clone(),
+            Risc0RequestedReceiptKind::Composite,
+        );
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+
+        let result = ed25519_prove_signature(
+            public_key[..31].to_vec(),
+            b"original".to_vec(),
+            signature,
+            Risc0RequestedReceiptKind::Composite,
+        );
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+    }
+}
@@ -0,0 +1,522 @@
+//! Ethereum `personal_sign` (EIP-191) and typed-data (EIP-712) proofs that
+//! commit the signer address.
+//!
+//! Hashing and recovery come from `ecdsa_core::eth`, shared with the guests.
+
+use ecdsa_core::eth::{
+    self, hash_struct, keccak256, personal_message_hash, typed_data_hash, TypedDataDomain,
+    TypedDataInput, TypedDataJournal,
+};
+use ecdsa_methods::{
+    EIP712_VERIFY_ELF, EIP712_VERIFY_ID, ETH_PERSONAL_SIGN_VERIFY_ELF, ETH_PERSONAL_SIGN_VERIFY_ID,
+};
+
+use crate::{
+    prove_input, verify_receipt, Risc0Error, Risc0ProofOutput, Risc0RequestedReceiptKind,
+};
+
+/// Verify output for Ethereum signature proofs.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0VerifyEthOutput {
+    pub is_valid: bool,
+    /// 20-byte signer address.
+    pub address: Vec<u8>,
+    /// Signer address as EIP-55 checksummed hex with `0x` prefix.
+    pub address_hex: String,
+    pub message: Vec<u8>,
+    /// The message as text, present only when it is valid UTF-8.
+    pub message_text: Option<String>,
+}
+
+/// EIP-712 domain, given like a struct: its encoded type and member
+/// encodings, so any subset of the domain fields can be used.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0Eip712Domain {
+    /// Encoded domain type, e.g.
+    /// `EIP712Domain(string name,uint256 chainId,address verifyingContract)`.
+    pub domain_type: String,
+    /// 32-byte encodings of the members listed in `domain_type`; see the
+    /// `eth_encode_typed_*` helpers.
+    pub encoded_fields: Vec<Vec<u8>>,
+}
+
+/// A disclosed member of a typed struct, by position in the struct type.
+#[derive(uniffi::Record, Clone, Debug, PartialEq, Eq)]
+pub struct Risc0Eip712Field {
+    pub index: u32,
+    /// 32-byte EIP-712 encoding of the member value.
+    pub value: Vec<u8>,
+}
+
+/// Verify output for EIP-712 typed-data proofs.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0VerifyTypedDataOutput {
+    pub is_valid: bool,
+    /// 20-byte signer address.
+    pub address: Vec<u8>,
+    /// Signer address as EIP-55 checksummed hex with `0x` prefix.
+    pub address_hex: String,
+    pub domain_separator: Vec<u8>,
+    /// Encoded type of the signed struct, e.g. `Mail(address to,string contents)`.
+    pub struct_type: String,
+    pub disclosed_fields: Vec<Risc0Eip712Field>,
+}
+
+fn encode_uint(value: u64) -> [u8; 32] {
+    let mut encoded = [0u8; 32];
+    encoded[24..].copy_from_slice(&value.to_be_bytes());
+    encoded
+}
+
+fn encode_address(address: &[u8; 20]) -> [u8; 32] {
+    let mut encoded = [0u8; 32];
+    encoded[12..].copy_from_slice(address);
+    encoded
+}
+
+fn parse_address(address: &[u8]) -> Result<[u8; 20], Risc0Error> {
+    <[u8; 20]>::try_from(address).map_err(|_| {
+        Risc0Error::InputError(format!("Address must be 20 bytes, got {}", address.len()))
+    })
+}
+
+fn parse_encoded_fields(encoded_fields: &[Vec<u8>]) -> Result<Vec<[u8; 32]>, Risc0Error> {
+    encoded_fields
+        .iter()
+        .map(|field| {
+            <[u8; 32]>::try_from(field.as_slice()).map_err(|_| {
+                Risc0Error::InputError(format!(
+                    "Encoded fields must be 32 bytes, got {}",
+                    field.len()
+                ))
+            })
+        })
+        .collect()
+}
+
+fn parse_domain(domain: Risc0Eip712Domain) -> Result<TypedDataDomain, Risc0Error> {
+    let domain = TypedDataDomain {
+        domain_type: domain.domain_type,
+        encoded_fields: parse_encoded_fields(&domain.encoded_fields)?,
+    };
+    if !domain.has_domain_type() {
+        return Err(Risc0Error::InputError(format!(
+            "Domain type must be an EIP712Domain type, got {:?}",
+            domain.domain_type
+        )));
+    }
+    Ok(domain)
+}
+
+/// EIP-55 checksummed hex encoding of an address.
+pub(crate) fn checksum_address(address: &[u8; 20]) -> String {
+    let lower = hex::encode(address);
+    let hash = keccak256(lower.as_bytes());
+
+    let checksummed: String = lower
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = if i % 2 == 0 { hash[i / 2] >> 4 } else { hash[i / 2] & 0x0f };
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+    format!("0x{}", checksummed)
+}
+
+/// Recovers the signer address from a 65-byte `r || s || v` wallet signature
+/// over `prehash`, like the guest does.
+fn recover_address(prehash: &[u8; 32], signature: &[u8]) -> Result<[u8; 20], Risc0Error> {
+    let signature = <&[u8; 65]>::try_from(signature).map_err(|_| {
+        Risc0Error::InputError(format!(
+            "Signature must be 65 bytes (r || s || v), got {}",
+            signature.len()
+        ))
+    })?;
+    eth::recover_address(prehash, signature)
+        .map_err(|e| Risc0Error::InputError(format!("Failed to recover signer: {}", e)))
+}
+
+/// Proves that the owner of an Ethereum address signed `message` with
+/// `personal_sign`.
+///
+/// `signature` is the 65-byte `r || s || v` value wallets return. The journal
+/// commits the recovered address and the message.
+#[uniffi::export]
+pub fn eth_prove_personal_sign(
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    // Recover on the host first so a bad signature fails fast instead of
+    // panicking the guest.
+    recover_address(&personal_message_hash(&message), &signature)?;
+
+    // Create input for zkVM (message, signature)
+    let input = (message, signature);
+    prove_input(ETH_PERSONAL_SIGN_VERIFY_ELF, &input, receipt_kind)
+}
+
+/// Verifies a receipt from [`eth_prove_personal_sign`] and returns the signer
+/// address and message.
+#[uniffi::export]
+pub fn eth_verify_personal_sign(
+    receipt_bytes: Vec<u8>,
+) -> Result<Risc0VerifyEthOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, ETH_PERSONAL_SIGN_VERIFY_ID)?;
+
+    let (address, message): ([u8; 20], Vec<u8>) = receipt
+        .journal
+        .decode()
+        .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+    let message_text = String::from_utf8(message.clone()).ok();
+
+    Ok(Risc0VerifyEthOutput {
+        is_valid: true,
+        address: address.to_vec(),
+        address_hex: checksum_address(&address),
+        message,
+        message_text,
+    })
+}
+
+/// EIP-712 encoding of a `string` member: its Keccak-256.
+#[uniffi::export]
+pub fn eth_encode_typed_string(value: String) -> Vec<u8> {
+    keccak256(value.as_bytes()).to_vec()
+}
+
+/// EIP-712 encoding of a `bytes` member: its Keccak-256.
+#[uniffi::export]
+pub fn eth_encode_typed_bytes(value: Vec<u8>) -> Vec<u8> {
+    keccak256(&value).to_vec()
+}
+
+/// EIP-712 encoding of an `address` member.
+#[uniffi::export]
+pub fn eth_encode_typed_address(address: Vec<u8>) -> Result<Vec<u8>, Risc0Error> {
+    Ok(encode_address(&parse_address(&address)?).to_vec())
+}
+
+/// EIP-712 encoding of a `uint` member that fits in 64 bits.
+#[uniffi::export]
+pub fn eth_encode_typed_uint(value: u64) -> Vec<u8> {
+    encode_uint(value).to_vec()
+}
+
+/// EIP-712 encoding of a `bool` member.
+#[uniffi::export]
+pub fn eth_encode_typed_bool(value: bool) -> Vec<u8> {
+    encode_uint(u64::from(value)).to_vec()
+}
+
+/// EIP-712 `hashStruct`, which is also the encoding of a nested struct member.
+///
+/// `struct_type` is the full encoded type including referenced types, e.g.
+/// `Mail(Person from,string contents)Person(string name,address wallet)`.
+#[uniffi::export]
+pub fn eth_hash_typed_struct(
+    struct_type: String,
+    encoded_fields: Vec<Vec<u8>>,
+) -> Result<Vec<u8>, Risc0Error> {
+    Ok(hash_struct(&struct_type, &parse_encoded_fields(&encoded_fields)?).to_vec())
+}
+
+/// EIP-712 domain separator, to compare with
+/// [`Risc0VerifyTypedDataOutput::domain_separator`].
+#[uniffi::export]
+pub fn eth_typed_data_domain_separator(domain: Risc0Eip712Domain) -> Result<Vec<u8>, Risc0Error> {
+    Ok(parse_domain(domain)?.separator().to_vec())
+}
+
+/// Proves that the owner of an Ethereum address signed EIP-712 typed data.
+///
+/// The struct is passed as its encoded type and 32-byte member encodings (see
+/// the `eth_encode_typed_*` helpers). The guest computes the EIP-712 digest,
+/// recovers the signer from the 65-byte `r || s || v` signature, and commits
+/// the signer address, the domain separator, the struct type, and only the
+/// members listed in `disclosed_fields`.
+#[uniffi::export]
+pub fn eth_prove_typed_data(
+    domain: Risc0Eip712Domain,
+    struct_type: String,
+    encoded_fields: Vec<Vec<u8>>,
+    disclosed_fields: Vec<u32>,
+    signature: Vec<u8>,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let domain = parse_domain(domain)?;
+    let encoded_fields = parse_encoded_fields(&encoded_fields)?;
+    if let Some(index) = disclosed_fields
+        .iter()
+        .find(|&&index| index as usize >= encoded_fields.len())
+    {
+        return Err(Risc0Error::InputError(format!(
+            "Disclosed field {} is out of range",
+            index
+        )));
+    }
+
+    // Recover on the host first so a bad signature fails fast instead of
+    // panicking the guest.
+    let digest = typed_data_hash(&domain.separator(), &hash_struct(&struct_type, &encoded_fields));
+    recover_address(&digest, &signature)?;
+
+    // Create input for zkVM (domain, struct type, encoded fields, disclosed fields, signature)
+    let input = TypedDataInput {
+        domain,
+        struct_type,
+        encoded_fields,
+        disclosed_fields,
+        signature,
+    };
+    prove_input(EIP712_VERIFY_ELF, &input, receipt_kind)
+}
+
+/// Verifies a receipt from [`eth_prove_typed_data`] and returns the signer
+/// address, domain separator, struct type and disclosed fields.
+#[uniffi::export]
+pub fn eth_verify_typed_data(
+    receipt_bytes: Vec<u8>,
+) -> Result<Risc0VerifyTypedDataOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, EIP712_VERIFY_ID)?;
+
+    let journal: TypedDataJournal = receipt
+        .journal
+        .decode()
+        .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+
+    Ok(Risc0VerifyTypedDataOutput {
+        is_valid: true,
+        address: journal.signer_address.to_vec(),
+        address_hex: checksum_address(&journal.signer_address),
+        domain_separator: journal.domain_separator.to_vec(),
+        struct_type: journal.struct_type,
+        disclosed_fields: journal
+            .disclosed_fields
+            .into_iter()
+            .map(|(index, value)| Risc0Eip712Field {
+                index,
+                value: value.to_vec(),
+            })
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ecdsa_core::eth::address;
+    use k256::ecdsa::SigningKey;
+    use rand_core::OsRng;
+
+    /// Signs like a wallet's `personal_sign`, returning `r || s || v` with v = 27/28.
+    fn personal_sign(signing_key: &SigningKey, message: &[u8]) -> Vec<u8> {
+        let (signature, recovery_id) = signing_key
+            .sign_prehash_recoverable(&personal_message_hash(message))
+            .unwrap();
+        let mut bytes = signature.to_bytes().to_vec();
+        bytes.push(recovery_id.to_byte() + 27);
+        bytes
+    }
+
+    #[test]
+    fn test_checksum_address() {
+        // Test vector from EIP-55
+        let address: [u8; 20] = hex::decode("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            checksum_address(&address),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+    }
+
+    #[test]
+    fn test_eth_personal_sign_roundtrip() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let expected_address = address(signing_key.verifying_key());
+        let message = b"I own this address".to_vec();
+        let signature = personal_sign(&signing_key, &message);
+
+        let proof_output = eth_prove_personal_sign(
+            message.clone(),
+            signature,
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed");
+
+        let verify_output =
+            eth_verify_personal_sign(proof_output.receipt).expect("Verification should succeed");
+        assert!(verify_output.is_valid, "Proof should be valid");
+        assert_eq!(verify_output.address, expected_address.to_vec());
+        assert_eq!(verify_output.address_hex, checksum_address(&expected_address));
+        assert_eq!(verify_output.message, message);
+    }
+
+    #[test]
+    fn test_eth_prove_personal_sign_rejects_short_signature() {
+        let result = eth_prove_personal_sign(
+            b"message".to_vec(),
+            vec![0u8; 64],
+            Risc0RequestedReceiptKind::Composite,
+        );
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+    }
+
+    /// Domain and `Mail` struct from the EIP-712 specification example.
+    fn example_mail() -> (Risc0Eip712Domain, String, Vec<Vec<u8>>) {
+        let domain = Risc0Eip712Domain {
+            domain_type:
+                "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+                    .to_string(),
+            encoded_fields: vec![
+                eth_encode_typed_string("Ether Mail".to_string()),
+                eth_encode_typed_string("1".to_string()),
+                eth_encode_typed_uint(1),
+                eth_encode_typed_address(
+                    hex::decode("cccccccccccccccccccccccccccccccccccccccc").unwrap(),
+                )
+                .unwrap(),
+            ],
+        };
+
+        let person_type = "Person(string name,address wallet)".to_string();
+        let person = |name: &str, wallet: &str| {
+            eth_hash_typed_struct(
+                person_type.clone(),
+                vec![
+                    eth_encode_typed_string(name.to_string()),
+                    eth_encode_typed_address(hex::decode(wallet).unwrap()).unwrap(),
+                ],
+            )
+            .unwrap()
+        };
+
+        let mail_type =
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+                .to_string();
+        let fields = vec![
+            person("Cow", "cd2a3d9f938e13cd947ec05abc7fe734df8dd826"),
+            person("Bob", "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"),
+            eth_encode_typed_string("Hello, Bob!".to_string()),
+        ];
+
+        (domain, mail_type, fields)
+    }
+
+    #[test]
+    fn test_typed_data_hash_matches_eip712_example() {
+        let (domain, mail_type, fields) = example_mail();
+
+        let domain_separator: [u8; 32] = eth_typed_data_domain_separator(domain)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            hex::encode(domain_separator),
+            "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        );
+
+        let struct_hash: [u8; 32] = eth_hash_typed_struct(mail_type, fields)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            hex::encode(struct_hash),
+            "c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"
+        );
+
+        assert_eq!(
+            hex::encode(typed_data_hash(&domain_separator, &struct_hash)),
+            "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+        );
+    }
+
+    /// Domain without a `version` member, as used by Permit2.
+    fn permit2_domain() -> Risc0Eip712Domain {
+        Risc0Eip712Domain {
+            domain_type: "EIP712Domain(string name,uint256 chainId,address verifyingContract)"
+                .to_string(),
+            encoded_fields: vec![
+                eth_encode_typed_string("Permit2".to_string()),
+                eth_encode_typed_uint(1),
+                eth_encode_typed_address(
+                    hex::decode("000000000022d473030f116ddee9f6b43ac78ba3").unwrap(),
+                )
+                .unwrap(),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_typed_data_domain_types() {
+        // `DOMAIN_SEPARATOR()` of the Permit2 contract on Ethereum mainnet
+        let separator = eth_typed_data_domain_separator(permit2_domain()).unwrap();
+        assert_eq!(
+            hex::encode(separator),
+            "866a5aba21966af95d6c7ab78eb2b2fc913915c28be3b9aa07cc04ff903e3f28"
+        );
+
+        // Only EIP712Domain types are domains
+        let mut domain = permit2_domain();
+        domain.domain_type = "Person(string name,uint256 chainId,address wallet)".to_string();
+        let result = eth_typed_data_domain_separator(domain);
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+    }
+
+    #[test]
+    fn test_eth_typed_data_roundtrip() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let expected_address = address(signing_key.verifying_key());
+        let (_, mail_type, fields) = example_mail();
+        let domain = permit2_domain();
+
+        let digest = typed_data_hash(
+            &eth_typed_data_domain_separator(domain.clone())
+                .unwrap()
+                .try_into()
+                .unwrap(),
+            &eth_hash_typed_struct(mail_type.clone(), fields.clone())
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        );
+        let (signature, recovery_id) = signing_key.sign_prehash_recoverable(&digest).unwrap();
+        let mut signature = signature.to_bytes().to_vec();
+        signature.push(recovery_id.to_byte() + 27);
+
+        // Disclose only the `contents` member
+        let proof_output = eth_prove_typed_data(
+            domain.clone(),
+            mail_type.clone(),
+            fields.clone(),
+            vec![2],
+            signature,
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed");
+
+        let verify_output =
+            eth_verify_typed_data(proof_output.receipt).expect("Verification should succeed");
+        assert!(verify_output.is_valid, "Proof should be valid");
+        assert_eq!(verify_output.address, expected_address.to_vec());
+        assert_eq!(
+            verify_output.domain_separator,
+            eth_typed_data_domain_separator(domain).unwrap()
+        );
+        assert_eq!(verify_output.struct_type, mail_type);
+        assert_eq!(
+            verify_output.disclosed_fields,
+            vec![Risc0Eip712Field {
+                index: 2,
+                value: fields[2].clone(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,148 @@
+//! Proofs that commit only a (blinded) SHA-256 of the signed message, so the
+//! receipt does not reveal the plaintext.
+
+use ecdsa_methods::{ECDSA_VERIFY_HIDDEN_ELF, ECDSA_VERIFY_HIDDEN_ID};
+use p256::EncodedPoint;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    decode_journal_key, parse_bytes32, parse_signed_message, prove_input, signer_public_key,
+    verify_receipt, Risc0Error, Risc0ProofOutput, Risc0PublicKey, Risc0RequestedReceiptKind,
+};
+
+/// Verify output for receipts that commit only a hash of the message.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0VerifyCommitmentOutput {
+    pub is_valid: bool,
+    /// SHA-256 of the blinding value (zeros if none) followed by the message.
+    pub message_commitment: Vec<u8>,
+    pub signer: Risc0PublicKey,
+}
+
+/// Parses an optional 32-byte blinding value.
+fn parse_blinding(blinding: Option<Vec<u8>>) -> Result<Option<[u8; 32]>, Risc0Error> {
+    blinding
+        .map(|blinding| parse_bytes32(&blinding, "Blinding value"))
+        .transpose()
+}
+
+/// Computes the message commitment the hidden-message guest commits:
+/// `SHA-256(blinding || message)`, with 32 zero bytes as the blinding when none
+/// is given so that `blinding || message` cannot pass as an unblinded message.
+fn message_commitment(message: &[u8], blinding: Option<&[u8; 32]>) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(blinding.unwrap_or(&[0; 32]));
+    hasher.update(message);
+    hasher.finalize().into()
+}
+
+/// Proves a signature while committing only the SHA-256 of the message, so the
+/// receipt does not reveal the plaintext.
+///
+/// A prover-chosen 32-byte `blinding` value is hashed in front of the message;
+/// use it for low-entropy messages that could otherwise be guessed from the
+/// hash. The verifier needs the same value to check a candidate message.
+#[uniffi::export]
+pub fn risc0_prove_hidden_message(
+    public_key: Vec<u8>,
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    blinding: Option<Vec<u8>>,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let (verifying_key, signature) = parse_signed_message(&public_key, &message, &signature)?;
+    let blinding = parse_blinding(blinding)?;
+
+    // Create input for zkVM (public key, message, signature, blinding)
+    let input = (verifying_key.to_encoded_point(true), message, signature, blinding);
+    prove_input(ECDSA_VERIFY_HIDDEN_ELF, &input, receipt_kind)
+}
+
+/// Verifies a receipt from [`risc0_prove_hidden_message`].
+///
+/// When `candidate_message` is given, it is hashed with `blinding` and must
+/// match the committed hash, otherwise [`Risc0Error::MessageMismatchError`] is
+/// returned.
+#[uniffi::export]
+pub fn risc0_verify_hidden_message(
+    receipt_bytes: Vec<u8>,
+    candidate_message: Option<Vec<u8>>,
+    blinding: Option<Vec<u8>>,
+) -> Result<Risc0VerifyCommitmentOutput, Risc0Error> {
+    let blinding = parse_blinding(blinding)?;
+    let receipt = verify_receipt(&receipt_bytes, ECDSA_VERIFY_HIDDEN_ID)?;
+
+    let (receipt_verifying_key, receipt_commitment): (EncodedPoint, [u8; 32]) = receipt
+        .journal
+        .decode()
+        .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+    let verifying_key = decode_journal_key(&receipt_verifying_key)?;
+
+    if let Some(candidate_message) = candidate_message {
+        if message_commitment(&candidate_message, blinding.as_ref()) != receipt_commitment {
+            return Err(Risc0Error::MessageMismatchError(
+                "Candidate message does not match the committed hash".to_string(),
+            ));
+        }
+    }
+
+    Ok(Risc0VerifyCommitmentOutput {
+        is_valid: true,
+        message_commitment: receipt_commitment.to_vec(),
+        signer: signer_public_key(&verifying_key),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::risc0_generate_salt;
+    use crate::test_utils::{random_key, sign};
+
+    #[test]
+    fn test_prove_verify_hidden_message() {
+        let (signing_key, public_key) = random_key();
+        let message = b"vote: yes".to_vec();
+        let blinding = risc0_generate_salt();
+
+        let proof_output = risc0_prove_hidden_message(
+            public_key,
+            message.clone(),
+            sign(&signing_key, &message),
+            Some(blinding.clone()),
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed");
+
+        // Without a candidate only the commitment is returned
+        let verify_output = risc0_verify_hidden_message(proof_output.receipt.clone(), None, None)
+            .expect("Verification should succeed without a candidate");
+        assert_eq!(
+            verify_output.message_commitment,
+            message_commitment(&message, Some(&blinding.clone().try_into().unwrap())).to_vec()
+        );
+
+        let verify_output = risc0_verify_hidden_message(
+            proof_output.receipt.clone(),
+            Some(message.clone()),
+            Some(blinding.clone()),
+        )
+        .expect("Verification should succeed for the signed message");
+        assert!(verify_output.is_valid, "Proof should be valid");
+
+        // The blinded preimage must not pass as an unblinded message
+        let result = risc0_verify_hidden_message(
+            proof_output.receipt.clone(),
+            Some([blinding.clone(), message].concat()),
+            None,
+        );
+        assert!(matches!(result, Err(Risc0Error::MessageMismatchError(_))));
+
+        let result = risc0_verify_hidden_message(
+            proof_output.receipt,
+            Some(b"vote: no".to_vec()),
+            Some(blinding),
+        );
+        assert!(matches!(result, Err(Risc0Error::MessageMismatchError(_))));
+    }
+}
@@ -0,0 +1,135 @@
+//! Proofs that commit to the signer key with a salted hash instead of
+//! revealing it, so receipts stay unlinkable until the prover opens the
+//! commitment.
+
+use ecdsa_methods::{ECDSA_VERIFY_KEY_COMMITMENT_ELF, ECDSA_VERIFY_KEY_COMMITMENT_ID};
+use p256::ecdsa::VerifyingKey;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    parse_bytes32, parse_public_key, parse_signed_message, prove_input, verify_receipt,
+    Risc0Error, Risc0ProofOutput, Risc0RequestedReceiptKind,
+};
+
+/// Verify output for receipts that commit to the signer key instead of revealing it.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0VerifyKeyCommitmentOutput {
+    pub is_valid: bool,
+    /// SHA-256 of the salt followed by the compressed SEC1 signer key.
+    pub key_commitment: Vec<u8>,
+    pub message: Vec<u8>,
+    /// The message as text, present only when it is valid UTF-8.
+    pub message_text: Option<String>,
+}
+
+/// Computes the key commitment the key-commitment guest commits:
+/// `SHA-256(salt || compressed SEC1 key)`.
+fn key_commitment(verifying_key: &VerifyingKey, salt: &[u8; 32]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(salt)
+        .chain_update(verifying_key.to_encoded_point(true).as_bytes())
+        .finalize()
+        .into()
+}
+
+/// Computes the commitment to `public_key` that [`risc0_prove_key_commitment`]
+/// publishes for the same `salt`.
+#[uniffi::export]
+pub fn risc0_commit_public_key(public_key: Vec<u8>, salt: Vec<u8>) -> Result<Vec<u8>, Risc0Error> {
+    let verifying_key = parse_public_key(&public_key)?;
+    let salt = parse_bytes32(&salt, "Salt")?;
+
+    Ok(key_commitment(&verifying_key, &salt).to_vec())
+}
+
+/// Opens a key commitment: returns whether `public_key` and `salt` produce
+/// `key_commitment`.
+#[uniffi::export]
+pub fn risc0_open_key_commitment(
+    key_commitment: Vec<u8>,
+    public_key: Vec<u8>,
+    salt: Vec<u8>,
+) -> Result<bool, Risc0Error> {
+    Ok(risc0_commit_public_key(public_key, salt)? == key_commitment)
+}
+
+/// Proves a signature while committing only a salted commitment to the signer
+/// key, so the receipt cannot be linked to the key until the prover reveals the
+/// key and salt (see [`risc0_open_key_commitment`]).
+#[uniffi::export]
+pub fn risc0_prove_key_commitment(
+    public_key: Vec<u8>,
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    salt: Vec<u8>,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let (verifying_key, signature) = parse_signed_message(&public_key, &message, &signature)?;
+    let salt = parse_bytes32(&salt, "Salt")?;
+
+    // Create input for zkVM (public key, message, signature, salt)
+    let input = (verifying_key.to_encoded_point(true), message, signature, salt);
+    prove_input(ECDSA_VERIFY_KEY_COMMITMENT_ELF, &input, receipt_kind)
+}
+
+/// Verifies a receipt from [`risc0_prove_key_commitment`] and returns the
+/// committed key commitment and message.
+#[uniffi::export]
+pub fn risc0_verify_key_commitment(
+    receipt_bytes: Vec<u8>,
+) -> Result<Risc0VerifyKeyCommitmentOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, ECDSA_VERIFY_KEY_COMMITMENT_ID)?;
+
+    let (key_commitment, message): ([u8; 32], Vec<u8>) = receipt
+        .journal
+        .decode()
+        .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+    let message_text = String::from_utf8(message.clone()).ok();
+
+    Ok(Risc0VerifyKeyCommitmentOutput {
+        is_valid: true,
+        key_commitment: key_commitment.to_vec(),
+        message,
+        message_text,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::risc0_generate_salt;
+    use crate::test_utils::{random_key, sign};
+
+    #[test]
+    fn test_prove_verify_key_commitment() {
+        let (signing_key, public_key) = random_key();
+        let (_, other_public_key) = random_key();
+        let message = b"A key I control signed this".to_vec();
+        let salt = risc0_generate_salt();
+
+        let proof_output = risc0_prove_key_commitment(
+            public_key.clone(),
+            message.clone(),
+            sign(&signing_key, &message),
+            salt.clone(),
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed");
+
+        let verify_output = risc0_verify_key_commitment(proof_output.receipt)
+            .expect("Verification should succeed");
+        assert!(verify_output.is_valid, "Proof should be valid");
+        assert_eq!(verify_output.message, message);
+        assert_eq!(
+            verify_output.key_commitment,
+            risc0_commit_public_key(public_key.clone(), salt.clone()).unwrap()
+        );
+
+        // Opening with the right key succeeds, with another key or salt it fails
+        let commitment = verify_output.key_commitment;
+        assert!(risc0_open_key_commitment(commitment.clone(), public_key.clone(), salt.clone())
+            .unwrap());
+        assert!(!risc0_open_key_commitment(commitment.clone(), other_public_key, salt).unwrap());
+        assert!(!risc0_open_key_commitment(commitment, public_key, risc0_generate_salt()).unwrap());
+    }
+}
@@ -19,13 +19,37 @@
 // Allow unexpected cfg for the full file
 #![allow(unexpected_cfgs)]
 
-use ecdsa_methods::{ECDSA_VERIFY_ELF, ECDSA_VERIFY_ID};
-use risc0_zkvm::{default_prover, ExecutorEnv, Receipt};
+use ecdsa_methods::{ECDSA_VERIFY_ELF, ECDSA_VERIFY_ID, ECDSA_VERIFY_PREHASH_ELF};
+use risc0_zkvm::{default_prover, ExecutorEnv, InnerReceipt, ProverOpts, Receipt};
 use p256::{
     EncodedPoint,
-    ecdsa::{Signature, SigningKey, VerifyingKey, signature::Signer},
+    ecdsa::{Signature, SigningKey, VerifyingKey, signature::{Signer, Verifier}},
 };
-use rand_core::OsRng;
+use rand_core::{OsRng, RngCore};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+mod aggregate;
+mod batch;
+mod chain;
+mod challenge;
+mod context;
+mod ed25519;
+mod eth;
+mod hidden;
+mod key_commitment;
+mod membership;
+pub mod merkle;
+mod p384;
+mod prehash;
+mod rsa;
+mod schnorr;
+mod secp256k1;
+#[cfg(test)]
+mod test_utils;
+mod threshold;
+mod unrevoked;
+mod validity;
 
 mopro_ffi::app!();
 
@@ -39,34 +63,161 @@ pub enum Risc0Error {
     VerifyError(String),
     #[error("Failed to decode journal: {0}")]
     DecodeError(String),
+    #[error("Invalid input: {0}")]
+    InputError(String),
+    #[error("Unexpected signer: {0}")]
+    SignerMismatchError(String),
+    #[error("Unexpected message: {0}")]
+    MessageMismatchError(String),
+    #[error("Unexpected challenge: {0}")]
+    ChallengeMismatchError(String),
+    #[error("Unexpected context: {0}")]
+    ContextMismatchError(String),
+    #[error("Proof expired: {0}")]
+    ExpiredError(String),
+    #[error("Proof not yet valid: {0}")]
+    NotYetValidError(String),
+}
+
+/// How the signed message is handed to the guest.
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Risc0MessageMode {
+    /// The guest hashes the whole message and commits it to the journal.
+    Full,
+    /// The host hashes the message with SHA-256; the guest verifies the
+    /// signature over the digest and commits only the digest. Proving cost no
+    /// longer grows with the message size.
+    Prehashed,
+}
+
+/// Kind of receipt to prove, from largest and fastest to prove to smallest.
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Risc0RequestedReceiptKind {
+    /// One STARK seal per segment; the prover default. Size grows with the
+    /// execution length.
+    Composite,
+    /// A single constant-size STARK seal (a few hundred KB).
+    Succinct,
+    /// A Groth16 SNARK seal of a few hundred bytes, verifiable on-chain.
+    /// Proving requires an x86 host and is not available on phones.
+    Groth16,
+}
+
+/// Kind of receipt that was produced.
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Risc0ReceiptKind {
+    /// See [`Risc0RequestedReceiptKind::Composite`].
+    Composite,
+    /// See [`Risc0RequestedReceiptKind::Succinct`].
+    Succinct,
+    /// See [`Risc0RequestedReceiptKind::Groth16`].
+    Groth16,
+    /// Dev-mode receipt without a real seal, produced instead of the
+    /// requested kind when `RISC0_DEV_MODE` is set.
+    Fake,
 }
 
 #[derive(uniffi::Record, Clone)]
 pub struct Risc0ProofOutput {
     pub receipt: Vec<u8>,
+    /// Kind of receipt that was produced.
+    pub receipt_kind: Risc0ReceiptKind,
+    /// Size of the seal in bytes, the bulk of the receipt.
+    pub seal_size: u64,
+}
+
+/// Elliptic curve of a proven public key.
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Risc0Curve {
+    /// secp256r1 / NIST P-256.
+    P256,
+    /// secp256k1 ECDSA, as used by Ethereum and Bitcoin.
+    Secp256k1,
+    /// Ed25519 (EdDSA over Curve25519).
+    Ed25519,
+    /// secp384r1 / NIST P-384, with SHA-384.
+    P384,
+    /// secp256k1 x-only key of a BIP-340 Schnorr signer, reported as the
+    /// even-Y point BIP-340 assigns to it.
+    Bip340,
+}
+
+/// Public key committed to the journal of a verified receipt.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0PublicKey {
+    pub curve: Risc0Curve,
+    /// SEC1 compressed encoding (33 bytes, or 49 for P-384). Ed25519 keys
+    /// have a single 32-byte encoding, reported in both fields.
+    pub compressed: Vec<u8>,
+    /// SEC1 uncompressed encoding (65 bytes, or 97 for P-384).
+    pub uncompressed: Vec<u8>,
+    /// Lowercase hex SHA-256 of the compressed encoding.
+    pub fingerprint: String,
 }
 
 #[derive(uniffi::Record, Clone)]
 pub struct Risc0VerifyOutput {
     pub is_valid: bool,
     pub verified_message: String,
+    pub signer: Risc0PublicKey,
 }
 
-#[uniffi::export]
-pub fn risc0_prove(message: String) -> Result<Risc0ProofOutput, Risc0Error> {
-    // Generate a random secp256r1 keypair and sign the message
-    let signing_key = SigningKey::random(&mut OsRng);
-    let verifying_key = signing_key.verifying_key();
+/// Verify output for messages that are not necessarily UTF-8.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0VerifyBytesOutput {
+    pub is_valid: bool,
+    pub message: Vec<u8>,
+    /// The message as text, present only when it is valid UTF-8.
+    pub message_text: Option<String>,
+    pub signer: Risc0PublicKey,
+}
+
+/// Prover options that produce `receipt_kind`.
+fn prover_opts(receipt_kind: Risc0RequestedReceiptKind) -> ProverOpts {
+    match receipt_kind {
+        Risc0RequestedReceiptKind::Composite => ProverOpts::composite(),
+        Risc0RequestedReceiptKind::Succinct => ProverOpts::succinct(),
+        Risc0RequestedReceiptKind::Groth16 => ProverOpts::groth16(),
+    }
+}
+
+/// Serializes `receipt` and reports its kind and seal size.
+fn proof_output(receipt: &Receipt) -> Result<Risc0ProofOutput, Risc0Error> {
+    let receipt_kind = match &receipt.inner {
+        InnerReceipt::Composite(_) => Risc0ReceiptKind::Composite,
+        InnerReceipt::Succinct(_) => Risc0ReceiptKind::Succinct,
+        InnerReceipt::Groth16(_) => Risc0ReceiptKind::Groth16,
+        InnerReceipt::Fake(_) => Risc0ReceiptKind::Fake,
+        _ => {
+            return Err(Risc0Error::SerializeError(
+                "Unsupported receipt kind".to_string(),
+            ))
+        }
+    };
+
+    // Serialize receipt to bytes
+    let receipt_bytes = bincode::serialize(receipt)
+        .map_err(|e| Risc0Error::SerializeError(format!("Failed to serialize receipt: {}", e)))?;
 
-    let message_bytes = message.as_bytes();
-    let signature: Signature = signing_key.sign(message_bytes);
+    Ok(Risc0ProofOutput {
+        receipt: receipt_bytes,
+        receipt_kind,
+        seal_size: receipt.seal_size() as u64,
+    })
+}
 
-    // Create input for zkVM (public key, message, signature)
-    let input = (verifying_key.to_encoded_point(true), message_bytes, signature);
+/// Runs `elf` in the zkVM with `input` written to the guest and returns the
+/// serialized receipt of `receipt_kind`.
+fn prove_input<T: Serialize>(
+    elf: &[u8],
+    input: &T,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let opts = prover_opts(receipt_kind);
 
-    // Create executor environment with ECDSA input
+    // Create executor environment with the guest input
     let env = ExecutorEnv::builder()
-        .write(&input)
+        .write(input)
         .map_err(|e| Risc0Error::ProveError(format!("Failed to write input: {}", e)))?
         .build()
         .map_err(|e| {
@@ -78,56 +229,436 @@ pub fn risc0_prove(message: String) -> Result<Risc0ProofOutput, Risc0Error> {
 
     // Generate proof
     let prove_info = prover
-        .prove(env, ECDSA_VERIFY_ELF)
+        .prove_with_opts(env, elf, &opts)
         .map_err(|e| Risc0Error::ProveError(format!("Failed to generate proof: {}", e)))?;
 
     // Extract receipt
     let receipt = prove_info.receipt;
 
-    // Serialize receipt to bytes
-    let receipt_bytes = bincode::serialize(&receipt)
-        .map_err(|e| Risc0Error::SerializeError(format!("Failed to serialize receipt: {}", e)))?;
+    proof_output(&receipt)
+}
 
-    Ok(Risc0ProofOutput {
-        receipt: receipt_bytes,
-    })
+/// Like [`prove_input`], but adds `assumptions` for the guest's `env::verify`
+/// calls. Each assumption is compressed to a succinct receipt first.
+///
+/// Assumptions are only resolved by succinct proving, so a composite
+/// `receipt_kind` is rejected and a Groth16 one is compressed from the
+/// resolved succinct receipt.
+fn prove_with_assumptions<T: Serialize>(
+    elf: &[u8],
+    input: &T,
+    assumptions: Vec<Receipt>,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    if receipt_kind == Risc0RequestedReceiptKind::Composite {
+        return Err(Risc0Error::InputError(
+            "Proofs over other receipts must be succinct or Groth16".to_string(),
+        ));
+    }
+    let prover = default_prover();
+    let succinct_opts = ProverOpts::succinct();
+
+    let mut builder = ExecutorEnv::builder();
+    for assumption in assumptions {
+        let assumption = prover.compress(&succinct_opts, &assumption).map_err(|e| {
+            Risc0Error::ProveError(format!("Failed to compress assumption: {}", e))
+        })?;
+        builder.add_assumption(assumption);
+    }
+    let env = builder
+        .write(input)
+        .map_err(|e| Risc0Error::ProveError(format!("Failed to write input: {}", e)))?
+        .build()
+        .map_err(|e| {
+            Risc0Error::ProveError(format!("Failed to build executor environment: {}", e))
+        })?;
+
+    // Generate a succinct proof, resolving the assumptions
+    let mut receipt = prover
+        .prove_with_opts(env, elf, &succinct_opts)
+        .map_err(|e| Risc0Error::ProveError(format!("Failed to generate proof: {}", e)))?
+        .receipt;
+
+    if receipt_kind == Risc0RequestedReceiptKind::Groth16 {
+        receipt = prover
+            .compress(&ProverOpts::groth16(), &receipt)
+            .map_err(|e| Risc0Error::ProveError(format!("Failed to compress receipt: {}", e)))?;
+    }
+
+    proof_output(&receipt)
+}
+
+/// Proves a secp256r1 signature over `message` inside the ECDSA guest.
+fn prove_ecdsa(
+    verifying_key: &VerifyingKey,
+    message: &[u8],
+    signature: &Signature,
+    mode: Risc0MessageMode,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let encoded_verifying_key = verifying_key.to_encoded_point(true);
+    match mode {
+        Risc0MessageMode::Full => {
+            // Create input for zkVM (public key, message, signature)
+            let input = (encoded_verifying_key, message, signature);
+            prove_input(ECDSA_VERIFY_ELF, &input, receipt_kind)
+        }
+        Risc0MessageMode::Prehashed => {
+            // Create input for zkVM (public key, message digest, signature)
+            let message_digest: [u8; 32] = Sha256::digest(message).into();
+            let input = (encoded_verifying_key, message_digest, signature);
+            prove_input(ECDSA_VERIFY_PREHASH_ELF, &input, receipt_kind)
+        }
+    }
+}
+
+/// Parses a SEC1-encoded (compressed or uncompressed) secp256r1 public key.
+fn parse_public_key(public_key: &[u8]) -> Result<VerifyingKey, Risc0Error> {
+    VerifyingKey::from_sec1_bytes(public_key)
+        .map_err(|e| Risc0Error::InputError(format!("Invalid public key: {}", e)))
 }
 
+/// Parses a public key and signature and checks the signature over `message`
+/// on the host, so an invalid one fails fast instead of panicking the guest.
+fn parse_signed_message(
+    public_key: &[u8],
+    message: &[u8],
+    signature: &[u8],
+) -> Result<(VerifyingKey, Signature), Risc0Error> {
+    let verifying_key = parse_public_key(public_key)?;
+    let signature = parse_signature(signature)?;
+
+    verifying_key
+        .verify(message, &signature)
+        .map_err(|e| Risc0Error::InputError(format!("Signature does not verify: {}", e)))?;
+
+    Ok((verifying_key, signature))
+}
+
+/// Parses a list of SEC1-encoded secp256r1 public keys.
+fn parse_public_keys(public_keys: &[Vec<u8>]) -> Result<Vec<VerifyingKey>, Risc0Error> {
+    public_keys.iter().map(|key| parse_public_key(key)).collect()
+}
+
+/// Parses a secp256r1 ECDSA signature, either as fixed-size `r || s` or ASN.1 DER.
+fn parse_signature(signature: &[u8]) -> Result<Signature, Risc0Error> {
+    Signature::from_slice(signature)
+        .or_else(|_| Signature::from_der(signature))
+        .map_err(|e| Risc0Error::InputError(format!("Invalid signature: {}", e)))
+}
+
+/// Signs `message` with a fresh random key and proves the signature,
+/// producing a receipt of `receipt_kind`.
+///
+/// Succinct and Groth16 receipts take longer to prove but are much smaller to
+/// upload; see [`Risc0ProofOutput::seal_size`].
 #[uniffi::export]
-pub fn risc0_verify(receipt_bytes: Vec<u8>) -> Result<Risc0VerifyOutput, Risc0Error> {
-    // Deserialize receipt from bytes
+pub fn risc0_prove(
+    message: String,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    risc0_prove_bytes(message.into_bytes(), receipt_kind)
+}
+
+/// Same as [`risc0_prove`] for arbitrary binary messages (CBOR, protobuf, DER, ...).
+#[uniffi::export]
+pub fn risc0_prove_bytes(
+    message: Vec<u8>,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    // Generate a random secp256r1 keypair and sign the message
+    let signing_key = SigningKey::random(&mut OsRng);
+    let verifying_key = signing_key.verifying_key();
+
+    let signature: Signature = signing_key.sign(&message);
+
+    prove_ecdsa(
+        verifying_key,
+        &message,
+        &signature,
+        Risc0MessageMode::Full,
+        receipt_kind,
+    )
+}
+
+/// Proves a signature produced elsewhere, e.g. by a server or hardware token.
+///
+/// `public_key` is a SEC1-encoded secp256r1 key and `signature` is either the
+/// fixed-size `r || s` encoding or ASN.1 DER. The signature is checked on the
+/// host first so an invalid one fails fast instead of panicking the guest.
+#[uniffi::export]
+pub fn risc0_prove_signature(
+    public_key: Vec<u8>,
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    risc0_prove_signature_with_mode(
+        public_key,
+        message,
+        signature,
+        Risc0MessageMode::Full,
+        receipt_kind,
+    )
+}
+
+/// Same as [`risc0_prove_signature`], choosing how the message reaches the
+/// guest.
+///
+/// Use [`Risc0MessageMode::Prehashed`] for large documents and verify the
+/// resulting receipt with [`prehash::risc0_verify_prehashed`].
+#[uniffi::export]
+pub fn risc0_prove_signature_with_mode(
+    public_key: Vec<u8>,
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    mode: Risc0MessageMode,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let (verifying_key, signature) = parse_signed_message(&public_key, &message, &signature)?;
+
+    prove_ecdsa(&verifying_key, &message, &signature, mode, receipt_kind)
+}
+
+/// Compresses a receipt, e.g. a composite one from [`risc0_prove`], to
+/// `target_kind`, typically on a backend after a phone proved it quickly.
+///
+/// Only succinct and Groth16 are valid targets. The journal is preserved, so
+/// the result verifies with the same `risc0_verify*` function as the input.
+#[uniffi::export]
+pub fn risc0_compress_receipt(
+    receipt_bytes: Vec<u8>,
+    target_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    if target_kind == Risc0RequestedReceiptKind::Composite {
+        return Err(Risc0Error::InputError(format!(
+            "Cannot compress a receipt to {:?}",
+            target_kind
+        )));
+    }
+    let opts = prover_opts(target_kind);
+
     let receipt: Receipt = bincode::deserialize(&receipt_bytes)
         .map_err(|e| Risc0Error::SerializeError(format!("Failed to deserialize receipt: {}", e)))?;
 
+    let compressed = default_prover()
+        .compress(&opts, &receipt)
+        .map_err(|e| Risc0Error::ProveError(format!("Failed to compress receipt: {}", e)))?;
+    if compressed.journal.bytes != receipt.journal.bytes {
+        return Err(Risc0Error::ProveError(
+            "Compression changed the journal".to_string(),
+        ));
+    }
+
+    proof_output(&compressed)
+}
+
+/// Deserializes a receipt and checks its seal against `image_id`.
+fn verify_receipt(receipt_bytes: &[u8], image_id: [u32; 8]) -> Result<Receipt, Risc0Error> {
+    // Deserialize receipt from bytes
+    let receipt: Receipt = bincode::deserialize(receipt_bytes)
+        .map_err(|e| Risc0Error::SerializeError(format!("Failed to deserialize receipt: {}", e)))?;
+
     // Verify the receipt
     receipt
-        .verify(ECDSA_VERIFY_ID)
+        .verify(image_id)
         .map_err(|e| Risc0Error::VerifyError(format!("Failed to verify receipt: {}", e)))?;
 
-    // Extract output from journal (verifying key and message)
+    Ok(receipt)
+}
+
+/// Parses a secp256r1 public key committed to a journal.
+fn decode_journal_key(encoded_verifying_key: &EncodedPoint) -> Result<VerifyingKey, Risc0Error> {
+    VerifyingKey::from_encoded_point(encoded_verifying_key)
+        .map_err(|e| Risc0Error::DecodeError(format!("Invalid public key in journal: {}", e)))
+}
+
+/// Decodes the `(verifying key, message)` journal committed by the ECDSA guest.
+fn decode_ecdsa_journal(receipt: &Receipt) -> Result<(VerifyingKey, Vec<u8>), Risc0Error> {
     let (receipt_verifying_key, receipt_message): (EncodedPoint, Vec<u8>) = receipt
         .journal
         .decode()
         .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
 
+    let verifying_key = decode_journal_key(&receipt_verifying_key)?;
+
+    Ok((verifying_key, receipt_message))
+}
+
+/// Describes a verified secp256r1 key in the encodings callers compare against.
+fn signer_public_key(verifying_key: &VerifyingKey) -> Risc0PublicKey {
+    public_key_record(
+        Risc0Curve::P256,
+        verifying_key.to_encoded_point(true).as_bytes().to_vec(),
+        verifying_key.to_encoded_point(false).as_bytes().to_vec(),
+    )
+}
+
+/// Builds a [`Risc0PublicKey`], fingerprinting the compressed encoding.
+fn public_key_record(
+    curve: Risc0Curve,
+    compressed: Vec<u8>,
+    uncompressed: Vec<u8>,
+) -> Risc0PublicKey {
+    let fingerprint = hex::encode(Sha256::digest(&compressed));
+
+    Risc0PublicKey {
+        curve,
+        compressed,
+        uncompressed,
+        fingerprint,
+    }
+}
+
+/// Builds the verify output for a decoded ECDSA journal.
+fn ecdsa_verify_output(
+    verifying_key: &VerifyingKey,
+    receipt_message: Vec<u8>,
+) -> Result<Risc0VerifyOutput, Risc0Error> {
     let verified_message = String::from_utf8(receipt_message)
         .map_err(|e| Risc0Error::DecodeError(format!("Failed to convert message to string: {}", e)))?;
 
     Ok(Risc0VerifyOutput {
         is_valid: true,
         verified_message,
+        signer: signer_public_key(verifying_key),
     })
 }
 
+/// Builds the byte-oriented verify output for a decoded ECDSA journal.
+fn ecdsa_verify_bytes_output(
+    verifying_key: &VerifyingKey,
+    message: Vec<u8>,
+) -> Risc0VerifyBytesOutput {
+    let message_text = String::from_utf8(message.clone()).ok();
+
+    Risc0VerifyBytesOutput {
+        is_valid: true,
+        message,
+        message_text,
+        signer: signer_public_key(verifying_key),
+    }
+}
+
+/// Parses a 32-byte value such as a salt or blinding value.
+fn parse_bytes32(value: &[u8], name: &str) -> Result<[u8; 32], Risc0Error> {
+    <[u8; 32]>::try_from(value).map_err(|_| {
+        Risc0Error::InputError(format!("{} must be 32 bytes, got {}", name, value.len()))
+    })
+}
+
+/// Returns 32 random bytes, suitable as a blinding value for
+/// [`hidden::risc0_prove_hidden_message`] or a salt for
+/// [`key_commitment::risc0_prove_key_commitment`].
+#[uniffi::export]
+pub fn risc0_generate_salt() -> Vec<u8> {
+    let mut salt = [0u8; 32];
+    OsRng.fill_bytes(&mut salt);
+    salt.to_vec()
+}
+
+#[uniffi::export]
+pub fn risc0_verify(receipt_bytes: Vec<u8>) -> Result<Risc0VerifyOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, ECDSA_VERIFY_ID)?;
+
+    // Extract output from journal (verifying key and message)
+    let (verifying_key, receipt_message) = decode_ecdsa_journal(&receipt)?;
+
+    ecdsa_verify_output(&verifying_key, receipt_message)
+}
+
+/// Same as [`risc0_verify`] but returns the message as raw bytes, so receipts
+/// over non-UTF-8 payloads verify instead of failing with a `DecodeError`.
+#[uniffi::export]
+pub fn risc0_verify_bytes(receipt_bytes: Vec<u8>) -> Result<Risc0VerifyBytesOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, ECDSA_VERIFY_ID)?;
+    let (verifying_key, receipt_message) = decode_ecdsa_journal(&receipt)?;
+
+    Ok(ecdsa_verify_bytes_output(&verifying_key, receipt_message))
+}
+
+/// Checks a decoded ECDSA journal against the caller's policy.
+///
+/// The signer must be one of `allowed_public_keys` (SEC1, compressed or
+/// uncompressed). When given, the message must equal `expected_message` and its
+/// SHA-256 must equal `expected_message_sha256`.
+fn check_ecdsa_policy(
+    verifying_key: &VerifyingKey,
+    message: &[u8],
+    allowed_public_keys: &[Vec<u8>],
+    expected_message: Option<&[u8]>,
+    expected_message_sha256: Option<&[u8]>,
+) -> Result<(), Risc0Error> {
+    if allowed_public_keys.is_empty() {
+        return Err(Risc0Error::InputError(
+            "At least one allowed public key is required".to_string(),
+        ));
+    }
+
+    let allowed_keys = parse_public_keys(allowed_public_keys)?;
+    if !allowed_keys.contains(verifying_key) {
+        return Err(Risc0Error::SignerMismatchError(format!(
+            "Public key {} is not allowed",
+            hex::encode(verifying_key.to_encoded_point(true).as_bytes())
+        )));
+    }
+
+    if let Some(expected_message) = expected_message {
+        if message != expected_message {
+            return Err(Risc0Error::MessageMismatchError(
+                "Journal message does not match the expected message".to_string(),
+            ));
+        }
+    }
+
+    if let Some(expected_message_sha256) = expected_message_sha256 {
+        if Sha256::digest(message).as_slice() != expected_message_sha256 {
+            return Err(Risc0Error::MessageMismatchError(
+                "Journal message does not match the expected message hash".to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Verifies a receipt like [`risc0_verify`] and additionally requires the
+/// journal to match an expected signer and, optionally, message.
+///
+/// Fails with [`Risc0Error::SignerMismatchError`] when the proven key is not in
+/// `allowed_public_keys` and with [`Risc0Error::MessageMismatchError`] when the
+/// message or its SHA-256 differ from the expected values.
+#[uniffi::export]
+pub fn risc0_verify_with_policy(
+    receipt_bytes: Vec<u8>,
+    allowed_public_keys: Vec<Vec<u8>>,
+    expected_message: Option<Vec<u8>>,
+    expected_message_sha256: Option<Vec<u8>>,
+) -> Result<Risc0VerifyOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, ECDSA_VERIFY_ID)?;
+    let (verifying_key, receipt_message) = decode_ecdsa_journal(&receipt)?;
+
+    check_ecdsa_policy(
+        &verifying_key,
+        &receipt_message,
+        &allowed_public_keys,
+        expected_message.as_deref(),
+        expected_message_sha256.as_deref(),
+    )?;
+
+    ecdsa_verify_output(&verifying_key, receipt_message)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use test_utils::{random_key, sign};
 
     #[test]
     fn test_risc0_prove_success() {
         // Test proving with a simple message
         let message = "Hello, ECDSA!".to_string();
-        let result = risc0_prove(message);
+        let result = risc0_prove(message, Risc0RequestedReceiptKind::Composite);
 
         assert!(result.is_ok(), "Proving should succeed for valid message");
 
@@ -142,7 +673,7 @@ mod tests {
     fn test_risc0_verify_success() {
         // First generate a proof
         let message = "Test message for verification".to_string();
-        let prove_result = risc0_prove(message.clone());
+        let prove_result = risc0_prove(message.clone(), Risc0RequestedReceiptKind::Composite);
         assert!(prove_result.is_ok(), "Proving should succeed");
 
         let proof_output = prove_result.unwrap();
@@ -177,7 +708,8 @@ mod tests {
             let message_str = message.to_string();
 
             // Generate proof
-            let prove_result = risc0_prove(message_str.clone());
+            let prove_result =
+                risc0_prove(message_str.clone(), Risc0RequestedReceiptKind::Composite);
             assert!(
                 prove_result.is_ok(),
                 "Proving should succeed for message: '{}'",
@@ -207,4 +739,199 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_prove_signature_roundtrip() {
+        // Sign outside of the prover and hand over only key, message and signature
+        let signing_key = SigningKey::random(&mut OsRng);
+        let message = b"Signed by an external device".to_vec();
+        let signature: Signature = signing_key.sign(&message);
+
+        let public_key = signing_key
+            .verifying_key()
+            .to_encoded_point(false)
+            .as_bytes()
+            .to_vec();
+        let der_signature = signature.to_der().as_bytes().to_vec();
+        let proof_output = risc0_prove_signature(
+            public_key,
+            message.clone(),
+            der_signature,
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed for an external signature");
+
+        let verify_output =
+            risc0_verify(proof_output.receipt).expect("Verification should succeed");
+        assert!(verify_output.is_valid, "Proof should be valid");
+        assert_eq!(verify_output.verified_message.as_bytes(), &message[..]);
+    }
+
+    #[test]
+    fn test_risc0_verify_returns_signer() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let verifying_key = signing_key.verifying_key();
+        let message = b"Which key signed this?".to_vec();
+        let signature: Signature = signing_key.sign(&message);
+
+        let compressed = verifying_key.to_encoded_point(true).as_bytes().to_vec();
+        let uncompressed = verifying_key.to_encoded_point(false).as_bytes().to_vec();
+        let proof_output = risc0_prove_signature(
+            compressed.clone(),
+            message,
+            signature.to_bytes().to_vec(),
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed");
+
+        let verify_output =
+            risc0_verify(proof_output.receipt).expect("Verification should succeed");
+        assert_eq!(verify_output.signer.compressed, compressed);
+        assert_eq!(verify_output.signer.uncompressed, uncompressed);
+        assert_eq!(
+            verify_output.signer.fingerprint,
+            hex::encode(Sha256::digest(&compressed)),
+            "Fingerprint should be the SHA-256 of the compressed key"
+        );
+    }
+
+    #[test]
+    fn test_prove_signature_rejects_invalid_signature() {
+        let (signing_key, public_key) = random_key();
+        let result = risc0_prove_signature(
+            public_key,
+            b"tampered message".to_vec(),
+            sign(&signing_key, b"original message"),
+            Risc0RequestedReceiptKind::Composite,
+        );
+
+        assert!(
+            matches!(result, Err(Risc0Error::InputError(_))),
+            "Proving should fail for a signature over a different message"
+        );
+    }
+
+    #[test]
+    fn test_risc0_verify_with_policy() {
+        let (signing_key, public_key) = random_key();
+        let other_key = SigningKey::random(&mut OsRng);
+        let message = b"Transfer 10 tokens".to_vec();
+
+        let other_public_key = other_key
+            .verifying_key()
+            .to_encoded_point(false)
+            .as_bytes()
+            .to_vec();
+        let receipt = risc0_prove_signature(
+            public_key.clone(),
+            message.clone(),
+            sign(&signing_key, &message),
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed")
+        .receipt;
+
+        // Expected signer among several allowed keys, matching message hash
+        let verify_output = risc0_verify_with_policy(
+            receipt.clone(),
+            vec![other_public_key.clone(), public_key.clone()],
+            None,
+            Some(Sha256::digest(&message).to_vec()),
+        )
+        .expect("Verification should succeed for an allowed signer");
+        assert_eq!(verify_output.signer.compressed, public_key);
+
+        // Signer not in the allow list
+        let result = risc0_verify_with_policy(
+            receipt.clone(),
+            vec![other_public_key],
+            None,
+            None,
+        );
+        assert!(matches!(result, Err(Risc0Error::SignerMismatchError(_))));
+
+        // Different expected message
+        let result = risc0_verify_with_policy(
+            receipt,
+            vec![public_key],
+            Some(b"Transfer 1000 tokens".to_vec()),
+            None,
+        );
+        assert!(matches!(result, Err(Risc0Error::MessageMismatchError(_))));
+    }
+
+    #[test]
+    fn test_prove_verify_bytes_roundtrip() {
+        // Not valid UTF-8, e.g. a CBOR map header followed by a raw digest
+        let message = vec![0xa1, 0xff, 0xfe, 0x00, 0x80, 0xc3, 0x28];
+        let proof_output = risc0_prove_bytes(message.clone(), Risc0RequestedReceiptKind::Composite)
+            .expect("Proving should succeed");
+
+        let result = risc0_verify(proof_output.receipt.clone());
+        assert!(
+            matches!(result, Err(Risc0Error::DecodeError(_))),
+            "Text verification should reject non-UTF-8 messages"
+        );
+
+        let verify_output =
+            risc0_verify_bytes(proof_output.receipt).expect("Verification should succeed");
+        assert!(verify_output.is_valid, "Proof should be valid");
+        assert_eq!(verify_output.message, message);
+        assert_eq!(verify_output.message_text, None);
+    }
+
+    #[test]
+    fn test_verify_bytes_text_view() {
+        let message = "Unicode: 你好世界".to_string();
+        let proof_output = risc0_prove(message.clone(), Risc0RequestedReceiptKind::Composite)
+            .expect("Proving should succeed");
+
+        let verify_output =
+            risc0_verify_bytes(proof_output.receipt).expect("Verification should succeed");
+        assert_eq!(verify_output.message, message.as_bytes());
+        assert_eq!(verify_output.message_text, Some(message));
+    }
+
+    #[test]
+    fn test_prove_with_receipt_kind() {
+        let message = "Small receipt".to_string();
+        let proof_output =
+            risc0_prove(message.clone(), Risc0RequestedReceiptKind::Succinct)
+                .expect("Proving should succeed");
+
+        let expected_kind = if prover_opts(Risc0RequestedReceiptKind::Succinct).dev_mode() {
+            Risc0ReceiptKind::Fake
+        } else {
+            Risc0ReceiptKind::Succinct
+        };
+        assert_eq!(proof_output.receipt_kind, expected_kind);
+
+        let verify_output =
+            risc0_verify(proof_output.receipt).expect("Verification should succeed");
+        assert_eq!(verify_output.verified_message, message);
+    }
+
+    #[test]
+    fn test_compress_receipt() {
+        let message = "Compress me".to_string();
+        let proof_output = risc0_prove(message.clone(), Risc0RequestedReceiptKind::Composite)
+            .expect("Proving should succeed");
+
+        let compressed = risc0_compress_receipt(
+            proof_output.receipt.clone(),
+            Risc0RequestedReceiptKind::Succinct,
+        )
+        .expect("Compression should succeed");
+        if !prover_opts(Risc0RequestedReceiptKind::Succinct).dev_mode() {
+            assert_eq!(proof_output.receipt_kind, Risc0ReceiptKind::Composite);
+            assert_eq!(compressed.receipt_kind, Risc0ReceiptKind::Succinct);
+        }
+
+        let verify_output = risc0_verify(compressed.receipt).expect("Verification should succeed");
+        assert_eq!(verify_output.verified_message, message);
+
+        let result =
+            risc0_compress_receipt(proof_output.receipt, Risc0RequestedReceiptKind::Composite);
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+    }
 }
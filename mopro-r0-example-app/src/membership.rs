@@ -0,0 +1,124 @@
+//! Anonymous membership proofs: one key of a registered set signed, and the
+//! journal commits only the Merkle root of the set.
+
+use ecdsa_methods::{ECDSA_VERIFY_MEMBERSHIP_ELF, ECDSA_VERIFY_MEMBERSHIP_ID};
+
+use crate::merkle::KeySetTree;
+use crate::{
+    parse_public_keys, parse_signed_message, prove_input, verify_receipt, Risc0Error,
+    Risc0ProofOutput, Risc0RequestedReceiptKind,
+};
+
+/// Verify output for anonymous membership proofs.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0VerifyMembershipOutput {
+    pub is_valid: bool,
+    /// Merkle root of the key set the signer belongs to.
+    pub key_set_root: Vec<u8>,
+    pub message: Vec<u8>,
+    /// The message as text, present only when it is valid UTF-8.
+    pub message_text: Option<String>,
+}
+
+/// Returns the Merkle root of a set of SEC1 public keys, as committed by
+/// [`risc0_prove_membership`]. The order of the keys matters.
+#[uniffi::export]
+pub fn risc0_key_set_root(public_keys: Vec<Vec<u8>>) -> Result<Vec<u8>, Risc0Error> {
+    let keys = parse_public_keys(&public_keys)?;
+    Ok(KeySetTree::new(&keys).root().to_vec())
+}
+
+/// Proves that one of `public_keys` signed `message` without revealing which.
+///
+/// The journal commits only the Merkle root of `public_keys` and the message;
+/// compare the root with [`risc0_key_set_root`] of the registered set.
+#[uniffi::export]
+pub fn risc0_prove_membership(
+    public_keys: Vec<Vec<u8>>,
+    public_key: Vec<u8>,
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let keys = parse_public_keys(&public_keys)?;
+    let (verifying_key, signature) = parse_signed_message(&public_key, &message, &signature)?;
+
+    let path = KeySetTree::new(&keys).path(&verifying_key).ok_or_else(|| {
+        Risc0Error::InputError("Public key is not in the key set".to_string())
+    })?;
+
+    // Create input for zkVM (public key, message, signature, leaf index, Merkle path)
+    let input = (
+        verifying_key.to_encoded_point(true),
+        message,
+        signature,
+        path.leaf_index,
+        path.siblings,
+    );
+    prove_input(ECDSA_VERIFY_MEMBERSHIP_ELF, &input, receipt_kind)
+}
+
+/// Verifies a receipt from [`risc0_prove_membership`] and returns the
+/// committed key set root and message.
+#[uniffi::export]
+pub fn risc0_verify_membership(
+    receipt_bytes: Vec<u8>,
+) -> Result<Risc0VerifyMembershipOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, ECDSA_VERIFY_MEMBERSHIP_ID)?;
+
+    let (key_set_root, message): ([u8; 32], Vec<u8>) = receipt
+        .journal
+        .decode()
+        .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+    let message_text = String::from_utf8(message.clone()).ok();
+
+    Ok(Risc0VerifyMembershipOutput {
+        is_valid: true,
+        key_set_root: key_set_root.to_vec(),
+        message,
+        message_text,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_key, sign};
+
+    #[test]
+    fn test_prove_verify_membership() {
+        let keys: Vec<_> = (0..5).map(|_| random_key()).collect();
+        let public_keys: Vec<Vec<u8>> =
+            keys.iter().map(|(_, public_key)| public_key.clone()).collect();
+
+        let message = b"One of us signed this".to_vec();
+        let proof_output = risc0_prove_membership(
+            public_keys.clone(),
+            public_keys[3].clone(),
+            message.clone(),
+            sign(&keys[3].0, &message),
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed for a member key");
+
+        let verify_output =
+            risc0_verify_membership(proof_output.receipt).expect("Verification should succeed");
+        assert!(verify_output.is_valid, "Proof should be valid");
+        assert_eq!(verify_output.message, message);
+        assert_eq!(
+            verify_output.key_set_root,
+            risc0_key_set_root(public_keys.clone()).unwrap()
+        );
+
+        // A signer outside the set cannot produce a proof
+        let (outsider, outsider_public_key) = random_key();
+        let result = risc0_prove_membership(
+            public_keys,
+            outsider_public_key,
+            message.clone(),
+            sign(&outsider, &message),
+            Risc0RequestedReceiptKind::Composite,
+        );
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+    }
+}
@@ -0,0 +1,240 @@
+//! Merkle trees over secp256r1 public keys.
+//!
+//! Hashing comes from `ecdsa_core::merkle`, shared with the guests: leaves are
+//! `SHA-256(0x00 || data)` and nodes are `SHA-256(0x01 || left || right)`.
+//! [`KeySetTree`] proves membership of a key, [`RevocationTree`] proves that a
+//! key is absent from a sorted set of revoked keys.
+
+use p256::ecdsa::VerifyingKey;
+
+pub use ecdsa_core::merkle::{
+    EMPTY_LEAF, MAX_SENTINEL, MIN_SENTINEL, NonMembershipWitness, hash_leaf, hash_node,
+};
+
+/// Leaf hash of a public key: its compressed SEC1 encoding.
+pub fn key_leaf(verifying_key: &VerifyingKey) -> [u8; 32] {
+    hash_leaf(verifying_key.to_encoded_point(true).as_bytes())
+}
+
+/// Leaf hash of a batch entry: the compressed SEC1 key followed by the
+/// SHA-256 of the signed message.
+pub fn batch_leaf(verifying_key: &VerifyingKey, message: &[u8]) -> [u8; 32] {
+    ecdsa_core::merkle::batch_leaf(verifying_key.to_encoded_point(true).as_bytes(), message)
+}
+
+/// Inclusion proof for one leaf.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerklePath {
+    /// Position of the leaf; bit `i` tells whether the node at level `i` is a right child.
+    pub leaf_index: u32,
+    /// Sibling hashes from the bottom level up.
+    pub siblings: Vec<[u8; 32]>,
+}
+
+impl MerklePath {
+    /// Recomputes the root for `leaf`.
+    pub fn root(&self, leaf: [u8; 32]) -> [u8; 32] {
+        ecdsa_core::merkle::root_from_path(leaf, self.leaf_index, &self.siblings)
+    }
+}
+
+/// Binary Merkle tree over precomputed leaf hashes.
+#[derive(Clone, Debug)]
+pub struct MerkleTree {
+    /// All levels, from the padded leaves up to the root.
+    levels: Vec<Vec<[u8; 32]>>,
+    leaf_count: usize,
+}
+
+impl MerkleTree {
+    /// Builds a tree, padding the leaves to a power of two with `padding`.
+    ///
+    /// An empty leaf list yields a single padding leaf.
+    pub fn from_leaves(mut leaves: Vec<[u8; 32]>, padding: [u8; 32]) -> Self {
+        let leaf_count = leaves.len();
+        leaves.resize(leaf_count.max(1).next_power_of_two(), padding);
+
+        let mut levels = vec![leaves];
+        while let Some(level) = levels.last().filter(|level| level.len() > 1) {
+            let next = level
+                .chunks(2)
+                .map(|pair| hash_node(&pair[0], &pair[1]))
+                .collect();
+            levels.push(next);
+        }
+
+        Self { levels, leaf_count }
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.levels.last().expect("a tree has at least one level")[0]
+    }
+
+    /// Leaf hashes, including padding.
+    pub fn leaves(&self) -> &[[u8; 32]] {
+        &self.levels[0]
+    }
+
+    /// Number of leaves before padding.
+    pub fn leaf_count(&self) -> usize {
+        self.leaf_count
+    }
+
+    /// Inclusion proof for the leaf at `index`, if it exists.
+    pub fn path(&self, index: usize) -> Option<MerklePath> {
+        if index >= self.leaves().len() {
+            return None;
+        }
+
+        let siblings = self.levels[..self.levels.len() - 1]
+            .iter()
+            .enumerate()
+            .map(|(level, nodes)| nodes[(index >> level) ^ 1])
+            .collect();
+
+        Some(MerklePath {
+            leaf_index: index as u32,
+            siblings,
+        })
+    }
+}
+
+/// Merkle tree over a registered set of public keys, used to prove that one
+/// of them signed without revealing which.
+#[derive(Clone, Debug)]
+pub struct KeySetTree {
+    tree: MerkleTree,
+}
+
+impl KeySetTree {
+    /// Builds the tree in the order the keys are given.
+    pub fn new(keys: &[VerifyingKey]) -> Self {
+        let leaves = keys.iter().map(key_leaf).collect();
+        Self {
+            tree: MerkleTree::from_leaves(leaves, EMPTY_LEAF),
+        }
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.tree.root()
+    }
+
+    /// Inclusion proof for `key`, or `None` if it is not in the set.
+    pub fn path(&self, key: &VerifyingKey) -> Option<MerklePath> {
+        let leaf = key_leaf(key);
+        let index = self.tree.leaves()[..self.tree.leaf_count()]
+            .iter()
+            .position(|candidate| *candidate == leaf)?;
+        self.tree.path(index)
+    }
+}
+
+/// Sorted Merkle tree over revoked public keys.
+///
+/// Values are sorted key leaf hashes framed by [`MIN_SENTINEL`] and
+/// [`MAX_SENTINEL`], and each is hashed again as a tree leaf, so every key
+/// that is not revoked falls strictly between two adjacent values and inner
+/// nodes can never pass as values.
+#[derive(Clone, Debug)]
+pub struct RevocationTree {
+    values: Vec<[u8; 32]>,
+    tree: MerkleTree,
+}
+
+impl RevocationTree {
+    pub fn new(revoked_keys: &[VerifyingKey]) -> Self {
+        let mut values: Vec<[u8; 32]> = revoked_keys.iter().map(key_leaf).collect();
+        values.sort_unstable();
+        values.dedup();
+        values.insert(0, MIN_SENTINEL);
+        values.push(MAX_SENTINEL);
+
+        let leaves = values.iter().map(|value| hash_leaf(value)).collect();
+        Self {
+            values,
+            tree: MerkleTree::from_leaves(leaves, hash_leaf(&MAX_SENTINEL)),
+        }
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.tree.root()
+    }
+
+    /// Witness that `key` is not revoked, or `None` if it is.
+    pub fn non_membership_witness(&self, key: &VerifyingKey) -> Option<NonMembershipWitness> {
+        let value = key_leaf(key);
+
+        // First value sorting after the key; the sentinels guarantee 0 < high < len.
+        let high = self.values.partition_point(|candidate| *candidate <= value);
+        let low = high - 1;
+        if self.values[low] == value {
+            return None;
+        }
+
+        let low_path = self.tree.path(low)?;
+        Some(NonMembershipWitness {
+            low_index: low_path.leaf_index,
+            low_value: self.values[low],
+            low_path: low_path.siblings,
+            high_value: self.values[high],
+            high_path: self.tree.path(high)?.siblings,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::SigningKey;
+    use rand_core::OsRng;
+
+    fn random_keys(count: usize) -> Vec<VerifyingKey> {
+        (0..count)
+            .map(|_| VerifyingKey::from(&SigningKey::random(&mut OsRng)))
+            .collect()
+    }
+
+    #[test]
+    fn test_paths_recompute_root() {
+        for count in [1, 2, 3, 5, 8] {
+            let keys = random_keys(count);
+            let tree = KeySetTree::new(&keys);
+
+            for key in &keys {
+                let path = tree.path(key).expect("Every key should have a path");
+                assert_eq!(path.root(key_leaf(key)), tree.root());
+            }
+        }
+    }
+
+    #[test]
+    fn test_path_for_unknown_key() {
+        let tree = KeySetTree::new(&random_keys(3));
+        let outsider = random_keys(1).remove(0);
+        assert!(tree.path(&outsider).is_none());
+    }
+
+    #[test]
+    fn test_non_membership_witness() {
+        let revoked = random_keys(4);
+        let tree = RevocationTree::new(&revoked);
+
+        for key in &revoked {
+            assert!(tree.non_membership_witness(key).is_none());
+        }
+
+        for key in &random_keys(4) {
+            let witness = tree
+                .non_membership_witness(key)
+                .expect("Unrevoked keys should have a witness");
+            assert_eq!(witness.root(key_leaf(key)), tree.root());
+        }
+    }
+
+    #[test]
+    fn test_empty_revocation_list() {
+        let tree = RevocationTree::new(&[]);
+        let key = random_keys(1).remove(0);
+        assert!(tree.non_membership_witness(&key).is_some());
+    }
+}
@@ -0,0 +1,73 @@
+//! Prehashed-message proofs for large documents: the host hashes the message
+//! and the guest verifies the signature over the SHA-256 digest.
+
+use ecdsa_methods::ECDSA_VERIFY_PREHASH_ID;
+use p256::EncodedPoint;
+
+use crate::{decode_journal_key, signer_public_key, verify_receipt, Risc0Error, Risc0PublicKey};
+
+/// Verify output for receipts produced in [`crate::Risc0MessageMode::Prehashed`].
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0VerifyDigestOutput {
+    pub is_valid: bool,
+    /// SHA-256 digest of the signed message.
+    pub message_digest: Vec<u8>,
+    pub signer: Risc0PublicKey,
+}
+
+/// Verifies a receipt produced in [`crate::Risc0MessageMode::Prehashed`] and
+/// returns the committed message digest. Compare it with the SHA-256 of the
+/// document the caller expects.
+#[uniffi::export]
+pub fn risc0_verify_prehashed(
+    receipt_bytes: Vec<u8>,
+) -> Result<Risc0VerifyDigestOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, ECDSA_VERIFY_PREHASH_ID)?;
+
+    let (receipt_verifying_key, message_digest): (EncodedPoint, [u8; 32]) = receipt
+        .journal
+        .decode()
+        .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+    let verifying_key = decode_journal_key(&receipt_verifying_key)?;
+
+    Ok(Risc0VerifyDigestOutput {
+        is_valid: true,
+        message_digest: message_digest.to_vec(),
+        signer: signer_public_key(&verifying_key),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_key, sign};
+    use crate::{
+        risc0_prove_signature_with_mode, risc0_verify_bytes, Risc0MessageMode,
+        Risc0RequestedReceiptKind,
+    };
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn test_prove_verify_prehashed_roundtrip() {
+        let (signing_key, public_key) = random_key();
+        let document = vec![0x42u8; 1 << 20];
+
+        let proof_output = risc0_prove_signature_with_mode(
+            public_key.clone(),
+            document.clone(),
+            sign(&signing_key, &document),
+            Risc0MessageMode::Prehashed,
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed in prehashed mode");
+
+        // The receipt belongs to the prehash guest, not the full-message one
+        assert!(risc0_verify_bytes(proof_output.receipt.clone()).is_err());
+
+        let verify_output = risc0_verify_prehashed(proof_output.receipt)
+            .expect("Verification should succeed");
+        assert!(verify_output.is_valid, "Proof should be valid");
+        assert_eq!(verify_output.message_digest, Sha256::digest(&document).to_vec());
+        assert_eq!(verify_output.signer.compressed, public_key);
+    }
+}
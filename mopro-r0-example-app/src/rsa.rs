@@ -0,0 +1,285 @@
+//! RSA proofs, for legacy JWTs (RS256 / PS256), X.509 chains and DKIM.
+//!
+//! Keys are passed as a big-endian modulus and public exponent, as in a JWK.
+//! Messages are hashed with SHA-256. The guest commits
+//! `(SHA-256 of the modulus, exponent, scheme, message)` so the journal stays
+//! small even for 4096-bit keys.
+
+use ::rsa::{BigUint, Pkcs1v15Sign, Pss, RsaPrivateKey, RsaPublicKey, traits::PublicKeyParts};
+use ecdsa_methods::{RSA_VERIFY_ELF, RSA_VERIFY_ID};
+use rand_core::OsRng;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    prove_input, verify_receipt, Risc0Error, Risc0ProofOutput, Risc0RequestedReceiptKind,
+};
+
+/// Modulus sizes accepted by the RSA guest.
+const MODULUS_BITS: [usize; 3] = [2048, 3072, 4096];
+
+/// RSA signature padding scheme.
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Risc0RsaScheme {
+    /// PKCS#1 v1.5, as used by RS256.
+    Pkcs1v15,
+    /// PSS with a 32-byte salt, as used by PS256.
+    Pss,
+}
+
+impl Risc0RsaScheme {
+    /// Tag passed to and committed by the guest.
+    fn tag(self) -> u8 {
+        match self {
+            Risc0RsaScheme::Pkcs1v15 => 0,
+            Risc0RsaScheme::Pss => 1,
+        }
+    }
+
+    fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Some(Risc0RsaScheme::Pkcs1v15),
+            1 => Some(Risc0RsaScheme::Pss),
+            _ => None,
+        }
+    }
+}
+
+/// Verify output for RSA signature proofs.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0VerifyRsaOutput {
+    pub is_valid: bool,
+    /// SHA-256 of the big-endian modulus, as returned by [`rsa_modulus_hash`].
+    pub modulus_hash: Vec<u8>,
+    /// Big-endian public exponent.
+    pub exponent: Vec<u8>,
+    pub scheme: Risc0RsaScheme,
+    pub message: Vec<u8>,
+    /// The message as text, present only when it is valid UTF-8.
+    pub message_text: Option<String>,
+}
+
+/// `SHA-256(modulus)`, with the modulus big-endian and without leading zeros.
+fn modulus_hash(modulus: &BigUint) -> [u8; 32] {
+    Sha256::digest(modulus.to_bytes_be()).into()
+}
+
+/// Parses a big-endian modulus and exponent into a key the guest accepts.
+fn parse_rsa_public_key(modulus: &[u8], exponent: &[u8]) -> Result<RsaPublicKey, Risc0Error> {
+    let public_key =
+        RsaPublicKey::new(BigUint::from_bytes_be(modulus), BigUint::from_bytes_be(exponent))
+            .map_err(|e| Risc0Error::InputError(format!("Invalid public key: {}", e)))?;
+    let modulus_bits = public_key.n().bits();
+    if !MODULUS_BITS.contains(&modulus_bits) {
+        return Err(Risc0Error::InputError(format!(
+            "Unsupported RSA modulus size: {} bits, expected one of {:?}",
+            modulus_bits, MODULUS_BITS
+        )));
+    }
+    Ok(public_key)
+}
+
+/// Proves an RSA signature over `message` inside the RSA guest.
+fn prove_rsa(
+    public_key: &RsaPublicKey,
+    scheme: Risc0RsaScheme,
+    message: &[u8],
+    signature: &[u8],
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    // Create input for zkVM (modulus, exponent, scheme, message, signature)
+    let input = (
+        public_key.n().to_bytes_be(),
+        public_key.e().to_bytes_be(),
+        scheme.tag(),
+        message,
+        signature,
+    );
+    prove_input(RSA_VERIFY_ELF, &input, receipt_kind)
+}
+
+/// Computes the modulus hash that [`rsa_verify`] reports, so verifiers can
+/// pin the expected issuer key.
+#[uniffi::export]
+pub fn rsa_modulus_hash(modulus: Vec<u8>) -> Vec<u8> {
+    modulus_hash(&BigUint::from_bytes_be(&modulus)).to_vec()
+}
+
+/// Same as [`crate::risc0_prove`] on RSA, with a freshly generated 2048-bit
+/// key. Key generation takes noticeably longer than for elliptic curves.
+#[uniffi::export]
+pub fn rsa_prove(
+    message: String,
+    scheme: Risc0RsaScheme,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let private_key = RsaPrivateKey::new(&mut OsRng, 2048)
+        .map_err(|e| Risc0Error::ProveError(format!("Failed to generate RSA key: {}", e)))?;
+    let message_digest = Sha256::digest(message.as_bytes());
+    let signature = match scheme {
+        Risc0RsaScheme::Pkcs1v15 => {
+            private_key.sign_with_rng(&mut OsRng, Pkcs1v15Sign::new::<Sha256>(), &message_digest)
+        }
+        Risc0RsaScheme::Pss => {
+            private_key.sign_with_rng(&mut OsRng, Pss::new::<Sha256>(), &message_digest)
+        }
+    }
+    .map_err(|e| Risc0Error::ProveError(format!("Failed to sign message: {}", e)))?;
+
+    prove_rsa(
+        &private_key.to_public_key(),
+        scheme,
+        message.as_bytes(),
+        &signature,
+        receipt_kind,
+    )
+}
+
+/// Same as [`crate::risc0_prove_signature`] for an RSA-2048, RSA-3072 or
+/// RSA-4096 key given as a big-endian modulus and public exponent.
+///
+/// The message is hashed with SHA-256. The signature is checked on the host
+/// first so that invalid input fails fast instead of inside the prover.
+#[uniffi::export]
+pub fn rsa_prove_signature(
+    modulus: Vec<u8>,
+    exponent: Vec<u8>,
+    scheme: Risc0RsaScheme,
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let public_key = parse_rsa_public_key(&modulus, &exponent)?;
+
+    let message_digest = Sha256::digest(&message);
+    match scheme {
+        Risc0RsaScheme::Pkcs1v15 => {
+            public_key.verify(Pkcs1v15Sign::new::<Sha256>(), &message_digest, &signature)
+        }
+        Risc0RsaScheme::Pss => public_key.verify(Pss::new::<Sha256>(), &message_digest, &signature),
+    }
+    .map_err(|e| Risc0Error::InputError(format!("Signature does not verify: {}", e)))?;
+
+    prove_rsa(&public_key, scheme, &message, &signature, receipt_kind)
+}
+
+/// Verifies a receipt from [`rsa_prove`] or [`rsa_prove_signature`].
+#[uniffi::export]
+pub fn rsa_verify(receipt_bytes: Vec<u8>) -> Result<Risc0VerifyRsaOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, RSA_VERIFY_ID)?;
+
+    let (modulus_hash, exponent, scheme, message): ([u8; 32], Vec<u8>, u8, Vec<u8>) = receipt
+        .journal
+        .decode()
+        .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+    let scheme = Risc0RsaScheme::from_tag(scheme).ok_or_else(|| {
+        Risc0Error::DecodeError(format!("Unknown RSA scheme in journal: {}", scheme))
+    })?;
+    let message_text = String::from_utf8(message.clone()).ok();
+
+    Ok(Risc0VerifyRsaOutput {
+        is_valid: true,
+        modulus_hash: modulus_hash.to_vec(),
+        exponent,
+        scheme,
+        message,
+        message_text,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rsa_prove_verify_roundtrip() {
+        for scheme in [Risc0RsaScheme::Pkcs1v15, Risc0RsaScheme::Pss] {
+            let message = "Hello, RSA!".to_string();
+            let proof_output =
+                rsa_prove(message.clone(), scheme, Risc0RequestedReceiptKind::Composite)
+                    .expect("Proving should succeed");
+
+            let verify_output =
+                rsa_verify(proof_output.receipt.clone()).expect("Verification should succeed");
+            assert!(verify_output.is_valid, "Proof should be valid");
+            assert_eq!(verify_output.scheme, scheme);
+            assert_eq!(verify_output.message_text, Some(message));
+            assert_eq!(verify_output.exponent, vec![0x01, 0x00, 0x01]);
+
+            // An RSA receipt is not an ECDSA one
+            assert!(crate::risc0_verify(proof_output.receipt).is_err());
+        }
+    }
+
+    #[test]
+    fn test_rsa_prove_signature() {
+        let private_key = RsaPrivateKey::new(&mut OsRng, 3072).unwrap();
+        let public_key = private_key.to_public_key();
+        let message = b"DKIM-Signature header".to_vec();
+        let signature = private_key
+            .sign(Pkcs1v15Sign::new::<Sha256>(), &Sha256::digest(&message))
+            .unwrap();
+
+        let modulus = public_key.n().to_bytes_be();
+        let proof_output = rsa_prove_signature(
+            modulus.clone(),
+            public_key.e().to_bytes_be(),
+            Risc0RsaScheme::Pkcs1v15,
+            message.clone(),
+            signature,
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed for an external signature");
+
+        let verify_output = rsa_verify(proof_output.receipt).expect("Verification should succeed");
+        assert_eq!(verify_output.message, message);
+        assert_eq!(verify_output.modulus_hash, rsa_modulus_hash(modulus.clone()));
+
+        // A leading zero byte does not change the modulus hash
+        let mut padded_modulus = vec![0];
+        padded_modulus.extend_from_slice(&modulus);
+        assert_eq!(rsa_modulus_hash(padded_modulus), verify_output.modulus_hash);
+    }
+
+    #[test]
+    fn test_rsa_prove_signature_rejects_bad_input() {
+        let private_key = RsaPrivateKey::new(&mut OsRng, 2048).unwrap();
+        let public_key = private_key.to_public_key();
+        let signature = private_key
+            .sign(Pkcs1v15Sign::new::<Sha256>(), &Sha256::digest(b"original"))
+            .unwrap();
+
+        // Wrong message
+        let result = rsa_prove_signature(
+            public_key.n().to_bytes_be(),
+            public_key.e().to_bytes_be(),
+            Risc0RsaScheme::Pkcs1v15,
+            b"tampered".to_vec(),
+            signature.clone(),
+            Risc0RequestedReceiptKind::Composite,
+        );
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+
+        // Wrong scheme
+        let result = rsa_prove_signature(
+            public_key.n().to_bytes_be(),
+            public_key.e().to_bytes_be(),
+            Risc0RsaScheme::Pss,
+            b"original".to_vec(),
+            signature,
+            Risc0RequestedReceiptKind::Composite,
+        );
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+
+        // Unsupported modulus size
+        let small_key = RsaPrivateKey::new(&mut OsRng, 1024).unwrap().to_public_key();
+        let result = rsa_prove_signature(
+            small_key.n().to_bytes_be(),
+            small_key.e().to_bytes_be(),
+            Risc0RsaScheme::Pkcs1v15,
+            b"original".to_vec(),
+            vec![0; 128],
+            Risc0RequestedReceiptKind::Composite,
+        );
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+    }
+}
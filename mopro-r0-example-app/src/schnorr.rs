@@ -0,0 +1,184 @@
+//! BIP-340 Schnorr proofs, for Bitcoin Taproot keys.
+//!
+//! Keys are 32-byte x-only secp256k1 keys and messages are 32 bytes signed
+//! raw, as BIP-340 specifies. The guest commits `(x-only key, message)`.
+
+use ecdsa_methods::{SCHNORR_VERIFY_ELF, SCHNORR_VERIFY_ID};
+use k256::{
+    PublicKey,
+    elliptic_curve::sec1::ToEncodedPoint,
+    schnorr::{Signature, SigningKey, VerifyingKey, signature::hazmat::PrehashVerifier},
+};
+use rand_core::{OsRng, RngCore};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    prove_input, public_key_record, verify_receipt, Risc0Curve, Risc0Error, Risc0ProofOutput,
+    Risc0RequestedReceiptKind, Risc0VerifyBytesOutput,
+};
+
+/// Proves a BIP-340 signature over `message` inside the Schnorr guest.
+fn prove_schnorr(
+    verifying_key: &VerifyingKey,
+    message: [u8; 32],
+    signature: &Signature,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    // Create input for zkVM (public key, message, signature)
+    let input = (
+        <[u8; 32]>::from(verifying_key.to_bytes()),
+        message,
+        signature.to_bytes().to_vec(),
+    );
+    prove_input(SCHNORR_VERIFY_ELF, &input, receipt_kind)
+}
+
+/// Same as [`crate::risc0_prove`] with a BIP-340 signature from a freshly
+/// generated Taproot key. BIP-340 signs 32-byte messages, so the key signs
+/// and the journal commits the SHA-256 of `message`.
+#[uniffi::export]
+pub fn schnorr_prove(
+    message: String,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let signing_key = SigningKey::random(&mut OsRng);
+    let mut aux_rand = [0u8; 32];
+    OsRng.fill_bytes(&mut aux_rand);
+    let message: [u8; 32] = Sha256::digest(message.as_bytes()).into();
+    let signature = signing_key
+        .sign_prehash_with_aux_rand(&message, &aux_rand)
+        .map_err(|e| Risc0Error::ProveError(format!("Failed to sign message: {}", e)))?;
+
+    prove_schnorr(
+        signing_key.verifying_key(),
+        message,
+        &signature,
+        receipt_kind,
+    )
+}
+
+/// Same as [`crate::risc0_prove_signature`] for a 32-byte x-only public key
+/// and 64-byte BIP-340 signature over the raw 32-byte `message`, usually a
+/// sighash.
+#[uniffi::export]
+pub fn schnorr_prove_signature(
+    public_key: Vec<u8>,
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let verifying_key = VerifyingKey::from_bytes(&public_key)
+        .map_err(|e| Risc0Error::InputError(format!("Invalid public key: {}", e)))?;
+    let message: [u8; 32] = message
+        .try_into()
+        .map_err(|_| Risc0Error::InputError("Message must be 32 bytes".to_string()))?;
+    let signature = Signature::try_from(signature.as_slice())
+        .map_err(|e| Risc0Error::InputError(format!("Invalid signature: {}", e)))?;
+
+    verifying_key
+        .verify_prehash(&message, &signature)
+        .map_err(|e| Risc0Error::InputError(format!("Signature does not verify: {}", e)))?;
+
+    prove_schnorr(&verifying_key, message, &signature, receipt_kind)
+}
+
+/// Verifies a receipt from [`schnorr_prove`] or [`schnorr_prove_signature`].
+///
+/// The signer reports `Risc0Curve::Bip340` with the SEC1 encodings of the
+/// even-Y point BIP-340 assigns to the x-only key, so the x-only key is
+/// `compressed[1..]`.
+#[uniffi::export]
+pub fn schnorr_verify(receipt_bytes: Vec<u8>) -> Result<Risc0VerifyBytesOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, SCHNORR_VERIFY_ID)?;
+
+    let (receipt_verifying_key, message): ([u8; 32], [u8; 32]) = receipt
+        .journal
+        .decode()
+        .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+    let message = message.to_vec();
+    let mut compressed = vec![0x02];
+    compressed.extend_from_slice(&receipt_verifying_key);
+    let public_key = PublicKey::from_sec1_bytes(&compressed)
+        .map_err(|e| Risc0Error::DecodeError(format!("Invalid public key in journal: {}", e)))?;
+    let message_text = String::from_utf8(message.clone()).ok();
+
+    Ok(Risc0VerifyBytesOutput {
+        is_valid: true,
+        message,
+        message_text,
+        signer: public_key_record(
+            Risc0Curve::Bip340,
+            compressed,
+            public_key.to_encoded_point(false).as_bytes().to_vec(),
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schnorr_prove_verify_roundtrip() {
+        let message = "Hello, Taproot!".to_string();
+        let proof_output = schnorr_prove(message.clone(), Risc0RequestedReceiptKind::Composite)
+            .expect("Proving should succeed");
+
+        let verify_output =
+            schnorr_verify(proof_output.receipt.clone()).expect("Verification should succeed");
+        assert!(verify_output.is_valid, "Proof should be valid");
+        assert_eq!(verify_output.message, Sha256::digest(message.as_bytes()).to_vec());
+        assert_eq!(verify_output.signer.curve, Risc0Curve::Bip340);
+        assert_eq!(verify_output.signer.compressed[0], 0x02);
+
+        // A Schnorr receipt is not a secp256k1 ECDSA one
+        assert!(crate::secp256k1::secp256k1_verify(proof_output.receipt).is_err());
+    }
+
+    #[test]
+    fn test_schnorr_prove_signature_bip340_vector() {
+        // BIP-340 test vector 0
+        let public_key =
+            hex::decode("f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9")
+                .unwrap();
+        let message = vec![0u8; 32];
+        let signature = hex::decode(
+            "e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca8215\
+             25f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0",
+        )
+        .unwrap();
+
+        let proof_output = schnorr_prove_signature(
+            public_key.clone(),
+            message.clone(),
+            signature.clone(),
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed for the test vector");
+
+        let verify_output =
+            schnorr_verify(proof_output.receipt).expect("Verification should succeed");
+        assert_eq!(verify_output.message, message);
+        assert_eq!(verify_output.signer.compressed[1..], public_key[..]);
+
+        // Flipping a message bit invalidates the signature
+        let mut tampered = message;
+        tampered[31] ^= 1;
+        let result = schnorr_prove_signature(
+            public_key.clone(),
+            tampered,
+            signature.clone(),
+            Risc0RequestedReceiptKind::Composite,
+        );
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+
+        // BIP-340 messages are exactly 32 bytes
+        let result = schnorr_prove_signature(
+            public_key,
+            vec![0u8; 31],
+            signature,
+            Risc0RequestedReceiptKind::Composite,
+        );
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+    }
+}
@@ -0,0 +1,155 @@
+//! secp256k1 ECDSA proofs, for keys held in Ethereum and Bitcoin wallets.
+//!
+//! The guest commits `(curve, compressed SEC1 key, message)` so secp256k1
+//! statements cannot be mistaken for secp256r1 ones.
+
+use ecdsa_methods::{SECP256K1_VERIFY_ELF, SECP256K1_VERIFY_ID};
+use k256::ecdsa::{
+    Signature, SigningKey, VerifyingKey,
+    signature::{Signer, Verifier},
+};
+use rand_core::OsRng;
+
+use crate::{
+    prove_input, public_key_record, verify_receipt, Risc0Curve, Risc0Error, Risc0ProofOutput,
+    Risc0RequestedReceiptKind, Risc0VerifyBytesOutput,
+};
+
+/// Curve tag committed by the secp256k1 guest.
+const CURVE_NAME: &str = "secp256k1";
+
+/// Proves a secp256k1 signature over `message` inside the secp256k1 guest.
+fn prove_secp256k1(
+    verifying_key: &VerifyingKey,
+    message: &[u8],
+    signature: &Signature,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    // Create input for zkVM (public key, message, signature)
+    let input = (
+        verifying_key.to_encoded_point(true).as_bytes().to_vec(),
+        message,
+        signature.to_bytes().to_vec(),
+    );
+    prove_input(SECP256K1_VERIFY_ELF, &input, receipt_kind)
+}
+
+/// Same as [`crate::risc0_prove`] on secp256k1, with a freshly generated keypair.
+#[uniffi::export]
+pub fn secp256k1_prove(
+    message: String,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let signing_key = SigningKey::random(&mut OsRng);
+    let signature: Signature = signing_key.sign(message.as_bytes());
+
+    prove_secp256k1(
+        signing_key.verifying_key(),
+        message.as_bytes(),
+        &signature,
+        receipt_kind,
+    )
+}
+
+/// Same as [`crate::risc0_prove_signature`] for a secp256k1 key and signature.
+///
+/// The message is hashed with SHA-256. The signature may be `r || s` or DER.
+#[uniffi::export]
+pub fn secp256k1_prove_signature(
+    public_key: Vec<u8>,
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let verifying_key = VerifyingKey::from_sec1_bytes(&public_key)
+        .map_err(|e| Risc0Error::InputError(format!("Invalid public key: {}", e)))?;
+    let signature = Signature::from_slice(&signature)
+        .or_else(|_| Signature::from_der(&signature))
+        .map_err(|e| Risc0Error::InputError(format!("Invalid signature: {}", e)))?;
+    // The guest rejects high-S signatures, as Bitcoin and Ethereum do, so
+    // normalize third-party signatures first.
+    let signature = signature.normalize_s().unwrap_or(signature);
+
+    verifying_key
+        .verify(&message, &signature)
+        .map_err(|e| Risc0Error::InputError(format!("Signature does not verify: {}", e)))?;
+
+    prove_secp256k1(&verifying_key, &message, &signature, receipt_kind)
+}
+
+/// Verifies a receipt from [`secp256k1_prove`] or [`secp256k1_prove_signature`].
+#[uniffi::export]
+pub fn secp256k1_verify(receipt_bytes: Vec<u8>) -> Result<Risc0VerifyBytesOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, SECP256K1_VERIFY_ID)?;
+
+    let (curve, receipt_verifying_key, message): (String, Vec<u8>, Vec<u8>) = receipt
+        .journal
+        .decode()
+        .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+    if curve != CURVE_NAME {
+        return Err(Risc0Error::DecodeError(format!(
+            "Unexpected curve in journal: {}",
+            curve
+        )));
+    }
+    let verifying_key = VerifyingKey::from_sec1_bytes(&receipt_verifying_key)
+        .map_err(|e| Risc0Error::DecodeError(format!("Invalid public key in journal: {}", e)))?;
+    let message_text = String::from_utf8(message.clone()).ok();
+
+    Ok(Risc0VerifyBytesOutput {
+        is_valid: true,
+        message,
+        message_text,
+        signer: public_key_record(
+            Risc0Curve::Secp256k1,
+            verifying_key.to_encoded_point(true).as_bytes().to_vec(),
+            verifying_key.to_encoded_point(false).as_bytes().to_vec(),
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secp256k1_prove_verify_roundtrip() {
+        let message = "Hello, secp256k1!".to_string();
+        let proof_output = secp256k1_prove(message.clone(), Risc0RequestedReceiptKind::Composite)
+            .expect("Proving should succeed");
+
+        let verify_output =
+            secp256k1_verify(proof_output.receipt.clone()).expect("Verification should succeed");
+        assert!(verify_output.is_valid, "Proof should be valid");
+        assert_eq!(verify_output.message_text, Some(message));
+        assert_eq!(verify_output.signer.curve, Risc0Curve::Secp256k1);
+
+        // A secp256k1 receipt is not a secp256r1 one
+        assert!(crate::risc0_verify(proof_output.receipt).is_err());
+    }
+
+    #[test]
+    fn test_secp256k1_prove_signature() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let message = b"Signed by a wallet".to_vec();
+        let signature: Signature = signing_key.sign(&message);
+
+        let public_key = signing_key
+            .verifying_key()
+            .to_encoded_point(false)
+            .as_bytes()
+            .to_vec();
+        let proof_output = secp256k1_prove_signature(
+            public_key.clone(),
+            message.clone(),
+            signature.to_der().as_bytes().to_vec(),
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed for an external signature");
+
+        let verify_output =
+            secp256k1_verify(proof_output.receipt).expect("Verification should succeed");
+        assert_eq!(verify_output.message, message);
+        assert_eq!(verify_output.signer.uncompressed, public_key);
+    }
+}
@@ -0,0 +1,21 @@
+//! Helpers shared by the P-256 tests.
+
+use p256::ecdsa::{Signature, SigningKey, signature::Signer};
+use rand_core::OsRng;
+
+/// Returns a fresh secp256r1 signing key and its compressed SEC1 public key.
+pub(crate) fn random_key() -> (SigningKey, Vec<u8>) {
+    let signing_key = SigningKey::random(&mut OsRng);
+    let public_key = signing_key
+        .verifying_key()
+        .to_encoded_point(true)
+        .as_bytes()
+        .to_vec();
+    (signing_key, public_key)
+}
+
+/// Signs `message` and returns the fixed-size `r || s` signature.
+pub(crate) fn sign(signing_key: &SigningKey, message: &[u8]) -> Vec<u8> {
+    let signature: Signature = signing_key.sign(message);
+    signature.to_bytes().to_vec()
+}
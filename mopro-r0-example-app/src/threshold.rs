@@ -0,0 +1,202 @@
+//! k-of-n threshold proofs: at least `threshold` distinct keys of a set
+//! signed the message, without revealing which.
+
+use ecdsa_core::ThresholdInput;
+use ecdsa_methods::{ECDSA_VERIFY_THRESHOLD_ELF, ECDSA_VERIFY_THRESHOLD_ID};
+use p256::{EncodedPoint, ecdsa::signature::Verifier};
+
+use crate::{
+    parse_public_keys, parse_signature, prove_input, verify_receipt, Risc0Error,
+    Risc0ProofOutput, Risc0RequestedReceiptKind,
+};
+
+/// A signature tagged with the index of its key in the key set.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0ThresholdSignature {
+    /// Index of the signing key in `public_keys`.
+    pub key_index: u32,
+    /// Signature as `r || s` or ASN.1 DER.
+    pub signature: Vec<u8>,
+}
+
+/// Verify output for k-of-n threshold proofs.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0VerifyThresholdOutput {
+    pub is_valid: bool,
+    /// Merkle root of the key set, as returned by
+    /// [`crate::membership::risc0_key_set_root`].
+    pub key_set_root: Vec<u8>,
+    /// Minimum number of distinct keys that signed the message.
+    pub threshold: u32,
+    pub message: Vec<u8>,
+    /// The message as text, present only when it is valid UTF-8.
+    pub message_text: Option<String>,
+}
+
+/// Proves that at least `threshold` distinct keys of `public_keys` signed
+/// `message`, without revealing which.
+///
+/// Each of `signatures` names the index of its key in `public_keys` and is
+/// checked against that key only. The journal commits the Merkle root of
+/// `public_keys`, the threshold and the message; compare the root with
+/// [`crate::membership::risc0_key_set_root`].
+#[uniffi::export]
+pub fn risc0_prove_threshold(
+    public_keys: Vec<Vec<u8>>,
+    threshold: u32,
+    message: Vec<u8>,
+    signatures: Vec<Risc0ThresholdSignature>,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let keys = parse_public_keys(&public_keys)?;
+    if threshold == 0 {
+        return Err(Risc0Error::InputError("Threshold must be at least 1".to_string()));
+    }
+
+    // Check every signature against the key it names, rejecting indices
+    // outside the set and keys that signed more than once.
+    let mut signed_keys: Vec<EncodedPoint> = Vec::with_capacity(signatures.len());
+    let mut indexed_signatures = Vec::with_capacity(signatures.len());
+    for (signature_index, entry) in signatures.iter().enumerate() {
+        let key = keys.get(entry.key_index as usize).ok_or_else(|| {
+            Risc0Error::InputError(format!(
+                "Signature {} names key {} outside the set of {} keys",
+                signature_index,
+                entry.key_index,
+                keys.len()
+            ))
+        })?;
+        let signature = parse_signature(&entry.signature)?;
+        key.verify(&message, &signature).map_err(|e| {
+            Risc0Error::InputError(format!(
+                "Signature {} does not verify under key {}: {}",
+                signature_index, entry.key_index, e
+            ))
+        })?;
+
+        let signer = key.to_encoded_point(true);
+        if signed_keys.contains(&signer) {
+            return Err(Risc0Error::InputError(format!(
+                "Signature {} is from a key that already signed",
+                signature_index
+            )));
+        }
+        signed_keys.push(signer);
+        indexed_signatures.push((entry.key_index, signature));
+    }
+    if indexed_signatures.len() < threshold as usize {
+        return Err(Risc0Error::InputError(format!(
+            "{} signatures do not meet the threshold of {}",
+            indexed_signatures.len(),
+            threshold
+        )));
+    }
+
+    // Create input for zkVM (key set, threshold, message, indexed signatures)
+    let input = ThresholdInput {
+        key_set: keys.iter().map(|key| key.to_encoded_point(true)).collect(),
+        threshold,
+        message,
+        signatures: indexed_signatures,
+    };
+    prove_input(ECDSA_VERIFY_THRESHOLD_ELF, &input, receipt_kind)
+}
+
+/// Verifies a receipt from [`risc0_prove_threshold`] and returns the committed
+/// key set root, threshold and message.
+#[uniffi::export]
+pub fn risc0_verify_threshold(
+    receipt_bytes: Vec<u8>,
+) -> Result<Risc0VerifyThresholdOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, ECDSA_VERIFY_THRESHOLD_ID)?;
+
+    let (key_set_root, threshold, message): ([u8; 32], u32, Vec<u8>) = receipt
+        .journal
+        .decode()
+        .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+    let message_text = String::from_utf8(message.clone()).ok();
+
+    Ok(Risc0VerifyThresholdOutput {
+        is_valid: true,
+        key_set_root: key_set_root.to_vec(),
+        threshold,
+        message,
+        message_text,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::membership::risc0_key_set_root;
+    use crate::test_utils::{random_key, sign};
+
+    #[test]
+    fn test_prove_verify_threshold() {
+        let keys: Vec<_> = (0..5).map(|_| random_key()).collect();
+        let public_keys: Vec<Vec<u8>> =
+            keys.iter().map(|(_, public_key)| public_key.clone()).collect();
+
+        let message = b"Approve transfer #7".to_vec();
+        let sign = |index: usize| Risc0ThresholdSignature {
+            key_index: index as u32,
+            signature: sign(&keys[index].0, &message),
+        };
+
+        let proof_output = risc0_prove_threshold(
+            public_keys.clone(),
+            2,
+            message.clone(),
+            vec![sign(4), sign(1)],
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed with enough signers");
+
+        let verify_output =
+            risc0_verify_threshold(proof_output.receipt).expect("Verification should succeed");
+        assert!(verify_output.is_valid, "Proof should be valid");
+        assert_eq!(verify_output.threshold, 2);
+        assert_eq!(verify_output.message, message);
+        assert_eq!(verify_output.key_set_root, risc0_key_set_root(public_keys.clone()).unwrap());
+
+        // The same key signing twice does not count twice
+        let result = risc0_prove_threshold(
+            public_keys.clone(),
+            2,
+            message.clone(),
+            vec![sign(1), sign(1)],
+            Risc0RequestedReceiptKind::Composite,
+        );
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+
+        // A signature checked against a key other than the one that made it
+        let result = risc0_prove_threshold(
+            public_keys.clone(),
+            1,
+            message.clone(),
+            vec![Risc0ThresholdSignature { key_index: 2, ..sign(3) }],
+            Risc0RequestedReceiptKind::Composite,
+        );
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+
+        // A key index outside the set
+        let result = risc0_prove_threshold(
+            public_keys.clone(),
+            1,
+            message.clone(),
+            vec![Risc0ThresholdSignature { key_index: 5, ..sign(3) }],
+            Risc0RequestedReceiptKind::Composite,
+        );
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+
+        // Too few signers
+        let result = risc0_prove_threshold(
+            public_keys,
+            3,
+            message.clone(),
+            vec![sign(0), sign(2)],
+            Risc0RequestedReceiptKind::Composite,
+        );
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+    }
+}
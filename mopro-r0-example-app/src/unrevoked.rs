@@ -0,0 +1,133 @@
+//! Revocation-aware proofs: the signer key is shown not to be in a sorted
+//! revocation tree, whose root the journal commits.
+
+use ecdsa_core::UnrevokedInput;
+use ecdsa_methods::{ECDSA_VERIFY_UNREVOKED_ELF, ECDSA_VERIFY_UNREVOKED_ID};
+use p256::EncodedPoint;
+
+use crate::merkle::RevocationTree;
+use crate::{
+    decode_journal_key, parse_public_keys, parse_signed_message, prove_input, signer_public_key,
+    verify_receipt, Risc0Error, Risc0ProofOutput, Risc0PublicKey, Risc0RequestedReceiptKind,
+};
+
+/// Verify output for proofs that the signer key is not revoked.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0VerifyUnrevokedOutput {
+    pub is_valid: bool,
+    pub message: Vec<u8>,
+    /// The message as text, present only when it is valid UTF-8.
+    pub message_text: Option<String>,
+    pub signer: Risc0PublicKey,
+    /// Root of the revocation tree the signer was checked against.
+    pub revocation_root: Vec<u8>,
+}
+
+/// Returns the root of the revocation tree over `revoked_public_keys`, as
+/// committed by [`risc0_prove_unrevoked`]. The order of the keys does not matter.
+#[uniffi::export]
+pub fn risc0_revocation_root(revoked_public_keys: Vec<Vec<u8>>) -> Result<Vec<u8>, Risc0Error> {
+    let revoked_keys = parse_public_keys(&revoked_public_keys)?;
+    Ok(RevocationTree::new(&revoked_keys).root().to_vec())
+}
+
+/// Proves a signature and that the signer key is not in `revoked_public_keys`.
+///
+/// The journal commits the key, the message, and the revocation root, so the
+/// verifier can check that the proof was made against the current list.
+#[uniffi::export]
+pub fn risc0_prove_unrevoked(
+    revoked_public_keys: Vec<Vec<u8>>,
+    public_key: Vec<u8>,
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let revoked_keys = parse_public_keys(&revoked_public_keys)?;
+    let (verifying_key, signature) = parse_signed_message(&public_key, &message, &signature)?;
+
+    let witness = RevocationTree::new(&revoked_keys)
+        .non_membership_witness(&verifying_key)
+        .ok_or_else(|| Risc0Error::InputError("Public key is revoked".to_string()))?;
+
+    // Create input for zkVM (public key, message, signature, non-membership witness)
+    let input = UnrevokedInput {
+        verifying_key: verifying_key.to_encoded_point(true),
+        message,
+        signature,
+        witness,
+    };
+    prove_input(ECDSA_VERIFY_UNREVOKED_ELF, &input, receipt_kind)
+}
+
+/// Verifies a receipt from [`risc0_prove_unrevoked`] and returns the committed
+/// key, message, and revocation root. Compare the root with
+/// [`risc0_revocation_root`] of the current revocation list.
+#[uniffi::export]
+pub fn risc0_verify_unrevoked(
+    receipt_bytes: Vec<u8>,
+) -> Result<Risc0VerifyUnrevokedOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, ECDSA_VERIFY_UNREVOKED_ID)?;
+
+    let (receipt_verifying_key, message, revocation_root): (EncodedPoint, Vec<u8>, [u8; 32]) =
+        receipt
+            .journal
+            .decode()
+            .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+    let verifying_key = decode_journal_key(&receipt_verifying_key)?;
+    let message_text = String::from_utf8(message.clone()).ok();
+
+    Ok(Risc0VerifyUnrevokedOutput {
+        is_valid: true,
+        message,
+        message_text,
+        signer: signer_public_key(&verifying_key),
+        revocation_root: revocation_root.to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_key, sign};
+
+    #[test]
+    fn test_prove_verify_unrevoked() {
+        let (signing_key, public_key) = random_key();
+        let revoked_keys: Vec<Vec<u8>> = (0..3).map(|_| random_key().1).collect();
+
+        let message = b"Still trusted".to_vec();
+        let signature = sign(&signing_key, &message);
+
+        let proof_output = risc0_prove_unrevoked(
+            revoked_keys.clone(),
+            public_key.clone(),
+            message.clone(),
+            signature.clone(),
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed for an unrevoked key");
+
+        let verify_output =
+            risc0_verify_unrevoked(proof_output.receipt).expect("Verification should succeed");
+        assert!(verify_output.is_valid, "Proof should be valid");
+        assert_eq!(verify_output.message, message);
+        assert_eq!(verify_output.signer.compressed, public_key);
+        assert_eq!(
+            verify_output.revocation_root,
+            risc0_revocation_root(revoked_keys.clone()).unwrap()
+        );
+
+        // Once revoked, the key can no longer be proven
+        let mut revoked_keys = revoked_keys;
+        revoked_keys.push(public_key.clone());
+        let result = risc0_prove_unrevoked(
+            revoked_keys,
+            public_key,
+            message,
+            signature,
+            Risc0RequestedReceiptKind::Composite,
+        );
+        assert!(matches!(result, Err(Risc0Error::InputError(_))));
+    }
+}
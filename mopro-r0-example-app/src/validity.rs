@@ -0,0 +1,138 @@
+//! Proofs with a validity window committed next to the key and message.
+
+use ecdsa_methods::{ECDSA_VERIFY_TIMED_ELF, ECDSA_VERIFY_TIMED_ID};
+use p256::EncodedPoint;
+
+use crate::{
+    decode_journal_key, parse_signed_message, prove_input, signer_public_key, verify_receipt,
+    Risc0Error, Risc0ProofOutput, Risc0PublicKey, Risc0RequestedReceiptKind,
+};
+
+/// Verify output for receipts with a validity window.
+#[derive(uniffi::Record, Clone)]
+pub struct Risc0VerifyValidityOutput {
+    pub is_valid: bool,
+    pub message: Vec<u8>,
+    /// The message as text, present only when it is valid UTF-8.
+    pub message_text: Option<String>,
+    pub signer: Risc0PublicKey,
+    /// Start of the validity window, in Unix seconds.
+    pub not_before: u64,
+    /// End of the validity window (inclusive), in Unix seconds.
+    pub not_after: u64,
+}
+
+/// Proves a signature with a validity window committed next to the key and
+/// message. Timestamps are Unix seconds and `not_after` is inclusive.
+#[uniffi::export]
+pub fn risc0_prove_with_validity(
+    public_key: Vec<u8>,
+    message: Vec<u8>,
+    signature: Vec<u8>,
+    not_before: u64,
+    not_after: u64,
+    receipt_kind: Risc0RequestedReceiptKind,
+) -> Result<Risc0ProofOutput, Risc0Error> {
+    let (verifying_key, signature) = parse_signed_message(&public_key, &message, &signature)?;
+
+    if not_before > not_after {
+        return Err(Risc0Error::InputError(format!(
+            "Validity window ends ({}) before it starts ({})",
+            not_after, not_before
+        )));
+    }
+
+    // Create input for zkVM (public key, message, signature, not before, not after)
+    let input = (
+        verifying_key.to_encoded_point(true),
+        message,
+        signature,
+        not_before,
+        not_after,
+    );
+    prove_input(ECDSA_VERIFY_TIMED_ELF, &input, receipt_kind)
+}
+
+/// Verifies a receipt from [`risc0_prove_with_validity`] at time `now` (Unix
+/// seconds), failing with [`Risc0Error::NotYetValidError`] or
+/// [`Risc0Error::ExpiredError`] outside the committed window.
+#[uniffi::export]
+pub fn risc0_verify_with_validity(
+    receipt_bytes: Vec<u8>,
+    now: u64,
+) -> Result<Risc0VerifyValidityOutput, Risc0Error> {
+    let receipt = verify_receipt(&receipt_bytes, ECDSA_VERIFY_TIMED_ID)?;
+
+    let (receipt_verifying_key, message, not_before, not_after): (
+        EncodedPoint,
+        Vec<u8>,
+        u64,
+        u64,
+    ) = receipt
+        .journal
+        .decode()
+        .map_err(|e| Risc0Error::DecodeError(format!("Failed to decode journal: {}", e)))?;
+    let verifying_key = decode_journal_key(&receipt_verifying_key)?;
+
+    if now < not_before {
+        return Err(Risc0Error::NotYetValidError(format!(
+            "Valid from {}, now is {}",
+            not_before, now
+        )));
+    }
+    if now > not_after {
+        return Err(Risc0Error::ExpiredError(format!(
+            "Valid until {}, now is {}",
+            not_after, now
+        )));
+    }
+
+    let message_text = String::from_utf8(message.clone()).ok();
+
+    Ok(Risc0VerifyValidityOutput {
+        is_valid: true,
+        message,
+        message_text,
+        signer: signer_public_key(&verifying_key),
+        not_before,
+        not_after,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_key, sign};
+
+    #[test]
+    fn test_prove_verify_with_validity() {
+        let (signing_key, public_key) = random_key();
+        let message = b"Valid for one hour".to_vec();
+
+        let not_before = 1_700_000_000;
+        let not_after = not_before + 3_600;
+        let proof_output = risc0_prove_with_validity(
+            public_key,
+            message.clone(),
+            sign(&signing_key, &message),
+            not_before,
+            not_after,
+            Risc0RequestedReceiptKind::Composite,
+        )
+        .expect("Proving should succeed");
+
+        let verify_output =
+            risc0_verify_with_validity(proof_output.receipt.clone(), not_before + 60)
+                .expect("Verification should succeed inside the window");
+        assert!(verify_output.is_valid, "Proof should be valid");
+        assert_eq!(verify_output.message, message);
+        assert_eq!(verify_output.not_before, not_before);
+        assert_eq!(verify_output.not_after, not_after);
+
+        let result = risc0_verify_with_validity(proof_output.receipt.clone(), not_before - 1);
+        assert!(matches!(result, Err(Risc0Error::NotYetValidError(_))));
+
+        let result = risc0_verify_with_validity(proof_output.receipt, not_after + 1);
+        assert!(matches!(result, Err(Risc0Error::ExpiredError(_))));
+    }
+}
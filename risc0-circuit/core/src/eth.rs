@@ -0,0 +1,123 @@
+//! Ethereum signature helpers: Keccak-256, EIP-191 `personal_sign` hashing,
+//! EIP-712 typed-data hashing and signer address recovery.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use k256::ecdsa::{Error, RecoveryId, Signature, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// EIP-191 hash of a `personal_sign` message:
+/// `keccak256("\x19Ethereum Signed Message:\n" || len(message) || message)`.
+pub fn personal_message_hash(message: &[u8]) -> [u8; 32] {
+    Keccak256::new()
+        .chain_update(b"\x19Ethereum Signed Message:\n")
+        .chain_update(message.len().to_string().as_bytes())
+        .chain_update(message)
+        .finalize()
+        .into()
+}
+
+/// Name every EIP-712 domain type starts with.
+const DOMAIN_TYPE_PREFIX: &str = "EIP712Domain(";
+
+/// EIP-712 domain, given like any other struct: its encoded type, e.g.
+/// `EIP712Domain(string name,uint256 chainId,address verifyingContract)`,
+/// and the 32-byte encodings of the members it lists.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypedDataDomain {
+    pub domain_type: String,
+    pub encoded_fields: Vec<[u8; 32]>,
+}
+
+impl TypedDataDomain {
+    /// Whether the type is an `EIP712Domain` type.
+    pub fn has_domain_type(&self) -> bool {
+        self.domain_type.starts_with(DOMAIN_TYPE_PREFIX)
+    }
+
+    /// EIP-712 domain separator: `hashStruct(domain)`.
+    pub fn separator(&self) -> [u8; 32] {
+        hash_struct(&self.domain_type, &self.encoded_fields)
+    }
+}
+
+/// Input of the `eip712_verify` guest.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TypedDataInput {
+    pub domain: TypedDataDomain,
+    /// Full encoded type of the signed struct, including referenced types.
+    pub struct_type: String,
+    pub encoded_fields: Vec<[u8; 32]>,
+    /// Indices of the members to commit.
+    pub disclosed_fields: Vec<u32>,
+    /// 65-byte `r || s || v` wallet signature.
+    pub signature: Vec<u8>,
+}
+
+/// Journal of the `eip712_verify` guest.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TypedDataJournal {
+    pub signer_address: [u8; 20],
+    pub domain_separator: [u8; 32],
+    pub struct_type: String,
+    /// Disclosed members as `(index, encoding)` pairs.
+    pub disclosed_fields: Vec<(u32, [u8; 32])>,
+}
+
+/// EIP-712 `hashStruct`: `keccak256(typeHash || encodeData)`, where
+/// `struct_type` is the full encoded type (including referenced types) and
+/// `encoded_fields` are the 32-byte encoded member values.
+pub fn hash_struct(struct_type: &str, encoded_fields: &[[u8; 32]]) -> [u8; 32] {
+    encoded_fields
+        .iter()
+        .fold(
+            Keccak256::new().chain_update(keccak256(struct_type.as_bytes())),
+            |hasher, field| hasher.chain_update(field),
+        )
+        .finalize()
+        .into()
+}
+
+/// EIP-712 signing digest: `keccak256(0x19 || 0x01 || domainSeparator || hashStruct(message))`.
+pub fn typed_data_hash(domain_separator: &[u8; 32], struct_hash: &[u8; 32]) -> [u8; 32] {
+    Keccak256::new()
+        .chain_update([0x19, 0x01])
+        .chain_update(domain_separator)
+        .chain_update(struct_hash)
+        .finalize()
+        .into()
+}
+
+/// Ethereum address of a public key: the last 20 bytes of the Keccak-256 of
+/// its uncompressed encoding without the `0x04` prefix.
+pub fn address(verifying_key: &VerifyingKey) -> [u8; 20] {
+    let hash = keccak256(&verifying_key.to_encoded_point(false).as_bytes()[1..]);
+    hash[12..].try_into().unwrap()
+}
+
+/// Recovers the signer address from a 65-byte `r || s || v` wallet
+/// signature over `prehash`. `v` may be 0/1 or 27/28, and high-S
+/// signatures are normalized.
+pub fn recover_address(prehash: &[u8; 32], signature: &[u8; 65]) -> Result<[u8; 20], Error> {
+    let v = signature[64];
+    let recovery_id =
+        RecoveryId::from_byte(if v >= 27 { v - 27 } else { v }).ok_or_else(Error::new)?;
+    let signature = Signature::from_slice(&signature[..64])?;
+    let (signature, recovery_id) = match signature.normalize_s() {
+        Some(normalized) => (
+            normalized,
+            RecoveryId::new(!recovery_id.is_y_odd(), recovery_id.is_x_reduced()),
+        ),
+        None => (signature, recovery_id),
+    };
+
+    let verifying_key = VerifyingKey::recover_from_prehash(prehash, &signature, recovery_id)?;
+    Ok(address(&verifying_key))
+}
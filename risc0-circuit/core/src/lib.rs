@@ -0,0 +1,62 @@
+//! Hashing and guest inputs shared by the guest methods and the host.
+//!
+//! Both sides depend on this crate, so trees and digests the host builds
+//! always match what the guests recompute.
+
+#![no_std]
+
+extern crate alloc;
+
+pub mod eth;
+pub mod merkle;
+
+use alloc::vec::Vec;
+use p256::{EncodedPoint, ecdsa::Signature};
+use serde::{Deserialize, Serialize};
+
+/// Byte that ends the context at the start of a context-bound message.
+pub const CONTEXT_SEPARATOR: u8 = 0x00;
+
+/// Whether `message` starts with `context || CONTEXT_SEPARATOR`.
+///
+/// Contexts that contain the separator are rejected, since one could then be
+/// a prefix of another.
+pub fn is_context_prefixed(message: &[u8], context: &str) -> bool {
+    !context.as_bytes().contains(&CONTEXT_SEPARATOR)
+        && message
+            .strip_prefix(context.as_bytes())
+            .is_some_and(|rest| rest.first() == Some(&CONTEXT_SEPARATOR))
+}
+
+/// Journal of the `ecdsa_verify_batch` guest.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BatchJournal {
+    /// Number of signatures verified in the batch.
+    pub count: u32,
+    /// Merkle root over the [`merkle::batch_leaf`] leaves, in batch order.
+    pub root: [u8; 32],
+    /// Compressed keys and messages in batch order, or empty if they were not
+    /// committed.
+    pub entries: Vec<(EncodedPoint, Vec<u8>)>,
+}
+
+/// Input of the `ecdsa_verify_threshold` guest.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ThresholdInput {
+    /// The whole key set, in the order its Merkle root is built.
+    pub key_set: Vec<EncodedPoint>,
+    pub threshold: u32,
+    pub message: Vec<u8>,
+    /// Signatures tagged with the index of their key in `key_set`.
+    pub signatures: Vec<(u32, Signature)>,
+}
+
+/// Input of the `ecdsa_verify_unrevoked` guest.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UnrevokedInput {
+    pub verifying_key: EncodedPoint,
+    pub message: Vec<u8>,
+    pub signature: Signature,
+    /// Witness that the key leaf is absent from the revocation tree.
+    pub witness: merkle::NonMembershipWitness,
+}
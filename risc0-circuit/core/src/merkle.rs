@@ -0,0 +1,231 @@
+//! SHA-256 Merkle trees with domain-separated leaves and nodes.
+
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+const LEAF_TAG: u8 = 0x00;
+const NODE_TAG: u8 = 0x01;
+
+/// Padding leaf used to fill trees up to a power of two. No data hashes to
+/// it, so it can never be proven as a member.
+pub const EMPTY_LEAF: [u8; 32] = [0u8; 32];
+
+/// Hashes leaf data, e.g. a compressed SEC1 public key.
+pub fn hash_leaf(data: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update([LEAF_TAG])
+        .chain_update(data)
+        .finalize()
+        .into()
+}
+
+/// Leaf hash of a batch entry: the compressed SEC1 key followed by the
+/// SHA-256 of the signed message.
+pub fn batch_leaf(compressed_key: &[u8], message: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update([LEAF_TAG])
+        .chain_update(compressed_key)
+        .chain_update(Sha256::digest(message))
+        .finalize()
+        .into()
+}
+
+/// Hashes two child nodes into their parent.
+pub fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update([NODE_TAG])
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+        .into()
+}
+
+/// Recomputes the root from a leaf hash, its index and the sibling hashes
+/// from the bottom level up. Bit `i` of `index` tells whether the node at
+/// level `i` is a right child.
+pub fn root_from_path(leaf: [u8; 32], index: u32, path: &[[u8; 32]]) -> [u8; 32] {
+    path.iter()
+        .enumerate()
+        .fold(leaf, |node, (level, sibling)| {
+            if (index >> level) & 1 == 1 {
+                hash_node(sibling, &node)
+            } else {
+                hash_node(&node, sibling)
+            }
+        })
+}
+
+/// Lowest value of a sorted tree.
+pub const MIN_SENTINEL: [u8; 32] = [0x00; 32];
+
+/// Highest value of a sorted tree, also used as padding.
+pub const MAX_SENTINEL: [u8; 32] = [0xff; 32];
+
+/// Proof that a value is absent from a sorted tree: the two adjacent values
+/// that sort immediately below and above it, with their inclusion paths.
+///
+/// Sorted trees hold the values between [`MIN_SENTINEL`] and [`MAX_SENTINEL`]
+/// as leaves `hash_leaf(value)`, so such a pair exists for every value that is
+/// not in the tree.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NonMembershipWitness {
+    /// Position of the low value; the high value is the next leaf.
+    pub low_index: u32,
+    pub low_value: [u8; 32],
+    pub low_path: Vec<[u8; 32]>,
+    pub high_value: [u8; 32],
+    pub high_path: Vec<[u8; 32]>,
+}
+
+impl NonMembershipWitness {
+    /// Checks that `value` is absent from the tree and returns its root.
+    ///
+    /// The witness values are hashed as leaves here, so inner nodes cannot
+    /// stand in for them, and both indices must fit the depth of the paths.
+    /// Panics if the witness is invalid.
+    pub fn root(&self, value: [u8; 32]) -> [u8; 32] {
+        assert!(
+            self.low_value < value && value < self.high_value,
+            "value is not between the witness values"
+        );
+        let depth = self.low_path.len();
+        assert_eq!(depth, self.high_path.len(), "witness paths differ in depth");
+        assert!(
+            depth < 32 && u64::from(self.low_index) + 1 < 1 << depth,
+            "witness index is outside the tree"
+        );
+
+        let root = root_from_path(hash_leaf(&self.low_value), self.low_index, &self.low_path);
+        assert_eq!(
+            root,
+            root_from_path(hash_leaf(&self.high_value), self.low_index + 1, &self.high_path),
+            "witness values are not in the same tree"
+        );
+
+        root
+    }
+}
+
+/// Computes the root of a tree over `leaves`, padded with [`EMPTY_LEAF`] to
+/// a power of two. An empty list yields a single padding leaf.
+pub fn root(mut leaves: Vec<[u8; 32]>) -> [u8; 32] {
+    leaves.resize(leaves.len().max(1).next_power_of_two(), EMPTY_LEAF);
+    while leaves.len() > 1 {
+        leaves = leaves
+            .chunks(2)
+            .map(|pair| hash_node(&pair[0], &pair[1]))
+            .collect();
+    }
+    leaves[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_root_matches_paths() {
+        let leaves: Vec<[u8; 32]> = (0u8..5).map(|i| hash_leaf(&[i])).collect();
+        let root = root(leaves.clone());
+
+        // Five leaves pad to eight: leaf 4 sits next to padding.
+        let left = hash_node(
+            &hash_node(&leaves[0], &leaves[1]),
+            &hash_node(&leaves[2], &leaves[3]),
+        );
+        let padding = hash_node(&EMPTY_LEAF, &EMPTY_LEAF);
+        let path = [EMPTY_LEAF, padding, left];
+        assert_eq!(root_from_path(leaves[4], 4, &path), root);
+    }
+
+    /// Sorted tree over `values` framed by the sentinels, and its levels.
+    fn sorted_tree(values: &[[u8; 32]]) -> Vec<Vec<[u8; 32]>> {
+        let mut leaves: Vec<[u8; 32]> = [MIN_SENTINEL]
+            .iter()
+            .chain(values)
+            .chain(&[MAX_SENTINEL])
+            .map(|value| hash_leaf(value))
+            .collect();
+        leaves.resize(leaves.len().next_power_of_two(), hash_leaf(&MAX_SENTINEL));
+
+        let mut levels = vec![leaves];
+        while levels[levels.len() - 1].len() > 1 {
+            let next = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| hash_node(&pair[0], &pair[1]))
+                .collect();
+            levels.push(next);
+        }
+        levels
+    }
+
+    fn path(levels: &[Vec<[u8; 32]>], index: usize) -> Vec<[u8; 32]> {
+        levels[..levels.len() - 1]
+            .iter()
+            .enumerate()
+            .map(|(level, nodes)| nodes[(index >> level) ^ 1])
+            .collect()
+    }
+
+    #[test]
+    fn test_non_membership_witness() {
+        let values = [[0x40; 32], [0x80; 32]];
+        let levels = sorted_tree(&values);
+        let witness = NonMembershipWitness {
+            low_index: 1,
+            low_value: values[0],
+            low_path: path(&levels, 1),
+            high_value: values[1],
+            high_path: path(&levels, 2),
+        };
+        assert_eq!(witness.root([0x50; 32]), levels[levels.len() - 1][0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not in the same tree")]
+    fn test_non_membership_rejects_inner_nodes() {
+        // Two sibling subtrees, passed off as adjacent leaves one level up:
+        // their node hashes are the values, the upper path is the witness.
+        let values = [[0x20; 32], [0x40; 32], [0x60; 32], [0x80; 32], [0xa0; 32], [0xc0; 32]];
+        let levels = sorted_tree(&values);
+        let low = (0..levels[1].len() - 1)
+            .find(|&index| levels[1][index] < levels[1][index + 1])
+            .expect("some adjacent nodes are in order");
+        let forged = NonMembershipWitness {
+            low_index: low as u32,
+            low_value: levels[1][low],
+            low_path: path(&levels[1..], low),
+            high_value: levels[1][low + 1],
+            high_path: path(&levels[1..], low + 1),
+        };
+
+        // The forged witness would have excluded any value between the nodes.
+        let mut value = forged.low_value;
+        value[31] = value[31].wrapping_add(1);
+        assert!(forged.low_value < value && value < forged.high_value);
+        forged.root(value);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the tree")]
+    fn test_non_membership_rejects_wrapping_index() {
+        // Indices past the last leaf alias the first leaves of the tree.
+        let levels = sorted_tree(&[[0x40; 32]]);
+        let witness = NonMembershipWitness {
+            low_index: levels[0].len() as u32,
+            low_value: MIN_SENTINEL,
+            low_path: path(&levels, 0),
+            high_value: [0x40; 32],
+            high_path: path(&levels, 1),
+        };
+        witness.root([0x20; 32]);
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        assert_eq!(root(vec![]), EMPTY_LEAF);
+        assert_eq!(root(vec![hash_leaf(b"key")]), hash_leaf(b"key"));
+    }
+}
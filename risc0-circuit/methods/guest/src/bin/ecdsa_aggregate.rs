@@ -0,0 +1,24 @@
+use ecdsa_verify::merkle;
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Decode the inner image ID, the inner journals, and whether to commit the
+    // journals themselves from the inputs.
+    let (image_id, journals, commit_journals): ([u32; 8], Vec<Vec<u8>>, bool) = env::read();
+    assert!(!journals.is_empty(), "nothing to aggregate");
+
+    // Verify every inner receipt. Each call adds an assumption that the host
+    // must resolve with the matching receipt, so the outer receipt is only
+    // valid if all inner ones are.
+    let mut leaves = Vec::with_capacity(journals.len());
+    for journal in &journals {
+        env::verify(image_id, journal.as_slice()).unwrap();
+        leaves.push(merkle::hash_leaf(journal));
+    }
+
+    // Commit to the journal the inner image ID, the number of inner receipts,
+    // the Merkle root over their journals, and the journals themselves if requested.
+    let count = journals.len() as u32;
+    let committed_journals = if commit_journals { journals } else { Vec::new() };
+    env::commit(&(image_id, count, merkle::root(leaves), committed_journals));
+}
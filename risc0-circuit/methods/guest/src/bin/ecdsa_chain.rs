@@ -0,0 +1,56 @@
+use p256::{
+    EncodedPoint,
+    ecdsa::{Signature, VerifyingKey, signature::Verifier},
+};
+use risc0_zkvm::guest::env;
+use sha2::{Digest, Sha256};
+
+/// Head that the first message of a chain links to.
+const GENESIS_HEAD: [u8; 32] = [0u8; 32];
+
+fn main() {
+    // Decode this method's image ID, the previous link's journal (absent for
+    // the first link), and the verifying key, message, and signature of the
+    // next link from the inputs. A guest cannot embed its own image ID, so it
+    // is passed in and committed; verifiers check it against the real one.
+    let (image_id, previous_journal, encoded_verifying_key, message, signature): (
+        [u32; 8],
+        Option<Vec<u8>>,
+        EncodedPoint,
+        Vec<u8>,
+        Signature,
+    ) = env::read();
+    let verifying_key = VerifyingKey::from_encoded_point(&encoded_verifying_key).unwrap();
+    let encoded_verifying_key = verifying_key.to_encoded_point(true);
+
+    // Verify the previous link, if any, as an assumption resolved by the host,
+    // and continue its chain under the same key.
+    let (counter, previous_head) = match previous_journal {
+        Some(previous_journal) => {
+            let (previous_image_id, previous_key, previous_counter, previous_head): (
+                [u32; 8],
+                EncodedPoint,
+                u64,
+                [u8; 32],
+            ) = risc0_zkvm::serde::from_slice(previous_journal.as_slice()).unwrap();
+            assert_eq!(previous_image_id, image_id, "previous link is from another method");
+            env::verify(image_id, previous_journal.as_slice()).unwrap();
+            assert_eq!(previous_key, encoded_verifying_key, "previous link is from another key");
+            (previous_counter + 1, previous_head)
+        }
+        None => (1, GENESIS_HEAD),
+    };
+
+    // Each message must start with the head it extends.
+    assert!(message.starts_with(&previous_head), "message does not extend the chain head");
+
+    // Verify the signature, panicking if verification fails.
+    verifying_key
+        .verify(&message, &signature)
+        .expect("ECDSA signature verification failed");
+
+    // Commit to the journal the image ID, the verifying key, the number of
+    // links so far, and the new chain head.
+    let head: [u8; 32] = Sha256::digest(&message).into();
+    env::commit(&(image_id, encoded_verifying_key, counter, head));
+}
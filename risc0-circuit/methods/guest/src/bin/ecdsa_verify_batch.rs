@@ -0,0 +1,40 @@
+use ecdsa_core::BatchJournal;
+use ecdsa_verify::merkle;
+use p256::{
+    EncodedPoint,
+    ecdsa::{Signature, VerifyingKey, signature::Verifier},
+};
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Decode the batch of (verifying key, message, signature) entries, and
+    // whether to commit the entries themselves, from the inputs.
+    let (entries, commit_entries): (Vec<(EncodedPoint, Vec<u8>, Signature)>, bool) = env::read();
+    assert!(!entries.is_empty(), "empty batch");
+
+    // Verify every signature, panicking if any verification fails, and hash
+    // each entry into a leaf over the compressed key and the message digest.
+    let mut leaves = Vec::with_capacity(entries.len());
+    let mut committed_entries = Vec::new();
+    for (index, (encoded_verifying_key, message, signature)) in entries.into_iter().enumerate() {
+        let verifying_key = VerifyingKey::from_encoded_point(&encoded_verifying_key).unwrap();
+        verifying_key
+            .verify(&message, &signature)
+            .unwrap_or_else(|_| panic!("ECDSA signature verification failed for entry {}", index));
+
+        let compressed_key = verifying_key.to_encoded_point(true);
+        leaves.push(merkle::batch_leaf(compressed_key.as_bytes(), &message));
+
+        if commit_entries {
+            committed_entries.push((compressed_key, message));
+        }
+    }
+
+    // Commit to the journal the number of entries, the Merkle root over the
+    // entry leaves, and the entries themselves if requested.
+    env::commit(&BatchJournal {
+        count: leaves.len() as u32,
+        root: merkle::root(leaves),
+        entries: committed_entries,
+    });
+}
@@ -0,0 +1,39 @@
+use p256::{
+    EncodedPoint,
+    ecdsa::{Signature, VerifyingKey, signature::Verifier},
+};
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Decode the verifying key, message, signature, verifier challenge, and
+    // whether the message must contain the challenge from the inputs.
+    let (encoded_verifying_key, message, signature, challenge, challenge_in_message): (
+        EncodedPoint,
+        Vec<u8>,
+        Signature,
+        Vec<u8>,
+        bool,
+    ) = env::read();
+    let verifying_key = VerifyingKey::from_encoded_point(&encoded_verifying_key).unwrap();
+
+    // Verify the signature, panicking if verification fails.
+    verifying_key
+        .verify(&message, &signature)
+        .expect("ECDSA signature verification failed");
+
+    // Require the signed message to contain the challenge, so the signature
+    // cannot have been computed before the verifier issued it.
+    if challenge_in_message {
+        assert!(
+            !challenge.is_empty()
+                && message
+                    .windows(challenge.len())
+                    .any(|window| window == &challenge[..]),
+            "signed message does not contain the challenge"
+        );
+    }
+
+    // Commit to the journal the verifying key, message, challenge, and whether
+    // the challenge was found in the signed message.
+    env::commit(&(encoded_verifying_key, message, challenge, challenge_in_message));
+}
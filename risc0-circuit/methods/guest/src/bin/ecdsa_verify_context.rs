@@ -0,0 +1,37 @@
+use ecdsa_core::is_context_prefixed;
+use p256::{
+    EncodedPoint,
+    ecdsa::{Signature, VerifyingKey, signature::Verifier},
+};
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Decode the application context, verifying key, message, signature, and
+    // whether the message must be prefixed with the context from the inputs.
+    let (context, encoded_verifying_key, message, signature, context_prefixed): (
+        String,
+        EncodedPoint,
+        Vec<u8>,
+        Signature,
+        bool,
+    ) = env::read();
+    let verifying_key = VerifyingKey::from_encoded_point(&encoded_verifying_key).unwrap();
+
+    // Verify the signature, panicking if verification fails.
+    verifying_key
+        .verify(&message, &signature)
+        .expect("ECDSA signature verification failed");
+
+    // Require the signed message to start with the context and a separator,
+    // so signatures made for one domain cannot be proven in another.
+    if context_prefixed {
+        assert!(
+            is_context_prefixed(&message, &context),
+            "signed message is not prefixed with the context"
+        );
+    }
+
+    // Commit to the journal the context, verifying key, message, and whether
+    // the message was checked to carry the context prefix.
+    env::commit(&(context, encoded_verifying_key, message, context_prefixed));
+}
@@ -0,0 +1,33 @@
+use p256::{
+    EncodedPoint,
+    ecdsa::{Signature, VerifyingKey, signature::Verifier},
+};
+use risc0_zkvm::guest::env;
+use sha2::{Digest, Sha256};
+
+fn main() {
+    // Decode the verifying key, message, signature, and optional blinding value from the inputs.
+    let (encoded_verifying_key, message, signature, blinding): (
+        EncodedPoint,
+        Vec<u8>,
+        Signature,
+        Option<[u8; 32]>,
+    ) = env::read();
+    let verifying_key = VerifyingKey::from_encoded_point(&encoded_verifying_key).unwrap();
+
+    // Verify the signature, panicking if verification fails.
+    verifying_key
+        .verify(&message, &signature)
+        .expect("ECDSA signature verification failed");
+
+    // Hash the message, prefixed with the blinding value (zeros when none is
+    // given), so the journal does not reveal the plaintext. The fixed-size
+    // prefix keeps blinded and unblinded commitments from colliding.
+    let mut hasher = Sha256::new();
+    hasher.update(blinding.unwrap_or([0; 32]));
+    hasher.update(&message);
+    let message_commitment: [u8; 32] = hasher.finalize().into();
+
+    // Commit to the journal the verifying key and the message commitment.
+    env::commit(&(encoded_verifying_key, message_commitment));
+}
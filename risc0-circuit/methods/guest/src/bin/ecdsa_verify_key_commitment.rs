@@ -0,0 +1,33 @@
+use p256::{
+    EncodedPoint,
+    ecdsa::{Signature, VerifyingKey, signature::Verifier},
+};
+use risc0_zkvm::guest::env;
+use sha2::{Digest, Sha256};
+
+fn main() {
+    // Decode the verifying key, message, signature, and commitment salt from the inputs.
+    let (encoded_verifying_key, message, signature, salt): (
+        EncodedPoint,
+        Vec<u8>,
+        Signature,
+        [u8; 32],
+    ) = env::read();
+    let verifying_key = VerifyingKey::from_encoded_point(&encoded_verifying_key).unwrap();
+
+    // Verify the signature, panicking if verification fails.
+    verifying_key
+        .verify(&message, &signature)
+        .expect("ECDSA signature verification failed");
+
+    // Commit to the compressed SEC1 key behind the salt, so the journal does not
+    // reveal which key signed until the prover opens the commitment.
+    let key_commitment: [u8; 32] = Sha256::new()
+        .chain_update(salt)
+        .chain_update(verifying_key.to_encoded_point(true).as_bytes())
+        .finalize()
+        .into();
+
+    // Commit to the journal the key commitment and message that was signed.
+    env::commit(&(key_commitment, message));
+}
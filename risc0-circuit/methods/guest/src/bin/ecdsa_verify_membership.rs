@@ -0,0 +1,31 @@
+use ecdsa_verify::merkle;
+use p256::{
+    EncodedPoint,
+    ecdsa::{Signature, VerifyingKey, signature::Verifier},
+};
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Decode the verifying key, message, signature, and the key's Merkle path from the inputs.
+    let (encoded_verifying_key, message, signature, leaf_index, path): (
+        EncodedPoint,
+        Vec<u8>,
+        Signature,
+        u32,
+        Vec<[u8; 32]>,
+    ) = env::read();
+    let verifying_key = VerifyingKey::from_encoded_point(&encoded_verifying_key).unwrap();
+
+    // Verify the signature, panicking if verification fails.
+    verifying_key
+        .verify(&message, &signature)
+        .expect("ECDSA signature verification failed");
+
+    // Recompute the root of the key set from the signer's leaf. The key itself
+    // and its position stay private.
+    let leaf = merkle::hash_leaf(verifying_key.to_encoded_point(true).as_bytes());
+    let key_set_root = merkle::root_from_path(leaf, leaf_index, &path);
+
+    // Commit to the journal the key set root and message that was signed.
+    env::commit(&(key_set_root, message));
+}
@@ -0,0 +1,21 @@
+use p256::{
+    EncodedPoint,
+    ecdsa::{Signature, VerifyingKey, signature::hazmat::PrehashVerifier},
+};
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Decode the verifying key, SHA-256 message digest, and signature from the inputs.
+    let (encoded_verifying_key, message_digest, signature): (EncodedPoint, [u8; 32], Signature) =
+        env::read();
+    let verifying_key = VerifyingKey::from_encoded_point(&encoded_verifying_key).unwrap();
+
+    // Verify the signature over the digest, panicking if verification fails. The
+    // cost no longer depends on the size of the signed document.
+    verifying_key
+        .verify_prehash(&message_digest, &signature)
+        .expect("ECDSA signature verification failed");
+
+    // Commit to the journal the verifying key and the digest of the signed message.
+    env::commit(&(encoded_verifying_key, message_digest));
+}
@@ -0,0 +1,55 @@
+use ecdsa_core::ThresholdInput;
+use ecdsa_verify::merkle;
+use p256::{
+    EncodedPoint,
+    ecdsa::{VerifyingKey, signature::Verifier},
+};
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Decode the key set, threshold, message, and the signatures tagged with
+    // the index of their key in the set from the inputs.
+    let ThresholdInput {
+        key_set,
+        threshold,
+        message,
+        signatures,
+    } = env::read();
+    assert!(threshold > 0, "threshold must be at least 1");
+    let key_set: Vec<VerifyingKey> = key_set
+        .iter()
+        .map(|encoded_key| VerifyingKey::from_encoded_point(encoded_key).unwrap())
+        .collect();
+
+    // Verify every signature, panicking if any verification fails. A key may
+    // sign only once, even if it appears more than once in the set.
+    let mut signers: Vec<EncodedPoint> = Vec::with_capacity(signatures.len());
+    for (key_index, signature) in &signatures {
+        let verifying_key = &key_set[*key_index as usize];
+        verifying_key
+            .verify(&message, signature)
+            .expect("ECDSA signature verification failed");
+
+        let signer = verifying_key.to_encoded_point(true);
+        assert!(!signers.contains(&signer), "duplicate signer at key index {}", key_index);
+        signers.push(signer);
+    }
+    assert!(
+        signers.len() >= threshold as usize,
+        "{} signatures do not meet the threshold of {}",
+        signers.len(),
+        threshold
+    );
+
+    // Hash the key set into the same Merkle root used for membership proofs.
+    // Which keys signed stays private.
+    let key_set_root = merkle::root(
+        key_set
+            .iter()
+            .map(|key| merkle::hash_leaf(key.to_encoded_point(true).as_bytes()))
+            .collect(),
+    );
+
+    // Commit to the journal the key set root, the threshold, and the message that was signed.
+    env::commit(&(key_set_root, threshold, message));
+}
@@ -0,0 +1,30 @@
+use p256::{
+    EncodedPoint,
+    ecdsa::{Signature, VerifyingKey, signature::Verifier},
+};
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Decode the verifying key, message, signature, and validity window (Unix
+    // seconds) from the inputs.
+    let (encoded_verifying_key, message, signature, not_before, not_after): (
+        EncodedPoint,
+        Vec<u8>,
+        Signature,
+        u64,
+        u64,
+    ) = env::read();
+    let verifying_key = VerifyingKey::from_encoded_point(&encoded_verifying_key).unwrap();
+
+    // Verify the signature, panicking if verification fails.
+    verifying_key
+        .verify(&message, &signature)
+        .expect("ECDSA signature verification failed");
+
+    // The guest has no clock; it only checks that the window is well formed and
+    // leaves the comparison with the current time to the verifier.
+    assert!(not_before <= not_after, "validity window ends before it starts");
+
+    // Commit to the journal the verifying key, message, and validity window.
+    env::commit(&(encoded_verifying_key, message, not_before, not_after));
+}
@@ -0,0 +1,24 @@
+use ecdsa_core::UnrevokedInput;
+use ecdsa_verify::merkle;
+use p256::ecdsa::{VerifyingKey, signature::Verifier};
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Decode the verifying key, message, signature, and the non-membership
+    // witness against the revocation tree from the inputs.
+    let input: UnrevokedInput = env::read();
+    let verifying_key = VerifyingKey::from_encoded_point(&input.verifying_key).unwrap();
+
+    // Verify the signature, panicking if verification fails.
+    verifying_key
+        .verify(&input.message, &input.signature)
+        .expect("ECDSA signature verification failed");
+
+    // Check that the signer key is not revoked, panicking if the witness is invalid.
+    let key_leaf = merkle::hash_leaf(verifying_key.to_encoded_point(true).as_bytes());
+    let revocation_root = input.witness.root(key_leaf);
+
+    // Commit to the journal the verifying key, message, and the revocation root
+    // the key was checked against.
+    env::commit(&(input.verifying_key, input.message, revocation_root));
+}
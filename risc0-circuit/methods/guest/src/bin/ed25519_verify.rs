@@ -0,0 +1,18 @@
+use ed25519_dalek::{Signature, VerifyingKey};
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Decode the 32-byte verifying key, message, and 64-byte signature from the inputs.
+    let (encoded_verifying_key, message, signature): ([u8; 32], Vec<u8>, Vec<u8>) = env::read();
+    let verifying_key = VerifyingKey::from_bytes(&encoded_verifying_key).unwrap();
+    let signature = Signature::from_slice(&signature).unwrap();
+
+    // Verify the signature, panicking if verification fails. Strict
+    // verification rejects weak keys and non-canonical signatures.
+    verifying_key
+        .verify_strict(&message, &signature)
+        .expect("Ed25519 signature verification failed");
+
+    // Commit to the journal the verifying key and message that was signed.
+    env::commit(&(encoded_verifying_key, message));
+}
@@ -0,0 +1,37 @@
+use ecdsa_verify::eth::{self, TypedDataInput, TypedDataJournal};
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Decode the EIP-712 domain, the encoded typed struct, the indices of the
+    // fields to disclose, and the 65-byte `r || s || v` signature from the inputs.
+    let input: TypedDataInput = env::read();
+    assert!(input.domain.has_domain_type(), "domain type is not an EIP712Domain type");
+
+    // Compute the EIP-712 digest inside the zkVM and recover the signer address,
+    // panicking if the signature is malformed.
+    let domain_separator = input.domain.separator();
+    let struct_hash = eth::hash_struct(&input.struct_type, &input.encoded_fields);
+    let digest = eth::typed_data_hash(&domain_separator, &struct_hash);
+    let signature: [u8; 65] = input
+        .signature
+        .try_into()
+        .expect("signature must be 65 bytes (r || s || v)");
+    let signer_address =
+        eth::recover_address(&digest, &signature).expect("failed to recover the signer key");
+
+    // Only the selected fields are revealed; the rest stay private.
+    let disclosed_fields = input
+        .disclosed_fields
+        .into_iter()
+        .map(|index| (index, input.encoded_fields[index as usize]))
+        .collect();
+
+    // Commit to the journal the signer address, domain separator, struct type,
+    // and the disclosed fields.
+    env::commit(&TypedDataJournal {
+        signer_address,
+        domain_separator,
+        struct_type: input.struct_type,
+        disclosed_fields,
+    });
+}
@@ -0,0 +1,19 @@
+use ecdsa_verify::eth;
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Decode the message and the 65-byte `r || s || v` wallet signature from the inputs.
+    let (message, signature): (Vec<u8>, Vec<u8>) = env::read();
+
+    // Hash the message as `personal_sign` does and recover the signer address,
+    // panicking if the signature is malformed.
+    let signature: [u8; 65] = signature
+        .try_into()
+        .expect("signature must be 65 bytes (r || s || v)");
+    let message_hash = eth::personal_message_hash(&message);
+    let signer_address = eth::recover_address(&message_hash, &signature)
+        .expect("failed to recover the signer key");
+
+    // Commit to the journal the signer address and the message that was signed.
+    env::commit(&(signer_address, message));
+}
@@ -0,0 +1,24 @@
+use p384::ecdsa::{Signature, VerifyingKey, signature::Verifier};
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Decode the SEC1 verifying key, message, and fixed-size signature from the inputs.
+    let (encoded_verifying_key, message, signature): (Vec<u8>, Vec<u8>, Vec<u8>) = env::read();
+    let verifying_key = VerifyingKey::from_sec1_bytes(&encoded_verifying_key).unwrap();
+    let signature = Signature::from_slice(&signature).unwrap();
+
+    // Verify the signature over the SHA-384 digest of the message, panicking
+    // if verification fails. SHA-384 has no accelerator, so this guest runs
+    // noticeably more cycles than the P-256 one.
+    verifying_key
+        .verify(&message, &signature)
+        .expect("ECDSA signature verification failed");
+
+    // Commit to the journal the curve, the compressed verifying key, and the
+    // message that was signed.
+    env::commit(&(
+        "p384",
+        verifying_key.to_encoded_point(true).as_bytes(),
+        message,
+    ));
+}
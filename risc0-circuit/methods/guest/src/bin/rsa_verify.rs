@@ -0,0 +1,41 @@
+use risc0_zkvm::guest::env;
+use rsa::{BigUint, Pkcs1v15Sign, Pss, RsaPublicKey, traits::PublicKeyParts};
+use sha2::{Digest, Sha256};
+
+/// Signature scheme tags, shared with the host.
+const SCHEME_PKCS1V15: u8 = 0;
+const SCHEME_PSS: u8 = 1;
+
+fn main() {
+    // Decode the big-endian modulus and public exponent, the scheme tag, the
+    // message, and the signature from the inputs.
+    let (modulus, exponent, scheme, message, signature): (Vec<u8>, Vec<u8>, u8, Vec<u8>, Vec<u8>) =
+        env::read();
+    let public_key =
+        RsaPublicKey::new(BigUint::from_bytes_be(&modulus), BigUint::from_bytes_be(&exponent))
+            .unwrap();
+    let modulus_bits = public_key.n().bits();
+    assert!(
+        matches!(modulus_bits, 2048 | 3072 | 4096),
+        "Unsupported RSA modulus size: {} bits",
+        modulus_bits
+    );
+
+    // Verify the signature over the SHA-256 digest of the message, panicking
+    // if verification fails. PSS signatures must use a 32-byte salt, as PS256 does.
+    let message_digest = Sha256::digest(&message);
+    match scheme {
+        SCHEME_PKCS1V15 => public_key
+            .verify(Pkcs1v15Sign::new::<Sha256>(), &message_digest, &signature)
+            .expect("RSA PKCS#1 v1.5 signature verification failed"),
+        SCHEME_PSS => public_key
+            .verify(Pss::new::<Sha256>(), &message_digest, &signature)
+            .expect("RSA PSS signature verification failed"),
+        _ => panic!("Unknown RSA signature scheme: {}", scheme),
+    }
+
+    // Commit to the journal a SHA-256 hash of the modulus instead of the full
+    // key, along with the exponent, the scheme, and the message that was signed.
+    let modulus_hash: [u8; 32] = Sha256::digest(public_key.n().to_bytes_be()).into();
+    env::commit(&(modulus_hash, public_key.e().to_bytes_be(), scheme, message));
+}
@@ -0,0 +1,19 @@
+use k256::schnorr::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey};
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Decode the 32-byte x-only verifying key, 32-byte message, and 64-byte signature from the inputs.
+    let (encoded_verifying_key, message, signature): ([u8; 32], [u8; 32], Vec<u8>) = env::read();
+    let verifying_key = VerifyingKey::from_bytes(&encoded_verifying_key).unwrap();
+    let signature = Signature::try_from(signature.as_slice()).unwrap();
+
+    // Verify the BIP-340 signature over the 32-byte message, panicking if
+    // verification fails. The challenge is computed with the BIP0340 tagged
+    // hash; the message is not hashed again first.
+    verifying_key
+        .verify_prehash(&message, &signature)
+        .expect("Schnorr signature verification failed");
+
+    // Commit to the journal the x-only verifying key and message that was signed.
+    env::commit(&(encoded_verifying_key, message));
+}
@@ -0,0 +1,23 @@
+use k256::ecdsa::{Signature, VerifyingKey, signature::Verifier};
+use risc0_zkvm::guest::env;
+
+fn main() {
+    // Decode the SEC1 verifying key, message, and fixed-size signature from the inputs.
+    let (encoded_verifying_key, message, signature): (Vec<u8>, Vec<u8>, Vec<u8>) = env::read();
+    let verifying_key = VerifyingKey::from_sec1_bytes(&encoded_verifying_key).unwrap();
+    let signature = Signature::from_slice(&signature).unwrap();
+
+    // Verify the signature, panicking if verification fails.
+    verifying_key
+        .verify(&message, &signature)
+        .expect("ECDSA signature verification failed");
+
+    // Commit to the journal the curve, the compressed verifying key, and the
+    // message that was signed. The curve tag keeps secp256k1 statements from
+    // being confused with secp256r1 ones.
+    env::commit(&(
+        "secp256k1",
+        verifying_key.to_encoded_point(true).as_bytes(),
+        message,
+    ));
+}
@@ -0,0 +1,3 @@
+//! Helpers shared by the ECDSA guest methods.
+
+pub use ecdsa_core::{eth, merkle};
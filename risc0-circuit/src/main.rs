@@ -1,22 +1,195 @@
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum, error::ErrorKind};
 use p256::{
     EncodedPoint,
     ecdsa::{Signature, SigningKey, VerifyingKey, signature::Signer},
 };
-use ecdsa_methods::{ECDSA_VERIFY_ELF, ECDSA_VERIFY_ID};
+use ecdsa_core::BatchJournal;
+use ecdsa_methods::{
+    ECDSA_VERIFY_BATCH_ELF, ECDSA_VERIFY_BATCH_ID, ECDSA_VERIFY_ELF, ECDSA_VERIFY_ID,
+    ECDSA_VERIFY_PREHASH_ELF, ECDSA_VERIFY_PREHASH_ID, ED25519_VERIFY_ELF, ED25519_VERIFY_ID,
+    P384_VERIFY_ELF, P384_VERIFY_ID,
+};
 use rand_core::OsRng;
-use risc0_zkvm::{ExecutorEnv, Receipt, default_prover};
+use risc0_zkvm::{ExecutorEnv, InnerReceipt, ProverOpts, Receipt, default_prover};
+use sha2::{Digest, Sha256};
 use log::{info, debug};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Signature scheme to prove.
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+enum Scheme {
+    /// ECDSA over secp256r1.
+    #[default]
+    P256,
+    /// Ed25519.
+    Ed25519,
+    /// ECDSA over secp384r1, with SHA-384.
+    P384,
+}
+
+/// How the signed message is handed to the guest.
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+enum MessageMode {
+    /// The guest hashes the whole message and commits it to the journal.
+    #[default]
+    Full,
+    /// The host hashes the message; the guest verifies and commits only the
+    /// 32-byte SHA-256 digest.
+    Prehashed,
+}
+
+/// Kind of receipt to produce.
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+enum ReceiptKind {
+    /// One STARK seal per segment.
+    #[default]
+    Composite,
+    /// A single constant-size STARK seal.
+    Succinct,
+    /// A Groth16 SNARK seal of a few hundred bytes (x86 hosts only).
+    Groth16,
+}
+
+impl ReceiptKind {
+    fn prover_opts(self) -> ProverOpts {
+        match self {
+            ReceiptKind::Composite => ProverOpts::composite(),
+            ReceiptKind::Succinct => ProverOpts::succinct(),
+            ReceiptKind::Groth16 => ProverOpts::groth16(),
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Compress a receipt file, e.g. one written with `--out`, to a smaller kind.
+    Compress {
+        /// Bincode-serialized receipt to read.
+        input: PathBuf,
+        /// File to write the compressed receipt to.
+        output: PathBuf,
+        /// Kind of receipt to compress to; composite is not a valid target.
+        #[arg(long, value_enum, default_value_t = ReceiptKind::Succinct)]
+        receipt_kind: ReceiptKind,
+    },
+}
+
+#[derive(Parser, Debug)]
+#[command(about = "Prove signature verification in the RISC Zero zkVM")]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Signature scheme to prove.
+    #[arg(long, value_enum, default_value_t = Scheme::P256)]
+    scheme: Scheme,
+
+    /// How the signed message is passed to the guest (P256 only) [default: full].
+    #[arg(long, value_enum)]
+    mode: Option<MessageMode>,
+
+    /// Prove this many P256 signatures in a single guest execution.
+    #[arg(long, conflicts_with_all = ["scheme", "mode"])]
+    batch: Option<usize>,
+
+    /// Kind of receipt to produce.
+    #[arg(long, value_enum, default_value_t = ReceiptKind::Composite)]
+    receipt_kind: ReceiptKind,
+
+    /// Write the bincode-serialized receipt to this file.
+    #[arg(long)]
+    out: Option<PathBuf>,
+}
 
 /// Given an secp256r1 verifier key (i.e. public key), message and signature,
 /// runs the ECDSA verifier inside the zkVM and returns a receipt, including a
 /// journal and seal attesting to the fact that the prover knows a valid
 /// signature from the committed public key over the committed message.
+///
+/// The receipt is of `receipt_kind`; the other `prove_*` functions below
+/// take the same argument.
+///
+/// In [`MessageMode::Prehashed`] the journal commits the SHA-256 digest of the
+/// message instead of the message itself.
 fn prove_ecdsa_verification(
     verifying_key: &VerifyingKey,
     message: &[u8],
     signature: &Signature,
+    mode: MessageMode,
+    receipt_kind: ReceiptKind,
+) -> Receipt {
+    let encoded_verifying_key = verifying_key.to_encoded_point(true);
+    let mut builder = ExecutorEnv::builder();
+    let elf = match mode {
+        MessageMode::Full => {
+            builder
+                .write(&(encoded_verifying_key, message, signature))
+                .unwrap();
+            ECDSA_VERIFY_ELF
+        }
+        MessageMode::Prehashed => {
+            let message_digest: [u8; 32] = Sha256::digest(message).into();
+            builder
+                .write(&(encoded_verifying_key, message_digest, signature))
+                .unwrap();
+            ECDSA_VERIFY_PREHASH_ELF
+        }
+    };
+    let env = builder.build().unwrap();
+
+    // Obtain the default prover.
+    let prover = default_prover();
+
+    // Produce a receipt by proving the specified ELF binary.
+    prover
+        .prove_with_opts(env, elf, &receipt_kind.prover_opts())
+        .unwrap()
+        .receipt
+}
+
+/// Given an Ed25519 verifying key, message and signature, runs the Ed25519
+/// verifier inside the zkVM and returns a receipt whose journal commits the
+/// public key and message, like [`prove_ecdsa_verification`].
+fn prove_ed25519_verification(
+    verifying_key: &ed25519_dalek::VerifyingKey,
+    message: &[u8],
+    signature: &ed25519_dalek::Signature,
+    receipt_kind: ReceiptKind,
+) -> Receipt {
+    let input = (verifying_key.to_bytes(), message, signature.to_bytes().to_vec());
+    let env = ExecutorEnv::builder()
+        .write(&input)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    // Obtain the default prover.
+    let prover = default_prover();
+
+    // Produce a receipt by proving the specified ELF binary.
+    prover
+        .prove_with_opts(env, ED25519_VERIFY_ELF, &receipt_kind.prover_opts())
+        .unwrap()
+        .receipt
+}
+
+/// Given a P-384 verifying key, message and signature, runs the P-384 ECDSA
+/// verifier inside the zkVM and returns a receipt whose journal commits the
+/// curve, compressed public key and message.
+fn prove_p384_verification(
+    verifying_key: &p384::ecdsa::VerifyingKey,
+    message: &[u8],
+    signature: &p384::ecdsa::Signature,
+    receipt_kind: ReceiptKind,
 ) -> Receipt {
-    let input = (verifying_key.to_encoded_point(true), message, signature);
+    let input = (
+        verifying_key.to_encoded_point(true).as_bytes().to_vec(),
+        message,
+        signature.to_bytes().to_vec(),
+    );
     let env = ExecutorEnv::builder()
         .write(&input)
         .unwrap()
@@ -27,13 +200,249 @@ fn prove_ecdsa_verification(
     let prover = default_prover();
 
     // Produce a receipt by proving the specified ELF binary.
-    prover.prove(env, ECDSA_VERIFY_ELF).unwrap().receipt
+    prover
+        .prove_with_opts(env, P384_VERIFY_ELF, &receipt_kind.prover_opts())
+        .unwrap()
+        .receipt
+}
+
+/// Runs the batch ECDSA verifier over all `entries` inside the zkVM and returns
+/// one receipt whose journal commits the entry count, the Merkle root over the
+/// `(key, message digest)` leaves and, with `commit_entries`, every key and
+/// message.
+fn prove_batch_verification(
+    entries: &[(VerifyingKey, Vec<u8>, Signature)],
+    commit_entries: bool,
+    receipt_kind: ReceiptKind,
+) -> Receipt {
+    let entries: Vec<_> = entries
+        .iter()
+        .map(|(verifying_key, message, signature)| {
+            (verifying_key.to_encoded_point(true), message, signature)
+        })
+        .collect();
+    let env = ExecutorEnv::builder()
+        .write(&(entries, commit_entries))
+        .unwrap()
+        .build()
+        .unwrap();
+
+    // Obtain the default prover.
+    let prover = default_prover();
+
+    // Produce a receipt by proving the specified ELF binary.
+    prover
+        .prove_with_opts(env, ECDSA_VERIFY_BATCH_ELF, &receipt_kind.prover_opts())
+        .unwrap()
+        .receipt
 }
 
+/// Logs the kind of `receipt` and the size of its seal.
+fn log_receipt_size(receipt: &Receipt) {
+    let kind = match &receipt.inner {
+        InnerReceipt::Composite(_) => "composite",
+        InnerReceipt::Succinct(_) => "succinct",
+        InnerReceipt::Groth16(_) => "groth16",
+        InnerReceipt::Fake(_) => "fake",
+        _ => "unknown",
+    };
+    info!("Produced {} receipt with a {} byte seal", kind, receipt.seal_size());
+}
+
+const MESSAGE: &[u8] = b"This is a message that will be signed, and verified within the zkVM";
+
 fn main() {
     // Initialize the logger
     env_logger::init();
 
+    let args = Args::parse();
+
+    if let Some(Command::Compress { input, output, receipt_kind }) = args.command {
+        if let ReceiptKind::Composite = receipt_kind {
+            Args::command()
+                .error(
+                    ErrorKind::InvalidValue,
+                    "cannot compress a receipt to composite; use succinct or groth16",
+                )
+                .exit();
+        }
+        if let Err(e) = compress_receipt_file(&input, &output, receipt_kind) {
+            exit_with_error(&e);
+        }
+        return;
+    }
+
+    if args.mode.is_some() && !matches!(args.scheme, Scheme::P256) {
+        Args::command()
+            .error(ErrorKind::ArgumentConflict, "--mode only applies to --scheme p256")
+            .exit();
+    }
+
+    let receipt = match (args.batch, args.scheme) {
+        (Some(batch_size), _) => run_batch(batch_size, args.receipt_kind),
+        (None, Scheme::P256) => run_p256(args.mode.unwrap_or_default(), args.receipt_kind),
+        (None, Scheme::Ed25519) => run_ed25519(args.receipt_kind),
+        (None, Scheme::P384) => run_p384(args.receipt_kind),
+    };
+
+    if let Some(out) = args.out {
+        if let Err(e) = write_receipt(&out, &receipt) {
+            exit_with_error(&e);
+        }
+    }
+}
+
+/// Prints `message` to stderr and exits with a failure status.
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("error: {}", message);
+    std::process::exit(1);
+}
+
+/// Writes `receipt` to `path` with bincode, the format the mobile bindings use.
+fn write_receipt(path: &Path, receipt: &Receipt) -> Result<(), String> {
+    let bytes = bincode::serialize(receipt)
+        .map_err(|e| format!("failed to serialize receipt for {}: {}", path.display(), e))?;
+    fs::write(path, bytes)
+        .map_err(|e| format!("failed to write receipt to {}: {}", path.display(), e))?;
+    info!("Wrote receipt to {}", path.display());
+    Ok(())
+}
+
+/// Reads a receipt from `input`, compresses it to `receipt_kind` and writes the
+/// result to `output`. The journal is unchanged, so the compressed receipt
+/// verifies against the same image ID.
+fn compress_receipt_file(
+    input: &Path,
+    output: &Path,
+    receipt_kind: ReceiptKind,
+) -> Result<(), String> {
+    info!("Compressing receipt {} to {:?}", input.display(), receipt_kind);
+    let bytes = fs::read(input)
+        .map_err(|e| format!("failed to read receipt {}: {}", input.display(), e))?;
+    let receipt: Receipt = bincode::deserialize(&bytes)
+        .map_err(|e| format!("failed to deserialize receipt {}: {}", input.display(), e))?;
+    log_receipt_size(&receipt);
+
+    let compressed = default_prover()
+        .compress(&receipt_kind.prover_opts(), &receipt)
+        .map_err(|e| format!("failed to compress receipt {}: {}", input.display(), e))?;
+    if compressed.journal.bytes != receipt.journal.bytes {
+        return Err(format!("compressing {} changed the journal", input.display()));
+    }
+    log_receipt_size(&compressed);
+
+    write_receipt(output, &compressed)
+}
+
+fn run_p384(receipt_kind: ReceiptKind) -> Receipt {
+    info!("Starting P384 ECDSA signature verification in zkVM");
+
+    // Generate a random secp384r1 keypair and sign the message.
+    debug!("Generating random secp384r1 keypair");
+    let signing_key = p384::ecdsa::SigningKey::random(&mut OsRng);
+    let verifying_key = signing_key.verifying_key();
+    info!("Generated keypair with public key: {}", verifying_key.to_encoded_point(true));
+
+    debug!("Signing message with private key");
+    let signature: p384::ecdsa::Signature = signing_key.sign(MESSAGE);
+
+    // Run signature verified in the zkVM guest and get the resulting receipt.
+    info!("Running P384 ECDSA verification in zkVM guest");
+    let receipt = prove_p384_verification(verifying_key, MESSAGE, &signature, receipt_kind);
+    info!("zkVM execution completed, receipt generated");
+    log_receipt_size(&receipt);
+
+    // Verify the receipt and then access the journal.
+    debug!("Verifying receipt with method ID");
+    receipt.verify(P384_VERIFY_ID).unwrap();
+    info!("Receipt verification successful");
+
+    let (curve, receipt_verifying_key, receipt_message): (String, Vec<u8>, Vec<u8>) =
+        receipt.journal.decode().unwrap();
+
+    info!("SUCCESS: Verified the {} signature over message {:?} with key {:02x?}",
+        curve,
+        String::from_utf8_lossy(&receipt_message),
+        receipt_verifying_key,
+    );
+
+    info!("P384 ECDSA verification in zkVM completed successfully");
+
+    receipt
+}
+
+fn run_batch(batch_size: usize, receipt_kind: ReceiptKind) -> Receipt {
+    info!("Starting batch verification of {} P256 ECDSA signatures in zkVM", batch_size);
+
+    // Sign a distinct message with a fresh secp256r1 keypair for each entry.
+    debug!("Generating {} keypairs and signatures", batch_size);
+    let entries: Vec<(VerifyingKey, Vec<u8>, Signature)> = (0..batch_size)
+        .map(|index| {
+            let signing_key = SigningKey::random(&mut OsRng);
+            let message = [MESSAGE, format!(" #{}", index).as_bytes()].concat();
+            let signature: Signature = signing_key.sign(&message);
+            (*signing_key.verifying_key(), message, signature)
+        })
+        .collect();
+
+    // Run the batch verification in the zkVM guest and get a single receipt.
+    info!("Running batch ECDSA verification in zkVM guest");
+    let receipt = prove_batch_verification(&entries, false, receipt_kind);
+    info!("zkVM execution completed, receipt generated");
+    log_receipt_size(&receipt);
+
+    // Verify the receipt and then access the journal.
+    debug!("Verifying receipt with method ID");
+    receipt.verify(ECDSA_VERIFY_BATCH_ID).unwrap();
+    info!("Receipt verification successful");
+
+    let journal: BatchJournal = receipt.journal.decode().unwrap();
+
+    info!(
+        "SUCCESS: Verified {} signatures with batch root {:02x?}",
+        journal.count, journal.root
+    );
+
+    receipt
+}
+
+fn run_ed25519(receipt_kind: ReceiptKind) -> Receipt {
+    info!("Starting Ed25519 signature verification in zkVM");
+
+    // Generate a random Ed25519 keypair and sign the message.
+    debug!("Generating random Ed25519 keypair");
+    let signing_key = ed25519_dalek::SigningKey::generate(&mut OsRng);
+    let verifying_key = signing_key.verifying_key();
+    info!("Generated keypair with public key: {:02x?}", verifying_key.to_bytes());
+
+    debug!("Signing message with private key");
+    let signature = signing_key.sign(MESSAGE);
+
+    // Run signature verified in the zkVM guest and get the resulting receipt.
+    info!("Running Ed25519 verification in zkVM guest");
+    let receipt = prove_ed25519_verification(&verifying_key, MESSAGE, &signature, receipt_kind);
+    info!("zkVM execution completed, receipt generated");
+    log_receipt_size(&receipt);
+
+    // Verify the receipt and then access the journal.
+    debug!("Verifying receipt with method ID");
+    receipt.verify(ED25519_VERIFY_ID).unwrap();
+    info!("Receipt verification successful");
+
+    let (receipt_verifying_key, receipt_message): ([u8; 32], Vec<u8>) =
+        receipt.journal.decode().unwrap();
+
+    info!("SUCCESS: Verified the signature over message {:?} with key {:02x?}",
+        String::from_utf8_lossy(&receipt_message),
+        receipt_verifying_key,
+    );
+
+    info!("Ed25519 verification in zkVM completed successfully");
+
+    receipt
+}
+
+fn run_p256(mode: MessageMode, receipt_kind: ReceiptKind) -> Receipt {
     info!("Starting P256 ECDSA signature verification in zkVM");
 
     // Generate a random secp256r1 keypair and sign the message.
@@ -42,7 +451,7 @@ fn main() {
     let verifying_key = signing_key.verifying_key();
     info!("Generated keypair with public key: {}", verifying_key.to_encoded_point(true));
 
-    let message = b"This is a message that will be signed, and verified within the zkVM";
+    let message = MESSAGE;
     debug!("Message to sign: {:?}", std::str::from_utf8(message).unwrap());
 
     debug!("Signing message with private key");
@@ -50,24 +459,44 @@ fn main() {
     debug!("Generated signature: {:?}", signature);
 
     // Run signature verified in the zkVM guest and get the resulting receipt.
-    info!("Running ECDSA verification in zkVM guest");
-    let receipt = prove_ecdsa_verification(verifying_key, message, &signature);
+    info!("Running ECDSA verification in zkVM guest ({:?} message)", mode);
+    let receipt = prove_ecdsa_verification(verifying_key, message, &signature, mode, receipt_kind);
     info!("zkVM execution completed, receipt generated");
+    log_receipt_size(&receipt);
 
     // Verify the receipt and then access the journal.
     debug!("Verifying receipt with method ID");
-    receipt.verify(ECDSA_VERIFY_ID).unwrap();
+    match mode {
+        MessageMode::Full => receipt.verify(ECDSA_VERIFY_ID).unwrap(),
+        MessageMode::Prehashed => receipt.verify(ECDSA_VERIFY_PREHASH_ID).unwrap(),
+    }
     info!("Receipt verification successful");
 
     debug!("Decoding journal from receipt");
-    let (receipt_verifying_key, receipt_message): (EncodedPoint, Vec<u8>) =
-        receipt.journal.decode().unwrap();
-    debug!("Journal decoded successfully");
+    match mode {
+        MessageMode::Full => {
+            let (receipt_verifying_key, receipt_message): (EncodedPoint, Vec<u8>) =
+                receipt.journal.decode().unwrap();
+            debug!("Journal decoded successfully");
 
-    info!("SUCCESS: Verified the signature over message {:?} with key {}",
-        std::str::from_utf8(&receipt_message[..]).unwrap(),
-        receipt_verifying_key,
-    );
+            info!("SUCCESS: Verified the signature over message {:?} with key {}",
+                String::from_utf8_lossy(&receipt_message),
+                receipt_verifying_key,
+            );
+        }
+        MessageMode::Prehashed => {
+            let (receipt_verifying_key, receipt_digest): (EncodedPoint, [u8; 32]) =
+                receipt.journal.decode().unwrap();
+            debug!("Journal decoded successfully");
+
+            info!("SUCCESS: Verified the signature over message digest {:02x?} with key {}",
+                receipt_digest,
+                receipt_verifying_key,
+            );
+        }
+    }
 
     info!("P256 ECDSA verification in zkVM completed successfully");
+
+    receipt
 }